@@ -29,6 +29,7 @@ pub use limbint::LimbInt;
 pub trait Integer: Sized {
     type Limb: Limb;
 
+    #[allow(clippy::wrong_self_convention)]
     fn into_limbs_le(&self) -> &[Self::Limb];
 }
 
@@ -66,6 +67,71 @@ impl Bytes {
             .rev()
             .flat_map(|l| l.into_bytes_be())
     }
+
+    /// Writes a sequence of bytes to a formatter in lowercase hexadecimal.
+    pub fn fmt_hex(
+        bytes: impl IntoIterator<Item = u8>,
+        f: &mut cryp_std::fmt::Formatter<'_>,
+    ) -> cryp_std::fmt::Result {
+        for b in bytes {
+            write!(f, "{:02x}", b)?;
+        }
+        Ok(())
+    }
+}
+
+/// Provides a namespace for the width-`w` non-adjacent form (wNAF) of an integer.
+///
+/// The wNAF of `k` is a sequence of digits `d_i` such that `k = sum_i d_i * 2^i`, where every
+/// non-zero digit is odd with `|d_i| < 2^(w-1)` and among any `w` consecutive digits at most
+/// one is non-zero. It is used for variable time scalar multiplication, where it reduces the
+/// number of additions to about `n / (w + 1)` for an `n`-bit scalar.
+pub struct Wnaf;
+
+impl Wnaf {
+    /// Computes the wNAF digits of an integer, least significant digit first.
+    ///
+    /// Trailing zero digits are removed, so the zero integer gives an empty vector.
+    /// The width must be between 2 and 32.
+    ///
+    /// Does not run in constant time.
+    pub fn recode(element: &impl Integer, w: usize) -> cryp_std::vec::Vec<i64> {
+        assert!((2..=32).contains(&w), "wNAF width must be between 2 and 32");
+
+        let mut bits = Bits::into_iter_be(element).collect::<cryp_std::vec::Vec<_>>();
+        bits.reverse();
+        let bit = |i: usize| bits.get(i).map_or(0, |b| *b as u64);
+
+        let width = 1u64 << w;
+        let mut digits = cryp_std::vec![0i64; bits.len() + 1];
+
+        let mut carry = 0;
+        let mut pos = 0;
+        while pos < digits.len() {
+            // The current window, with the carry from the previous negative digit
+            let window = carry + (0..w).fold(0, |acc, j| acc + (bit(pos + j) << j));
+
+            if window & 1 == 0 {
+                // an even window gives a zero digit, the carry is preserved
+                pos += 1;
+                continue;
+            }
+
+            if window < width / 2 {
+                carry = 0;
+                digits[pos] = window as i64;
+            } else {
+                carry = 1;
+                digits[pos] = window as i64 - width as i64;
+            }
+            pos += w;
+        }
+
+        while digits.last() == Some(&0) {
+            digits.pop();
+        }
+        digits
+    }
 }
 
 impl<L: Limb, const N: usize> Integer for [L; N] {
@@ -107,4 +173,47 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn test_wnaf() {
+        use crate::helper::big_int_from_u64;
+        use cryp_std::rand::{thread_rng, Rng};
+        use num_bigint::BigInt;
+
+        let mut rng = thread_rng();
+
+        assert!(Wnaf::recode(&[0u64; 2], 4).is_empty());
+        assert_eq!(Wnaf::recode(&[7u32], 2), vec![-1, 0, 0, 1]);
+        assert_eq!(Wnaf::recode(&[u64::MAX], 5), {
+            let mut digits = vec![0; 65];
+            digits[0] = -1;
+            digits[64] = 1;
+            digits
+        });
+
+        for w in 2..=8 {
+            for _ in 0..20 {
+                let scalar: [u64; 4] = rng.gen();
+                let digits = Wnaf::recode(&scalar, w);
+
+                // the digits represent the integer
+                let value = digits
+                    .iter()
+                    .rev()
+                    .fold(BigInt::from(0), |acc, d| acc * 2 + d);
+                assert_eq!(value, BigInt::from(big_int_from_u64(&scalar)));
+
+                // non-zero digits are odd, bounded and separated by at least w - 1 zeros
+                let mut last_non_zero: Option<usize> = None;
+                for (i, d) in digits.iter().enumerate().filter(|(_, d)| **d != 0) {
+                    assert_eq!(d.rem_euclid(2), 1);
+                    assert!(d.abs() < 1 << (w - 1));
+                    if let Some(j) = last_non_zero {
+                        assert!(i - j >= w);
+                    }
+                    last_non_zero = Some(i);
+                }
+            }
+        }
+    }
 }
@@ -1,5 +1,6 @@
 use cryp_std::fmt::Debug;
 use cryp_std::hash::Hash;
+use cryp_std::ops::{BitAnd, BitOr, Shr};
 use cryp_std::rand::UniformRand;
 
 /// Limb is a trait which represents a single limb of a big integer.
@@ -7,10 +8,23 @@ use cryp_std::rand::UniformRand;
 /// **Warning**: currently, it is assumed implicitly that Limb behaves like a power of two when
 /// it comes to coversion from bits.
 pub trait Limb:
-    Sized + Copy + Clone + PartialEq + Eq + Debug + Send + Sync + Hash + UniformRand + PartialOrd + Ord
+    Sized
+    + Copy
+    + Clone
+    + PartialEq
+    + Eq
+    + Debug
+    + Send
+    + Sync
+    + Hash
+    + UniformRand
+    + PartialOrd
+    + Ord
+    + BitAnd<Output = Self>
+    + Shr<u32, Output = Self>
 {
     /// The type used to represent a carry bit.
-    type Carry: PartialEq + Eq + Copy + Clone + Debug;
+    type Carry: PartialEq + Eq + Copy + Clone + Debug + BitOr<Output = Self::Carry>;
 
     /// The number of bytes needed to represent this limb.
     const BYTES: usize;
@@ -20,6 +34,8 @@ pub trait Limb:
 
     const ZERO: Self;
     const ONE: Self;
+    /// The limb with all bits set.
+    const MAX: Self;
 
     ///  Calculates `self + rhs + carry`, returning a carry bit.
     ///
@@ -38,8 +54,10 @@ pub trait Limb:
     fn mul_carry(&self, rhs: Self, carry: Self) -> (Self, Self);
 
     /// Conversion to bytes in big endian order.
+    #[allow(clippy::wrong_self_convention)]
     fn into_bytes_be(&self) -> Self::Bytes;
     /// Conversion to bytes in little endian order.
+    #[allow(clippy::wrong_self_convention)]
     fn into_bytes_le(&self) -> Self::Bytes;
 
     /// Constructs a limb from a sequence of bytes with the correct length.
@@ -50,6 +68,9 @@ pub trait Limb:
     ///
     /// The bytes are interpreted in little endian order.
     fn from_bytes_le(bytes: &[u8]) -> Result<Self, WrongByteLengthError>;
+
+    /// The number of leading zero bits.
+    fn leading_zeros(self) -> u32;
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
@@ -59,6 +80,7 @@ impl Limb for u32 {
     type Carry = bool;
     const ZERO: Self = 0;
     const ONE: Self = 1;
+    const MAX: Self = Self::MAX;
 
     const BYTES: usize = 4;
     type Bytes = [u8; 4];
@@ -102,12 +124,17 @@ impl Limb for u32 {
             .map_err(|_| WrongByteLengthError)
             .map(u32::from_le_bytes)
     }
+
+    fn leading_zeros(self) -> u32 {
+        u32::leading_zeros(self)
+    }
 }
 
 impl Limb for u64 {
     type Carry = bool;
     const ZERO: Self = 0;
     const ONE: Self = 1;
+    const MAX: Self = Self::MAX;
 
     const BYTES: usize = 8;
     type Bytes = [u8; 8];
@@ -152,6 +179,10 @@ impl Limb for u64 {
             .map_err(|_| WrongByteLengthError)
             .map(u64::from_le_bytes)
     }
+
+    fn leading_zeros(self) -> u32 {
+        u64::leading_zeros(self)
+    }
 }
 
 // -----------------------------------
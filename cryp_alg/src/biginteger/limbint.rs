@@ -1,4 +1,4 @@
-use super::{Bytes, Integer, Limb};
+use super::{Integer, Limb};
 
 /// A fixed size big-precision integer type
 #[derive(Debug, Clone, Copy)]
@@ -68,9 +68,9 @@ impl<L: Limb, const N: usize> LimbInt<L, N> {
     pub fn carrying_add(&self, rhs: Self, carry: L::Carry) -> (Self, L::Carry) {
         let mut carry = carry;
         let mut limbs = [L::ZERO; N];
-        for i in 0..N {
-            let (l, c) = self.limbs[i].add_carry(rhs.limbs[i], carry);
-            limbs[i] = l;
+        for (limb, (a, b)) in limbs.iter_mut().zip(self.limbs.iter().zip(&rhs.limbs)) {
+            let (l, c) = a.add_carry(*b, carry);
+            *limb = l;
             carry = c;
         }
         (limbs.into(), carry)
@@ -79,9 +79,9 @@ impl<L: Limb, const N: usize> LimbInt<L, N> {
     pub fn carrying_sub(&self, rhs: Self, carry: L::Carry) -> (Self, L::Carry) {
         let mut carry = carry;
         let mut limbs = [L::ZERO; N];
-        for i in 0..N {
-            let (l, c) = self.limbs[i].sub_carry(rhs.limbs[i], carry);
-            limbs[i] = l;
+        for (limb, (a, b)) in limbs.iter_mut().zip(self.limbs.iter().zip(&rhs.limbs)) {
+            let (l, c) = a.sub_carry(*b, carry);
+            *limb = l;
             carry = c;
         }
         (limbs.into(), carry)
@@ -133,9 +133,9 @@ impl<L: Limb, const N: usize> LimbInt<L, N> {
     pub fn mul_by_limb(&self, rhs: L) -> (Self, L) {
         let mut carry = L::ZERO;
         let mut limbs = [L::ZERO; N];
-        for i in 0..N {
-            let (l, c) = self.limbs[i].mul_carry(rhs, carry);
-            limbs[i] = l;
+        for (limb, a) in limbs.iter_mut().zip(self.limbs.iter()) {
+            let (l, c) = a.mul_carry(rhs, carry);
+            *limb = l;
             carry = c;
         }
         (limbs.into(), carry)
@@ -144,9 +144,9 @@ impl<L: Limb, const N: usize> LimbInt<L, N> {
     pub fn mul_by_limb_carry(&self, rhs: L, carry: L) -> (Self, L) {
         let mut carry = carry;
         let mut limbs = [L::ZERO; N];
-        for i in 0..N {
-            let (l, c) = self.limbs[i].mul_carry(rhs, carry);
-            limbs[i] = l;
+        for (limb, a) in limbs.iter_mut().zip(self.limbs.iter()) {
+            let (l, c) = a.mul_carry(rhs, carry);
+            *limb = l;
             carry = c;
         }
         (limbs.into(), carry)
@@ -197,6 +197,7 @@ impl<L: Limb, const N: usize> cryp_std::hash::Hash for LimbInt<L, N> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::biginteger::Bytes;
 
     use cryp_std::vec::Vec;
     use num_bigint::BigUint;
@@ -242,7 +243,7 @@ mod tests {
         let (res_0, c_0) = a_0.carrying_add(b_0, false);
 
         assert_eq!(res_0, LimbInt64::from([2u32, 1u32]));
-        assert_eq!(c_0, false);
+        assert!(!c_0);
 
         let real_add = to_u64(&a_0) + to_u64(&b_0);
         assert_eq!(real_add, to_u64(&res_0));
@@ -276,7 +277,7 @@ mod tests {
         let (res_0, c_0) = a_0.carrying_sub(b_0, false);
 
         assert_eq!(res_0, LimbInt64::from([0u32, 1u32]));
-        assert_eq!(c_0, false);
+        assert!(!c_0);
         assert_eq!(to_u64(&res_0), to_u64(&a_0) - to_u64(&b_0));
 
         let a = LimbInt64::from([0, 0]);
@@ -325,7 +326,7 @@ mod tests {
             u32::MAX,
             u32::MAX,
             u32::MAX,
-        ]) + 1 as u32;
+        ]) + 1_u32;
 
         assert_eq!(res.limbs.as_slice(), (&res_big % &modulus).to_u32_digits());
         assert_eq!(
@@ -400,7 +401,7 @@ mod tests {
         let product: Vec<u32> = product_l
             .limbs
             .into_iter()
-            .chain(product_r.limbs.into_iter())
+            .chain(product_r.limbs)
             .collect();
 
         let n_a = BigUint::from_slice(&a.limbs);
@@ -432,7 +433,7 @@ mod tests {
         let product: Vec<u64> = product_l
             .limbs
             .into_iter()
-            .chain(product_r.limbs.into_iter())
+            .chain(product_r.limbs)
             .collect();
 
         let n_c = big_int_from_u64(&c.limbs);
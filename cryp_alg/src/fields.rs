@@ -17,16 +17,18 @@ use cryp_std::{
 };
 
 use super::{One, Zero};
-use crate::Integer;
+use crate::{Bits, Integer};
 
-use zeroize::Zeroize;
 
 mod abstract_operations;
 mod models;
 
 pub use abstract_operations::{PrimeFieldOperations, F};
 pub use abstract_operations::general_reduction::{GeneralReduction, GeneralReductionOperations};
-pub use models::montgomery::{MontParameters, MontgomeryOperations};
+pub use models::cubic_extension::{CubicExtField, CubicExtParameters};
+pub use models::goldilocks::{GoldilocksParameters, GoldilocksReduction};
+pub use models::montgomery::{FftParameters, MontParameters, MontgomeryOperations};
+pub use models::quadratic_extension::{QuadExtField, QuadExtParameters};
 pub use models::solinas::{SolinasParameters, SolinasReduction};
 
 /// The interface for a field
@@ -89,6 +91,13 @@ pub trait Field:
         result
     }
 
+    /// The Frobenius endomorphism `x -> x^(q^power)`, where `q` is the characteristic.
+    ///
+    /// The default implementation is the identity, which is correct for prime fields.
+    fn frobenius_map(&self, _power: usize) -> Self {
+        *self
+    }
+
     /// Exponentiation by squaring for a small modulus.
     ///
     /// Does not run in constant time.
@@ -135,10 +144,103 @@ pub trait PrimeField: Field {
     /// The underlying representation as an integer
     ///
     /// Safety: the number of bits representing each element must be constant.
-    type BigInteger: Integer + Debug + PartialEq + Eq;
+    type BigInteger: Integer
+        + Debug
+        + PartialEq
+        + Eq
+        + Clone
+        + Copy
+        + Hash
+        + Send
+        + Sync
+        + 'static;
 
     const MODULUS: Self::BigInteger;
 
     fn as_int(&self) -> Self::BigInteger;
     fn from_int(int: &Self::BigInteger) -> Self;
+
+    /// Computes a square root of the element, if it is a quadratic residue.
+    ///
+    /// Either of the two roots may be returned. The default implementation uses the
+    /// Tonelli-Shanks algorithm and does not run in constant time.
+    fn sqrt(&self) -> Option<Self> {
+        if *self == Self::zero() {
+            return Some(Self::zero());
+        }
+        let exp = |x: &Self, bits: &[bool]| {
+            bits.iter().fold(Self::one(), |acc, bit| {
+                let acc = acc.square();
+                if *bit {
+                    acc * x
+                } else {
+                    acc
+                }
+            })
+        };
+
+        // Write p - 1 = 2^s * q with q odd, as big endian bits
+        let mut p_minus_one = Bits::into_iter_be(&Self::MODULUS).collect::<cryp_std::vec::Vec<_>>();
+        *p_minus_one.last_mut()? = false;
+        let half = &p_minus_one[..p_minus_one.len() - 1];
+        let s = p_minus_one.iter().rev().take_while(|bit| !**bit).count();
+        let q = &p_minus_one[..p_minus_one.len() - s];
+
+        // Euler's criterion
+        if exp(self, half) != Self::one() {
+            return None;
+        }
+        let mut z = Self::one().double();
+        while exp(&z, half) == Self::one() {
+            z += Self::one();
+        }
+
+        let mut m = s;
+        let mut c = exp(&z, q);
+        let mut t = exp(self, q);
+        // self^((q + 1) / 2)
+        let mut r = exp(self, &q[..q.len() - 1]) * self;
+        while t != Self::one() {
+            // The least i with t^(2^i) = 1
+            let mut i = 0;
+            let mut t_power = t;
+            while t_power != Self::one() {
+                t_power.square_in_place();
+                i += 1;
+            }
+            let mut b = c;
+            for _ in 0..m - i - 1 {
+                b.square_in_place();
+            }
+            m = i;
+            c = b.square();
+            t *= c;
+            r *= b;
+        }
+        Some(r)
+    }
+}
+
+/// An interface for a prime field with a large multiplicative subgroup of order `2^k`.
+///
+/// Such fields support radix-2 fast Fourier transforms, which are used for fast
+/// polynomial multiplication and evaluation over a subgroup.
+pub trait FftField: PrimeField {
+    /// The largest `k` such that `2^k` divides `p - 1`.
+    const TWO_ADICITY: u32;
+
+    /// A primitive root of unity of order `2^TWO_ADICITY`.
+    fn two_adic_root_of_unity() -> Self;
+
+    /// A primitive root of unity of order `2^log_n`, if one exists.
+    fn root_of_unity(log_n: u32) -> Option<Self> {
+        if log_n > Self::TWO_ADICITY {
+            return None;
+        }
+        let mut omega = Self::two_adic_root_of_unity();
+        for _ in log_n..Self::TWO_ADICITY {
+            omega.square_in_place();
+        }
+        Some(omega)
+    }
 }
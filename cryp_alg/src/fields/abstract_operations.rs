@@ -1,7 +1,11 @@
 
 pub(crate) mod general_reduction;
+// The generic building blocks below are not used by `F` yet
+#[allow(dead_code)]
 pub(crate) mod arithmetic;
+#[allow(dead_code)]
 pub(crate) mod exponentiation;
+#[allow(dead_code)]
 pub(crate) mod inversion;
 
 
@@ -67,7 +71,7 @@ pub trait PrimeFieldOperations: 'static + Debug {
     /// Default implementations uses the zero comparison and substraction.
     fn equals(lhs: &Self::BigInt, rhs: &Self::BigInt) -> bool {
         let mut res = *lhs;
-        Self::sub_assign(&mut res, &rhs);
+        Self::sub_assign(&mut res, rhs);
         Self::is_zero(&res)
     }
 
@@ -80,7 +84,7 @@ pub trait PrimeFieldOperations: 'static + Debug {
     /// Negation of an element.
     fn negation_in_place(element: &mut Self::BigInt) {
         let mut res = Self::zero();
-        Self::sub_assign(&mut res, &element);
+        Self::sub_assign(&mut res, element);
         *element = res;
     }
 
@@ -160,6 +164,7 @@ pub struct F<S: PrimeFieldOperations> {
 
 
 /// A struct representing a collection of custom operations on a field.
+#[allow(dead_code)]
 #[derive(Debug, Clone, Copy)]
 pub struct Operations<A : ArithmeticOperations, E : Exponentiation<A>, I: Inversion<A>> {
  _marker: cryp_std::marker::PhantomData<(A, E, I)>,
@@ -177,6 +182,14 @@ impl<S: PrimeFieldOperations> F<S> {
     pub const fn from_RAW_limbs(element: S::BigInt) -> Self {
         Self { element }
     }
+
+    /// The limbs of the element in its internal representation, the inverse of
+    /// `from_RAW_limbs`.
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn as_RAW_limbs(&self) -> S::BigInt {
+        self.element
+    }
 }
 
 //------------------------------------
@@ -465,9 +478,7 @@ impl<S: PrimeFieldOperations> Hash for F<S> {
 
 impl<S: PrimeFieldOperations> Clone for F<S> {
     fn clone(&self) -> Self {
-        Self {
-            element: self.element.clone(),
-        }
+        *self
     }
 }
 
@@ -493,6 +504,7 @@ impl<S: PrimeFieldOperations> UniformRand for F<S> {
 // From numerical types
 //------------
 
+#[allow(unused_macros)]
 macro_rules! impl_from {
     ($($t:ty),*) => {
         $(
@@ -53,7 +53,7 @@ pub trait ArithmeticOperations: 'static + Debug {
     /// Default implementations uses the zero comparison and substraction.
     fn equals(lhs: &Self::BigInt, rhs: &Self::BigInt) -> bool {
         let mut res = *lhs;
-        Self::sub_assign(&mut res, &rhs);
+        Self::sub_assign(&mut res, rhs);
         Self::is_zero(&res)
     }
 
@@ -66,7 +66,7 @@ pub trait ArithmeticOperations: 'static + Debug {
     /// Negation of an element.
     fn negation_in_place(element: &mut Self::BigInt) {
         let mut res = Self::zero();
-        Self::sub_assign(&mut res, &element);
+        Self::sub_assign(&mut res, element);
         *element = res;
     }
 
@@ -61,7 +61,7 @@ impl<const N: usize, P: GeneralReduction<N>> PrimeFieldOperations
 
     fn reduce(element: &Self::BigInt) -> Self::BigInt {
         // Using the given reduction algorithm
-        let padded = (*element, *&Self::BigInt::zero());
+        let padded = (*element, Self::BigInt::zero());
         P::reduction_limbint(&padded)
     }
 
@@ -69,8 +69,8 @@ impl<const N: usize, P: GeneralReduction<N>> PrimeFieldOperations
         // Takes a random collection of limbs and reject it if it is greater than the modulus
         let mut res = [P::Limb::ZERO; N];
         loop {
-            for i in 0..N {
-                res[i] = P::Limb::rand(rng);
+            for limb in res.iter_mut() {
+                *limb = P::Limb::rand(rng);
             }
             let element = Self::BigInt::from(res);
             if element.le(&P::MODULUS.into()) {
@@ -0,0 +1,296 @@
+//! Cubic extension fields
+//!
+//! Elements of the extension `F[v] / (v^3 - NONRESIDUE)` of a field `F` are represented
+//! as `c0 + c1 * v + c2 * v^2` with `c0, c1, c2` in `F`.
+
+use crate::{Field, One, Zero};
+use cryp_std::{
+    fmt::{Debug, Display},
+    hash::{Hash, Hasher},
+    iter,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+    rand::{Rng, UniformRand},
+};
+
+/// Parameters of a cubic extension `F[v] / (v^3 - NONRESIDUE)`.
+pub trait CubicExtParameters: 'static + Send + Sync + Debug {
+    /// The field `F` being extended.
+    type BaseField: Field;
+    /// The field containing the coefficients of the Frobenius map.
+    type FrobCoeff: Field;
+
+    /// A cubic non-residue of the base field.
+    const NONRESIDUE: Self::BaseField;
+
+    /// The coefficients `NONRESIDUE^((q^i - 1) / 3)`, where `q` is the characteristic,
+    /// for `i` up to the degree of the extension over the prime field.
+    const FROBENIUS_COEFF_C1: &'static [Self::FrobCoeff];
+    /// The coefficients `NONRESIDUE^((2 * q^i - 2) / 3)`.
+    const FROBENIUS_COEFF_C2: &'static [Self::FrobCoeff];
+
+    /// Multiplies an element of the base field by `NONRESIDUE`.
+    ///
+    /// The default implementation is a multiplication, but it is usually cheaper for
+    /// a specific non-residue.
+    fn mul_base_by_nonresidue(element: &Self::BaseField) -> Self::BaseField {
+        Self::NONRESIDUE * element
+    }
+
+    /// Multiplies the coefficients `c1, c2` by the Frobenius coefficients of the given power.
+    fn mul_base_by_frob_coeff(c1: &mut Self::BaseField, c2: &mut Self::BaseField, power: usize);
+}
+
+/// An element `c0 + c1 * v + c2 * v^2` of a cubic extension field.
+pub struct CubicExtField<P: CubicExtParameters> {
+    pub c0: P::BaseField,
+    pub c1: P::BaseField,
+    pub c2: P::BaseField,
+}
+
+impl<P: CubicExtParameters> CubicExtField<P> {
+    pub const fn new(c0: P::BaseField, c1: P::BaseField, c2: P::BaseField) -> Self {
+        Self { c0, c1, c2 }
+    }
+
+    /// Multiplies all coefficients by an element of the base field.
+    pub fn mul_by_base(&self, element: &P::BaseField) -> Self {
+        Self::new(self.c0 * element, self.c1 * element, self.c2 * element)
+    }
+}
+
+impl<P: CubicExtParameters> Zero for CubicExtField<P> {
+    fn zero() -> Self {
+        Self::new(
+            P::BaseField::zero(),
+            P::BaseField::zero(),
+            P::BaseField::zero(),
+        )
+    }
+}
+
+impl<P: CubicExtParameters> One for CubicExtField<P> {
+    fn one() -> Self {
+        Self::new(
+            P::BaseField::one(),
+            P::BaseField::zero(),
+            P::BaseField::zero(),
+        )
+    }
+}
+
+impl<P: CubicExtParameters> Field for CubicExtField<P> {
+    fn inverse(&self) -> Option<Self> {
+        // Algorithm 17 of "Implementing cryptographic pairings over Barreto-Naehrig curves"
+        // by Devegili, O'hEigeartaigh, Scott and Dahab.
+        let t0 = self.c0.square() - P::mul_base_by_nonresidue(&(self.c1 * self.c2));
+        let t1 = P::mul_base_by_nonresidue(&self.c2.square()) - self.c0 * self.c1;
+        let t2 = self.c1.square() - self.c0 * self.c2;
+
+        let norm = self.c0 * t0 + P::mul_base_by_nonresidue(&(self.c2 * t1 + self.c1 * t2));
+        norm.inverse()
+            .map(|inverse| Self::new(t0 * inverse, t1 * inverse, t2 * inverse))
+    }
+
+    fn square_in_place(&mut self) {
+        let other = *self;
+        *self *= &other;
+    }
+
+    fn double_in_place(&mut self) {
+        self.c0.double_in_place();
+        self.c1.double_in_place();
+        self.c2.double_in_place();
+    }
+
+    fn frobenius_map(&self, power: usize) -> Self {
+        let mut c1 = self.c1.frobenius_map(power);
+        let mut c2 = self.c2.frobenius_map(power);
+        P::mul_base_by_frob_coeff(&mut c1, &mut c2, power);
+        Self::new(self.c0.frobenius_map(power), c1, c2)
+    }
+}
+
+impl<P: CubicExtParameters> AddAssign<&CubicExtField<P>> for CubicExtField<P> {
+    fn add_assign(&mut self, other: &Self) {
+        self.c0 += other.c0;
+        self.c1 += other.c1;
+        self.c2 += other.c2;
+    }
+}
+
+impl<P: CubicExtParameters> SubAssign<&CubicExtField<P>> for CubicExtField<P> {
+    fn sub_assign(&mut self, other: &Self) {
+        self.c0 -= other.c0;
+        self.c1 -= other.c1;
+        self.c2 -= other.c2;
+    }
+}
+
+impl<P: CubicExtParameters> MulAssign<&CubicExtField<P>> for CubicExtField<P> {
+    fn mul_assign(&mut self, other: &Self) {
+        // Karatsuba multiplication, section 4 of "Multiplication and Squaring on
+        // Pairing-Friendly Fields" by Devegili, O'hEigeartaigh, Scott and Dahab.
+        let (a0, a1, a2) = (self.c0, self.c1, self.c2);
+        let (b0, b1, b2) = (other.c0, other.c1, other.c2);
+
+        let v0 = a0 * b0;
+        let v1 = a1 * b1;
+        let v2 = a2 * b2;
+
+        let c0 = v0 + P::mul_base_by_nonresidue(&((a1 + a2) * (b1 + b2) - v1 - v2));
+        let c1 = (a0 + a1) * (b0 + b1) - v0 - v1 + P::mul_base_by_nonresidue(&v2);
+        let c2 = (a0 + a2) * (b0 + b2) - v0 + v1 - v2;
+        *self = Self::new(c0, c1, c2);
+    }
+}
+
+impl<P: CubicExtParameters> Neg for CubicExtField<P> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.c0, -self.c1, -self.c2)
+    }
+}
+
+impl_extension_field_ops!(CubicExtField, CubicExtParameters);
+
+impl<P: CubicExtParameters> PartialEq for CubicExtField<P> {
+    fn eq(&self, other: &Self) -> bool {
+        self.c0 == other.c0 && self.c1 == other.c1 && self.c2 == other.c2
+    }
+}
+
+impl<P: CubicExtParameters> Eq for CubicExtField<P> {}
+
+impl<P: CubicExtParameters> Hash for CubicExtField<P> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.c0.hash(state);
+        self.c1.hash(state);
+        self.c2.hash(state);
+    }
+}
+
+impl<P: CubicExtParameters> Debug for CubicExtField<P> {
+    fn fmt(&self, f: &mut cryp_std::fmt::Formatter<'_>) -> cryp_std::fmt::Result {
+        write!(
+            f,
+            "CubicExtField({:?}, {:?}, {:?})",
+            self.c0, self.c1, self.c2
+        )
+    }
+}
+
+impl<P: CubicExtParameters> Display for CubicExtField<P> {
+    fn fmt(&self, f: &mut cryp_std::fmt::Formatter<'_>) -> cryp_std::fmt::Result {
+        write!(f, "({} + {} * v + {} * v^2)", self.c0, self.c1, self.c2)
+    }
+}
+
+impl<P: CubicExtParameters> UniformRand for CubicExtField<P> {
+    fn rand<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        Self::new(
+            P::BaseField::rand(rng),
+            P::BaseField::rand(rng),
+            P::BaseField::rand(rng),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{LimbInt, MontParameters, MontgomeryOperations, F};
+    use cryp_std::rand::thread_rng;
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    struct FpTestParams;
+
+    impl MontParameters<1> for FpTestParams {
+        type Limb = u64;
+
+        // 1000003 = 1 mod 3 and 2 is a cubic non-residue
+        const MODULUS: [u64; 1] = [1000003];
+
+        const R: [u64; 1] = [350687];
+
+        const R2: [u64; 1] = [3026];
+
+        const MP: u64 = 2336937208910341525;
+    }
+
+    type FpTest = F<MontgomeryOperations<1, FpTestParams>>;
+
+    const fn fp(raw: u64) -> FpTest {
+        FpTest::from_RAW_limbs(LimbInt::from_limbs([raw]))
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    struct Fp3TestParams;
+
+    impl CubicExtParameters for Fp3TestParams {
+        type BaseField = FpTest;
+        type FrobCoeff = FpTest;
+
+        // 2 in Montgomery form
+        const NONRESIDUE: FpTest = fp(701374);
+
+        // 1, 499501, 500501 in Montgomery form
+        const FROBENIUS_COEFF_C1: &'static [FpTest] = &[fp(350687), fp(981686), fp(667633)];
+
+        // 1, 500501, 499501 in Montgomery form
+        const FROBENIUS_COEFF_C2: &'static [FpTest] = &[fp(350687), fp(667633), fp(981686)];
+
+        fn mul_base_by_frob_coeff(c1: &mut FpTest, c2: &mut FpTest, power: usize) {
+            *c1 *= Self::FROBENIUS_COEFF_C1[power % 3];
+            *c2 *= Self::FROBENIUS_COEFF_C2[power % 3];
+        }
+    }
+
+    type Fp3Test = CubicExtField<Fp3TestParams>;
+
+    #[test]
+    fn test_arithmetic() {
+        let mut rng = thread_rng();
+        let (zero, one) = (Fp3Test::zero(), Fp3Test::one());
+
+        // v^3 = 2
+        let v = Fp3Test::new(FpTest::zero(), FpTest::one(), FpTest::zero());
+        assert_eq!(v * v * v, one.double());
+
+        for _ in 0..20 {
+            let a = Fp3Test::rand(&mut rng);
+            let b = Fp3Test::rand(&mut rng);
+            let c = Fp3Test::rand(&mut rng);
+
+            assert_eq!(a + zero, a);
+            assert_eq!(a * one, a);
+            assert_eq!(a - a, zero);
+            assert_eq!(a * b, b * a);
+            assert_eq!((a * b) * c, a * (b * c));
+            assert_eq!(a * (b + c), a * b + a * c);
+            assert_eq!(a.square(), a * a);
+            assert_eq!(a.double(), a + a);
+            assert_eq!(a * a.inverse().unwrap(), one);
+            assert_eq!((a * b) / b, a);
+        }
+        assert_eq!(zero.inverse(), None);
+    }
+
+    #[test]
+    fn test_frobenius() {
+        let mut rng = thread_rng();
+
+        for _ in 0..10 {
+            let a = Fp3Test::rand(&mut rng);
+            let b = Fp3Test::rand(&mut rng);
+            assert_eq!(a.frobenius_map(0), a);
+            assert_eq!(a.frobenius_map(1), a.exp(&FpTestParams::MODULUS));
+            assert_eq!(a.frobenius_map(2), a.frobenius_map(1).frobenius_map(1));
+            assert_eq!(a.frobenius_map(3), a);
+            assert_eq!(
+                (a * b).frobenius_map(1),
+                a.frobenius_map(1) * b.frobenius_map(1)
+            );
+        }
+    }
+}
@@ -0,0 +1,162 @@
+use crate::biginteger::LimbInt;
+use crate::ff::GeneralReduction;
+use cryp_std::fmt::Debug;
+
+/// Goldilocks primes `p = phi^2 - phi - 1` with `phi = 2^(32 * N)`
+///
+/// These are the primes of the form `2^(64 * N) - 2^(32 * N) - 1` introduced by Hamburg
+/// in "Ed448-Goldilocks, a new elliptic curve", https://eprint.iacr.org/2015/625,
+/// for example `2^448 - 2^224 - 1` with `N = 7`.
+///
+/// Assumes N > 1
+pub trait GoldilocksParameters<const N: usize>: 'static + Debug {
+    /// 2^(64 * N) - 2^(32 * N) - 1, hard-coded
+    const MODULUS: [u64; N];
+}
+
+/// The reduction for Goldilocks primes, using `phi^2 = phi + 1 mod p`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GoldilocksReduction<const N: usize, P: GoldilocksParameters<N>> {
+    _marker: cryp_std::marker::PhantomData<P>,
+}
+
+impl<const N: usize, P: GoldilocksParameters<N>> GoldilocksReduction<N, P> {
+    /// The limb of index `i` of `a * 2^(32 * N)`, for `a` of `N` limbs.
+    fn shifted_limb(a: &[u64; N], i: usize) -> u64 {
+        let limb = |j: usize| if j < N { a[j] } else { 0 };
+        let shift = N / 2;
+
+        if i < shift {
+            return 0;
+        }
+        if N.is_multiple_of(2) {
+            return limb(i - shift);
+        }
+        // shift by an extra half limb
+        let low = if i > shift {
+            limb(i - shift - 1) >> 32
+        } else {
+            0
+        };
+        (limb(i - shift) << 32) | low
+    }
+}
+
+impl<const N: usize, P: GoldilocksParameters<N>> GeneralReduction<N> for GoldilocksReduction<N, P> {
+    type Limb = u64;
+
+    const MODULUS: [Self::Limb; N] = P::MODULUS;
+
+    fn reduction(element: &([Self::Limb; N], [Self::Limb; N])) -> [Self::Limb; N] {
+        let (mut a_l, mut a_h) = *element;
+
+        // Note that a = a_l + 2^(64N) * a_h = a_l + a_h + 2^(32N) * a_h mod p
+        //
+        // Compute the right hand side as (a_l, a_h) and repeat until a_h is zero.
+        // Every round divides the size of a_h by about 2^(32N).
+        while a_h != [0; N] {
+            let (mut low, mut high) = ([0u64; N], [0u64; N]);
+            let mut carry = 0u128;
+
+            for i in 0..2 * N {
+                let mut sum = carry + Self::shifted_limb(&a_h, i) as u128;
+                if i < N {
+                    sum += a_l[i] as u128 + a_h[i] as u128;
+                }
+                if i < N {
+                    low[i] = sum as u64;
+                } else {
+                    high[i - N] = sum as u64;
+                }
+                carry = sum >> 64;
+            }
+            (a_l, a_h) = (low, high);
+        }
+
+        let modulus = LimbInt::from(Self::MODULUS);
+        let mut a_l = LimbInt::from(a_l);
+        while modulus.le(&a_l) {
+            a_l = a_l.carrying_sub(modulus, false).0;
+        }
+        a_l.limbs
+    }
+}
+
+// ================================
+
+// tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helper::big_int_from_u64;
+    use cryp_std::rand::{thread_rng, UniformRand};
+    use cryp_std::vec::Vec;
+    use num_bigint::BigUint;
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    struct Fp448Params;
+
+    impl GoldilocksParameters<7> for Fp448Params {
+        const MODULUS: [u64; 7] = [
+            18446744073709551615,
+            18446744073709551615,
+            18446744073709551615,
+            18446744069414584319,
+            18446744073709551615,
+            18446744073709551615,
+            18446744073709551615,
+        ];
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    struct Fp128Params;
+
+    // 2^128 - 2^64 - 1 is not prime, but the reduction does not depend on it
+    impl GoldilocksParameters<2> for Fp128Params {
+        const MODULUS: [u64; 2] = [18446744073709551615, 18446744073709551614];
+    }
+
+    fn check_reduction<const N: usize, P: GoldilocksParameters<N>>() {
+        let modulus = big_int_from_u64(P::MODULUS.as_slice());
+        let two = BigUint::from(2u64);
+        assert_eq!(
+            modulus,
+            two.pow(64 * N as u32) - two.pow(32 * N as u32) - 1u32
+        );
+
+        let mut rng = thread_rng();
+        let max = [u64::MAX; N];
+        for i in 0..100 {
+            let mut low = [0u64; N];
+            let mut high = [0u64; N];
+            for j in 0..N {
+                low[j] = u64::rand(&mut rng);
+                high[j] = u64::rand(&mut rng);
+            }
+            // the edge cases
+            if i == 0 {
+                (low, high) = (max, max);
+            } else if i == 1 {
+                (low, high) = (P::MODULUS, [0; N]);
+            }
+
+            let reduced = GoldilocksReduction::<N, P>::reduction(&(low, high));
+            let input: Vec<u64> = low.into_iter().chain(high).collect();
+
+            let n_reduced = big_int_from_u64(reduced.as_slice());
+            assert!(n_reduced < modulus);
+            assert_eq!(n_reduced, big_int_from_u64(input.as_slice()) % &modulus);
+        }
+    }
+
+    #[test]
+    fn test_goldilocks_reduction_448() {
+        check_reduction::<7, Fp448Params>();
+    }
+
+    #[test]
+    fn test_goldilocks_reduction_even() {
+        check_reduction::<2, Fp128Params>();
+    }
+}
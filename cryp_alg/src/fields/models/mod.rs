@@ -1,2 +1,154 @@
+/// Implements the operator traits of an extension field that follow from
+/// `AddAssign<&Self>`, `SubAssign<&Self>`, `MulAssign<&Self>` and `Field::inverse`.
+macro_rules! impl_extension_field_ops {
+    ($field:ident, $params:ident) => {
+        impl<P: $params> AddAssign for $field<P> {
+            fn add_assign(&mut self, other: Self) {
+                *self += &other;
+            }
+        }
+
+        impl<P: $params> Add for $field<P> {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                let mut result = self;
+                result += &other;
+                result
+            }
+        }
+
+        impl<P: $params> Add<&$field<P>> for $field<P> {
+            type Output = Self;
+
+            fn add(self, other: &Self) -> Self {
+                let mut result = self;
+                result += other;
+                result
+            }
+        }
+
+        impl<P: $params> SubAssign for $field<P> {
+            fn sub_assign(&mut self, other: Self) {
+                *self -= &other;
+            }
+        }
+
+        impl<P: $params> Sub for $field<P> {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                let mut result = self;
+                result -= &other;
+                result
+            }
+        }
+
+        impl<P: $params> Sub<&$field<P>> for $field<P> {
+            type Output = Self;
+
+            fn sub(self, other: &Self) -> Self {
+                let mut result = self;
+                result -= other;
+                result
+            }
+        }
+
+        impl<P: $params> MulAssign for $field<P> {
+            fn mul_assign(&mut self, other: Self) {
+                *self *= &other;
+            }
+        }
+
+        impl<P: $params> Mul for $field<P> {
+            type Output = Self;
+
+            fn mul(self, other: Self) -> Self {
+                let mut result = self;
+                result *= &other;
+                result
+            }
+        }
+
+        impl<P: $params> Mul<&$field<P>> for $field<P> {
+            type Output = Self;
+
+            fn mul(self, other: &Self) -> Self {
+                let mut result = self;
+                result *= other;
+                result
+            }
+        }
+
+        impl<P: $params> DivAssign<&$field<P>> for $field<P> {
+            #[allow(clippy::suspicious_op_assign_impl)]
+            fn div_assign(&mut self, other: &Self) {
+                *self *= &other.inverse().expect("Division by zero");
+            }
+        }
+
+        impl<P: $params> DivAssign for $field<P> {
+            fn div_assign(&mut self, other: Self) {
+                *self /= &other;
+            }
+        }
+
+        impl<P: $params> Div for $field<P> {
+            type Output = Self;
+
+            fn div(self, other: Self) -> Self {
+                let mut result = self;
+                result /= &other;
+                result
+            }
+        }
+
+        impl<P: $params> Div<&$field<P>> for $field<P> {
+            type Output = Self;
+
+            fn div(self, other: &Self) -> Self {
+                let mut result = self;
+                result /= other;
+                result
+            }
+        }
+
+        impl<P: $params> iter::Sum for $field<P> {
+            fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(Self::zero(), |acc, x| acc + x)
+            }
+        }
+
+        impl<'a, P: $params> iter::Sum<&'a Self> for $field<P> {
+            fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+                iter.fold(Self::zero(), |acc, x| acc + x)
+            }
+        }
+
+        impl<P: $params> iter::Product for $field<P> {
+            fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(Self::one(), |acc, x| acc * x)
+            }
+        }
+
+        impl<'a, P: $params> iter::Product<&'a Self> for $field<P> {
+            fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+                iter.fold(Self::one(), |acc, x| acc * x)
+            }
+        }
+
+        impl<P: $params> Clone for $field<P> {
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        impl<P: $params> Copy for $field<P> {}
+    };
+}
+
+pub(crate) mod cubic_extension;
+pub(crate) mod goldilocks;
 pub(crate) mod montgomery;
-pub(crate) mod solinas;
\ No newline at end of file
+pub(crate) mod quadratic_extension;
+pub(crate) mod solinas;
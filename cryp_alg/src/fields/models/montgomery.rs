@@ -1,4 +1,8 @@
-use crate::{biginteger::{Limb, LimbInt}, fields::abstract_operations::ArithmeticOperations};
+use crate::{
+    biginteger::{Limb, LimbInt},
+    fields::abstract_operations::ArithmeticOperations,
+    FftField, PrimeField, F,
+};
 use cryp_std::rand::{Rng, UniformRand};
 
 use crate::PrimeFieldOperations;
@@ -17,6 +21,14 @@ pub trait MontParameters<const N: usize>: 'static + Debug {
     const R: [Self::Limb; N];
 }
 
+/// Parameters of a Montgomery field whose multiplicative group has a large 2-power subgroup.
+pub trait FftParameters<const N: usize>: MontParameters<N> {
+    /// The largest `k` such that `2^k` divides `p - 1`.
+    const TWO_ADICITY: u32;
+    /// A primitive `2^TWO_ADICITY`-th root of unity in the regular (non-Montgomery) representation.
+    const TWO_ADIC_ROOT_OF_UNITY: [Self::Limb; N];
+}
+
 /// Montgomery representation of a prime field element
 ///
 /// The element is represented as `x*R mod p`, where `R = b^N`
@@ -52,9 +64,13 @@ impl<const N: usize, P: MontParameters<N>> MontgomeryOperations<N, P> {
             ui[i] = u;
 
             let (c_l, c_h) = modulus.carrying_mul(ui.into(), LimbInt::zero());
-            // Now add it to a
-            (a_l, c) = a_l.carrying_add(c_l, c);
-            (a_r, c) = a_r.carrying_add(c_h, c);
+            // Now add it to a, the carry out of the top limb can only happen once
+            // since the final result is less than 2 * p * b^n
+            let carry_l;
+            let carry_r;
+            (a_l, carry_l) = a_l.carrying_add(c_l, P::Limb::NO);
+            (a_r, carry_r) = a_r.carrying_add(c_h, carry_l);
+            c = c | carry_r;
         }
         // deal with final carry
         if c != P::Limb::NO {
@@ -123,12 +139,17 @@ impl<const N: usize, P: MontParameters<N>> PrimeFieldOperations for MontgomeryOp
     }
 
     fn rand<R: Rng + ?Sized>(rng: &mut R) -> Self::BigInt {
-        // Takes a random collection of limbs and reject it if it is greater than the modulus
+        // Takes a random collection of limbs and reject it if it is greater than the modulus.
+        //
+        // The top limb is masked to the bit length of the modulus so that the expected
+        // number of trials is at most two.
+        let mask = P::Limb::MAX >> P::MODULUS[N - 1].leading_zeros();
         let mut res = [P::Limb::ZERO; N];
         loop {
-            for i in 0..N {
-                res[i] = P::Limb::rand(rng);
+            for limb in res.iter_mut() {
+                *limb = P::Limb::rand(rng);
             }
+            res[N - 1] = res[N - 1] & mask;
             let element = Self::BigInt::from(res);
             if element.le(&P::MODULUS.into()) {
                 break;
@@ -154,7 +175,7 @@ impl<const N: usize, P: MontParameters<N>> PrimeFieldOperations for MontgomeryOp
         let modulus = LimbInt::from(P::MODULUS);
         let (d, c_1) = lhs.carrying_sub(*other, P::Limb::NO);
 
-        let (e, c2) = d.carrying_add(modulus, P::Limb::NO);
+        let (e, _c2) = d.carrying_add(modulus, P::Limb::NO);
 
         if c_1 == P::Limb::NO {
             *lhs = d;
@@ -164,7 +185,7 @@ impl<const N: usize, P: MontParameters<N>> PrimeFieldOperations for MontgomeryOp
     }
 
     fn mul_assign(lhs: &mut Self::BigInt, other: &Self::BigInt) {
-        *lhs = Self::montgomery_mul(&lhs, other)
+        *lhs = Self::montgomery_mul(lhs, other)
     }
 }
 
@@ -210,10 +231,17 @@ impl<const N: usize, P: MontParameters<N>> ArithmeticOperations for MontgomeryOp
     }
 
     fn mul_assign(lhs: &mut Self::BigInt, other: &Self::BigInt) {
-        *lhs = Self::montgomery_mul(&lhs, other)
+        *lhs = Self::montgomery_mul(lhs, other)
     }
 }
 
+impl<const N: usize, P: FftParameters<N>> FftField for F<MontgomeryOperations<N, P>> {
+    const TWO_ADICITY: u32 = P::TWO_ADICITY;
+
+    fn two_adic_root_of_unity() -> Self {
+        Self::from_int(&P::TWO_ADIC_ROOT_OF_UNITY.into())
+    }
+}
 
 // =================================================================================================
 
@@ -274,7 +302,7 @@ mod tests {
         let product: Vec<u32> = product_l
             .limbs
             .into_iter()
-            .chain(product_r.limbs.into_iter())
+            .chain(product_r.limbs)
             .collect();
 
         let n_a = BigUint::new(a.to_vec());
@@ -326,7 +354,7 @@ mod tests {
         let product: Vec<u64> = product_l
             .limbs
             .into_iter()
-            .chain(product_r.limbs.into_iter())
+            .chain(product_r.limbs)
             .collect();
 
         let n_a = big_int_from_u64(a.as_slice());
@@ -348,6 +376,56 @@ mod tests {
         assert_eq!((n_mont_red * &r) % &modulus, n_product % modulus);
     }
 
+    /// A modulus close to `2^128`, for which the addition of `u * p * b^i` overflows the
+    /// double-length integer before the last step of the reduction. The carry must be kept
+    /// until the end, which random inputs rarely check.
+    #[test]
+    fn test_montgomery_reduction_carry() {
+        use cryp_std::rand::thread_rng;
+        type Int = LimbInt<u64, 2>;
+
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        struct TestParams3;
+
+        impl MontParameters<2> for TestParams3 {
+            type Limb = u64;
+
+            // 2^128 - 159
+            const MODULUS: [u64; 2] = [18446744073709551457, 18446744073709551615];
+
+            const MP: u64 = 13109950190749555551;
+
+            const R2: [u64; 2] = [25281, 0];
+
+            const R: [u64; 2] = [159, 0];
+        }
+
+        let modulus = big_int_from_u64(TestParams3::MODULUS.as_slice());
+        let r = big_int_from_u64(TestParams3::R.as_slice());
+        assert_eq!(&r, &(BigUint::from(2u64).pow(128) % &modulus));
+
+        let p_minus_one = [18446744073709551456, 18446744073709551615];
+        let mut rng = thread_rng();
+        let mut inputs = Vec::from([(p_minus_one, p_minus_one)]);
+        for _ in 0..100 {
+            let a = [u64::rand(&mut rng), u64::MAX];
+            let b = [u64::rand(&mut rng), u64::MAX - 1];
+            inputs.push((a, b));
+        }
+
+        for (a, b) in inputs {
+            let (product_l, product_r) = Int::from(a).carrying_mul(Int::from(b), Int::zero());
+            let mont_red = MontgomeryOperations::<2, TestParams3>::montgomery_reduction(&(
+                product_l, product_r,
+            ));
+
+            let n_product = big_int_from_u64(a.as_slice()) * big_int_from_u64(b.as_slice());
+            let n_mont_red = big_int_from_u64(mont_red.limbs.as_slice());
+            assert!(n_mont_red < modulus);
+            assert_eq!((n_mont_red * &r) % &modulus, n_product % &modulus);
+        }
+    }
+
     #[test]
     fn test_montgomery_reduction_25519() {
         use cryp_std::rand::thread_rng;
@@ -403,7 +481,7 @@ mod tests {
             let product: Vec<u64> = product_l
                 .limbs
                 .into_iter()
-                .chain(product_r.limbs.into_iter())
+                .chain(product_r.limbs)
                 .collect();
 
             let n_a = big_int_from_u64(a.as_slice());
@@ -431,4 +509,84 @@ mod tests {
             assert_eq!((n_mont_red * &r) % &modulus, n_product % modulus);
         }
     }
+
+    /// The modulus of `TestParams1` has a top limb of 10 bits, so that most random limbs
+    /// would be rejected without the mask.
+    #[test]
+    fn test_rand() {
+        use cryp_std::rand::thread_rng;
+
+        let modulus = BigUint::from_slice(&TestParams1::MODULUS);
+        let mut rng = thread_rng();
+        let mut above_half = 0;
+        for _ in 0..1000 {
+            let x = <MontgomeryOperations<4, TestParams1> as PrimeFieldOperations>::rand(&mut rng);
+            let n_x = BigUint::from_slice(x.limbs.as_slice());
+            assert!(n_x < modulus);
+            if n_x * 2u32 > modulus {
+                above_half += 1;
+            }
+        }
+        assert!(above_half > 400 && above_half < 600);
+    }
+
+    #[test]
+    fn test_sqrt() {
+        use crate::{Field, Zero};
+        use cryp_std::rand::thread_rng;
+
+        // 998244353 = 119 * 2^23 + 1, so that Tonelli-Shanks runs its full loop
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        struct Fp998244353Params;
+
+        impl MontParameters<1> for Fp998244353Params {
+            type Limb = u64;
+
+            const MODULUS: [u64; 1] = [998244353];
+
+            const R: [u64; 1] = [932051910];
+
+            const R2: [u64; 1] = [299560064];
+
+            const MP: u64 = 17450252288407896063;
+        }
+
+        // 1000003 = 3 mod 4
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        struct Fp1000003Params;
+
+        impl MontParameters<1> for Fp1000003Params {
+            type Limb = u64;
+
+            const MODULUS: [u64; 1] = [1000003];
+
+            const R: [u64; 1] = [350687];
+
+            const R2: [u64; 1] = [3026];
+
+            const MP: u64 = 2336937208910341525;
+        }
+
+        fn check_sqrt<P: MontParameters<1, Limb = u64>>() {
+            type Fp<P> = F<MontgomeryOperations<1, P>>;
+
+            let mut rng = thread_rng();
+            assert_eq!(Fp::<P>::zero().sqrt(), Some(Fp::<P>::zero()));
+            let mut non_residues = 0;
+            for _ in 0..100 {
+                let a = Fp::<P>::rand(&mut rng);
+                let root = a.square().sqrt().unwrap();
+                assert!(root == a || root == -a);
+
+                match a.sqrt() {
+                    Some(root) => assert_eq!(root.square(), a),
+                    None => non_residues += 1,
+                }
+            }
+            assert!(non_residues > 0);
+        }
+
+        check_sqrt::<Fp998244353Params>();
+        check_sqrt::<Fp1000003Params>();
+    }
 }
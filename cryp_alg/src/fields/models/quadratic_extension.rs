@@ -0,0 +1,327 @@
+//! Quadratic extension fields
+//!
+//! Elements of the extension `F[u] / (u^2 - NONRESIDUE)` of a field `F` are represented
+//! as `c0 + c1 * u` with `c0, c1` in `F`.
+
+use crate::{Field, One, PrimeField, Zero};
+use cryp_std::{
+    fmt::{Debug, Display},
+    hash::{Hash, Hasher},
+    iter,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+    rand::{Rng, UniformRand},
+};
+
+/// Parameters of a quadratic extension `F[u] / (u^2 - NONRESIDUE)`.
+pub trait QuadExtParameters: 'static + Send + Sync + Debug {
+    /// The field `F` being extended.
+    type BaseField: Field;
+    /// The field containing the coefficients of the Frobenius map.
+    type FrobCoeff: Field;
+
+    /// A quadratic non-residue of the base field.
+    const NONRESIDUE: Self::BaseField;
+
+    /// The coefficients `NONRESIDUE^((q^i - 1) / 2)`, where `q` is the characteristic,
+    /// for `i` up to the degree of the extension over the prime field.
+    const FROBENIUS_COEFF_C1: &'static [Self::FrobCoeff];
+
+    /// Multiplies an element of the base field by `NONRESIDUE`.
+    ///
+    /// The default implementation is a multiplication, but it is usually cheaper for
+    /// a specific non-residue.
+    fn mul_base_by_nonresidue(element: &Self::BaseField) -> Self::BaseField {
+        Self::NONRESIDUE * element
+    }
+
+    /// Multiplies the coefficient `c1` by the Frobenius coefficient of the given power.
+    fn mul_base_by_frob_coeff(c1: &mut Self::BaseField, power: usize);
+}
+
+/// An element `c0 + c1 * u` of a quadratic extension field.
+pub struct QuadExtField<P: QuadExtParameters> {
+    pub c0: P::BaseField,
+    pub c1: P::BaseField,
+}
+
+impl<P: QuadExtParameters> QuadExtField<P> {
+    pub const fn new(c0: P::BaseField, c1: P::BaseField) -> Self {
+        Self { c0, c1 }
+    }
+
+    /// The conjugate `c0 - c1 * u`.
+    pub fn conjugate(&self) -> Self {
+        Self::new(self.c0, -self.c1)
+    }
+
+    /// The norm `c0^2 - NONRESIDUE * c1^2` to the base field.
+    pub fn norm(&self) -> P::BaseField {
+        self.c0.square() - P::mul_base_by_nonresidue(&self.c1.square())
+    }
+
+    /// Multiplies both coefficients by an element of the base field.
+    pub fn mul_by_base(&self, element: &P::BaseField) -> Self {
+        Self::new(self.c0 * element, self.c1 * element)
+    }
+}
+
+impl<P: QuadExtParameters> QuadExtField<P>
+where
+    P::BaseField: PrimeField,
+{
+    /// A square root of the element, if it exists.
+    ///
+    /// The root is computed from square roots in the base field using the norm, as in
+    /// "Square root computation over even extension fields" by Adj and Rodríguez-Henríquez.
+    /// It does not run in constant time.
+    pub fn sqrt(&self) -> Option<Self> {
+        if self.c1 == P::BaseField::zero() {
+            // c0 is a square in the base field, or c0 = NONRESIDUE * t^2 = (t * u)^2
+            return match self.c0.sqrt() {
+                Some(root) => Some(Self::new(root, P::BaseField::zero())),
+                None => {
+                    let t = (self.c0 * P::NONRESIDUE.inverse()?).sqrt()?;
+                    Some(Self::new(P::BaseField::zero(), t))
+                }
+            };
+        }
+
+        // (x0 + x1 * u)^2 = self gives x0^2 = (c0 +- sqrt(norm)) / 2 and x1 = c1 / (2 * x0)
+        let norm_root = self.norm().sqrt()?;
+        let half = P::BaseField::one().double().inverse()?;
+        let x0 = ((self.c0 + norm_root) * half)
+            .sqrt()
+            .or_else(|| ((self.c0 - norm_root) * half).sqrt())?;
+        let x1 = self.c1 * x0.double().inverse()?;
+
+        let root = Self::new(x0, x1);
+        (root.square() == *self).then_some(root)
+    }
+}
+
+impl<P: QuadExtParameters> Zero for QuadExtField<P> {
+    fn zero() -> Self {
+        Self::new(P::BaseField::zero(), P::BaseField::zero())
+    }
+}
+
+impl<P: QuadExtParameters> One for QuadExtField<P> {
+    fn one() -> Self {
+        Self::new(P::BaseField::one(), P::BaseField::zero())
+    }
+}
+
+impl<P: QuadExtParameters> Field for QuadExtField<P> {
+    fn inverse(&self) -> Option<Self> {
+        // (c0 + c1 * u)^-1 = (c0 - c1 * u) / norm
+        self.norm()
+            .inverse()
+            .map(|inverse| self.conjugate().mul_by_base(&inverse))
+    }
+
+    fn square_in_place(&mut self) {
+        let v0 = self.c0 * self.c1;
+        let c0 = self.c0.square() + P::mul_base_by_nonresidue(&self.c1.square());
+        *self = Self::new(c0, v0.double());
+    }
+
+    fn double_in_place(&mut self) {
+        self.c0.double_in_place();
+        self.c1.double_in_place();
+    }
+
+    fn frobenius_map(&self, power: usize) -> Self {
+        let mut c1 = self.c1.frobenius_map(power);
+        P::mul_base_by_frob_coeff(&mut c1, power);
+        Self::new(self.c0.frobenius_map(power), c1)
+    }
+}
+
+impl<P: QuadExtParameters> AddAssign<&QuadExtField<P>> for QuadExtField<P> {
+    fn add_assign(&mut self, other: &Self) {
+        self.c0 += other.c0;
+        self.c1 += other.c1;
+    }
+}
+
+impl<P: QuadExtParameters> SubAssign<&QuadExtField<P>> for QuadExtField<P> {
+    fn sub_assign(&mut self, other: &Self) {
+        self.c0 -= other.c0;
+        self.c1 -= other.c1;
+    }
+}
+
+impl<P: QuadExtParameters> MulAssign<&QuadExtField<P>> for QuadExtField<P> {
+    fn mul_assign(&mut self, other: &Self) {
+        // Karatsuba multiplication
+        let v0 = self.c0 * other.c0;
+        let v1 = self.c1 * other.c1;
+        let c1 = (self.c0 + self.c1) * (other.c0 + other.c1) - v0 - v1;
+        *self = Self::new(v0 + P::mul_base_by_nonresidue(&v1), c1);
+    }
+}
+
+impl<P: QuadExtParameters> Neg for QuadExtField<P> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.c0, -self.c1)
+    }
+}
+
+impl_extension_field_ops!(QuadExtField, QuadExtParameters);
+
+impl<P: QuadExtParameters> PartialEq for QuadExtField<P> {
+    fn eq(&self, other: &Self) -> bool {
+        self.c0 == other.c0 && self.c1 == other.c1
+    }
+}
+
+impl<P: QuadExtParameters> Eq for QuadExtField<P> {}
+
+impl<P: QuadExtParameters> Hash for QuadExtField<P> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.c0.hash(state);
+        self.c1.hash(state);
+    }
+}
+
+impl<P: QuadExtParameters> Debug for QuadExtField<P> {
+    fn fmt(&self, f: &mut cryp_std::fmt::Formatter<'_>) -> cryp_std::fmt::Result {
+        write!(f, "QuadExtField({:?}, {:?})", self.c0, self.c1)
+    }
+}
+
+impl<P: QuadExtParameters> Display for QuadExtField<P> {
+    fn fmt(&self, f: &mut cryp_std::fmt::Formatter<'_>) -> cryp_std::fmt::Result {
+        write!(f, "({} + {} * u)", self.c0, self.c1)
+    }
+}
+
+impl<P: QuadExtParameters> UniformRand for QuadExtField<P> {
+    fn rand<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        Self::new(P::BaseField::rand(rng), P::BaseField::rand(rng))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{LimbInt, MontParameters, MontgomeryOperations, F};
+    use cryp_std::rand::thread_rng;
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    struct FpTestParams;
+
+    impl MontParameters<1> for FpTestParams {
+        type Limb = u64;
+
+        // 1000003 = 3 mod 4, so -1 is a quadratic non-residue
+        const MODULUS: [u64; 1] = [1000003];
+
+        const R: [u64; 1] = [350687];
+
+        const R2: [u64; 1] = [3026];
+
+        const MP: u64 = 2336937208910341525;
+    }
+
+    type FpTest = F<MontgomeryOperations<1, FpTestParams>>;
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    struct Fp2TestParams;
+
+    impl QuadExtParameters for Fp2TestParams {
+        type BaseField = FpTest;
+        type FrobCoeff = FpTest;
+
+        // -1 in Montgomery form
+        const NONRESIDUE: FpTest = FpTest::from_RAW_limbs(LimbInt::from_limbs([649316]));
+
+        const FROBENIUS_COEFF_C1: &'static [FpTest] = &[
+            FpTest::from_RAW_limbs(LimbInt::from_limbs([350687])),
+            FpTest::from_RAW_limbs(LimbInt::from_limbs([649316])),
+        ];
+
+        fn mul_base_by_nonresidue(element: &FpTest) -> FpTest {
+            -*element
+        }
+
+        fn mul_base_by_frob_coeff(c1: &mut FpTest, power: usize) {
+            *c1 *= Self::FROBENIUS_COEFF_C1[power % 2];
+        }
+    }
+
+    type Fp2Test = QuadExtField<Fp2TestParams>;
+
+    #[test]
+    fn test_arithmetic() {
+        let mut rng = thread_rng();
+        let (zero, one) = (Fp2Test::zero(), Fp2Test::one());
+
+        // u^2 = -1
+        let u = Fp2Test::new(FpTest::zero(), FpTest::one());
+        assert_eq!(u.square(), -one);
+        assert_eq!(u * u, -one);
+
+        for _ in 0..20 {
+            let a = Fp2Test::rand(&mut rng);
+            let b = Fp2Test::rand(&mut rng);
+            let c = Fp2Test::rand(&mut rng);
+
+            assert_eq!(a + zero, a);
+            assert_eq!(a * one, a);
+            assert_eq!(a - a, zero);
+            assert_eq!(a * b, b * a);
+            assert_eq!((a * b) * c, a * (b * c));
+            assert_eq!(a * (b + c), a * b + a * c);
+            assert_eq!(a.square(), a * a);
+            assert_eq!(a.double(), a + a);
+            assert_eq!(a * a.inverse().unwrap(), one);
+            assert_eq!((a * b) / b, a);
+            assert_eq!((a * b).norm(), a.norm() * b.norm());
+            assert_eq!(a * a.conjugate(), Fp2Test::new(a.norm(), FpTest::zero()));
+        }
+        assert_eq!(zero.inverse(), None);
+    }
+
+    #[test]
+    fn test_sqrt() {
+        let mut rng = thread_rng();
+        let u = Fp2Test::new(FpTest::zero(), FpTest::one());
+        assert_eq!(Fp2Test::zero().sqrt(), Some(Fp2Test::zero()));
+        assert_eq!(u.sqrt().unwrap().square(), u);
+
+        // -1 is not a square in the base field but it is the square of u
+        let minus_one = -Fp2Test::one();
+        assert_eq!(minus_one.sqrt().unwrap().square(), minus_one);
+
+        let mut non_squares = 0;
+        for _ in 0..100 {
+            let a = Fp2Test::rand(&mut rng);
+            let square = a.square();
+            let root = square.sqrt().unwrap();
+            assert!(root == a || root == -a);
+
+            match a.sqrt() {
+                Some(root) => assert_eq!(root.square(), a),
+                None => non_squares += 1,
+            }
+        }
+        // half of the non-zero elements are squares
+        assert!(non_squares > 20 && non_squares < 80);
+    }
+
+    #[test]
+    fn test_frobenius() {
+        let mut rng = thread_rng();
+
+        for _ in 0..10 {
+            let a = Fp2Test::rand(&mut rng);
+            assert_eq!(a.frobenius_map(0), a);
+            assert_eq!(a.frobenius_map(1), a.exp(&FpTestParams::MODULUS));
+            assert_eq!(a.frobenius_map(1), a.conjugate());
+            assert_eq!(a.frobenius_map(2), a);
+        }
+    }
+}
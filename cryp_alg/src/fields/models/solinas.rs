@@ -1,4 +1,4 @@
-use crate::ff::{GeneralReduction, GeneralReductionOperations};
+use crate::ff::GeneralReduction;
 use crate::biginteger::{Limb, LimbInt};
 use cryp_std::fmt::Debug;
 
@@ -121,7 +121,7 @@ mod tests {
             let product: Vec<u64> = product_l
                 .limbs
                 .into_iter()
-                .chain(product_r.limbs.into_iter())
+                .chain(product_r.limbs)
                 .collect();
 
             let n_a = big_int_from_u64(a.as_slice());
@@ -132,7 +132,7 @@ mod tests {
             let n_red = big_int_from_u64(reduced.limbs.as_slice());
 
             // check c_vec multiplication
-            let mut c_vec = LimbInt::from(Fp25519Params::C);
+            let c_vec = LimbInt::from(Fp25519Params::C);
 
             let (r, t) = Int::from(a).carrying_mul(Int::from(c_vec), Int::from(b));
             let n_r = big_int_from_u64(r.limbs.as_slice());
@@ -1,3 +1,11 @@
+mod modp;
+mod schnorr;
 mod traits;
 
+pub use modp::{
+    FpModp1024, FpModp1024Params, FpModp2048, FpModp2048Params, GroupModp1024, GroupModp2048,
+    Modp1024Parameters, Modp2048Parameters, ScalarModp1024, ScalarModp1024Params, ScalarModp2048,
+    ScalarModp2048Params,
+};
+pub use schnorr::{SchnorrGroup, SchnorrParameters, SchnorrPublic};
 pub use traits::{Group, PrimeGroup};
@@ -0,0 +1,505 @@
+//! Schnorr groups from [RFC 5114](https://www.rfc-editor.org/rfc/rfc5114)
+//!
+//! - `GroupModp1024`: the 1024-bit MODP group with a 160-bit prime order subgroup (section 2.1).
+//! - `GroupModp2048`: the 2048-bit MODP group with a 256-bit prime order subgroup (section 2.3).
+//!
+
+use super::schnorr::{SchnorrGroup, SchnorrParameters};
+use crate::{LimbInt, MontParameters, MontgomeryOperations, F};
+
+pub type FpModp1024 = F<MontgomeryOperations<16, FpModp1024Params>>;
+pub type ScalarModp1024 = F<MontgomeryOperations<3, ScalarModp1024Params>>;
+pub type GroupModp1024 = SchnorrGroup<Modp1024Parameters>;
+
+pub type FpModp2048 = F<MontgomeryOperations<32, FpModp2048Params>>;
+pub type ScalarModp2048 = F<MontgomeryOperations<4, ScalarModp2048Params>>;
+pub type GroupModp2048 = SchnorrGroup<Modp2048Parameters>;
+
+/// Parameters for the 1024-bit prime field of RFC 5114, section 2.1
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FpModp1024Params;
+
+impl MontParameters<16usize> for FpModp1024Params {
+    type Limb = u64;
+
+    // the prime p of the RFC
+    const MODULUS: [Self::Limb; 16] = [
+        16077765716036174705,
+        16612932020998088456,
+        5025797140998003301,
+        11624264936007972029,
+        18060307861406993600,
+        5692504827098318648,
+        10973177293318026098,
+        12451289126165331312,
+        2636677523183469503,
+        1435721043477401891,
+        10968779805375746752,
+        6950147717089869080,
+        11126879321670695777,
+        5965474815443116998,
+        16038125721253401836,
+        12757448246557728797,
+    ];
+
+    const R: [Self::Limb; 16] = [
+        2368978357673376911,
+        1833812052711463159,
+        13420946932711548314,
+        6822479137701579586,
+        386436212302558015,
+        12754239246611232967,
+        7473566780391525517,
+        5995454947544220303,
+        15810066550526082112,
+        17011023030232149724,
+        7477964268333804863,
+        11496596356619682535,
+        7319864752038855838,
+        12481269258266434617,
+        2408618352456149779,
+        5689295827151822818,
+    ];
+
+    const R2: [Self::Limb; 16] = [
+        14436470177235882777,
+        16759823666155384005,
+        14420357763381919612,
+        9846242832317702189,
+        6404456976132158045,
+        9840714971936514156,
+        6237873477753400768,
+        12262994247526191263,
+        13544334763779110573,
+        13160568803215375527,
+        7827845772897519633,
+        15450146236922043916,
+        14147763793181881058,
+        10331901441609851567,
+        7142131368852412353,
+        12007244414664157731,
+    ];
+
+    const MP: Self::Limb = 8498437842751709807;
+}
+
+/// Parameters for the field of exponents of the 1024-bit group of RFC 5114, section 2.1
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ScalarModp1024Params;
+
+impl MontParameters<3usize> for ScalarModp1024Params {
+    type Limb = u64;
+
+    // 1399252811935680595399801714158014275474696840019
+    const MODULUS: [Self::Limb; 3] = [7257493200930480979, 9342962787904278141, 4112034439];
+
+    const R: [Self::Limb; 3] = [16312334095091155997, 4215676598971738884, 811712426];
+
+    const R2: [Self::Limb; 3] = [16172001212252817643, 885049899129054913, 3904690391];
+
+    const MP: Self::Limb = 17279742035199256357;
+}
+
+/// The 1024-bit MODP group with 160-bit prime order subgroup
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Modp1024Parameters;
+
+impl SchnorrParameters for Modp1024Parameters {
+    type BaseField = FpModp1024;
+    type ScalarField = ScalarModp1024;
+
+    const NAME: &'static str = "RFC5114-MODP-1024-160";
+
+    const GENERATOR: LimbInt<u64, 16> = LimbInt::from_limbs([
+        9610240611033789157,
+        9623996480058305060,
+        3276260259929295816,
+        15448090891779288995,
+        2143442948836467338,
+        12007243155814031187,
+        10420499554162444918,
+        15563270241661824737,
+        6814320618279225844,
+        1585856083603982442,
+        8610435052150780435,
+        2769689711973848651,
+        15438459365436583217,
+        17874872486859657343,
+        7450541765951068421,
+        11876497811329332242,
+    ]);
+
+    // (p - 1) / q
+    const COFACTOR: LimbInt<u64, 16> = LimbInt::from_limbs([
+        16304911699366523088,
+        17412744335394976010,
+        12536882429257582754,
+        17649026995724025646,
+        17659331599063566920,
+        14455976165896759818,
+        6714361613446564963,
+        5551333922729461622,
+        4493190777989034153,
+        18394504519385924958,
+        15200725501656274886,
+        10152536271270199250,
+        10469146807627784682,
+        3102466293,
+        0,
+        0,
+    ]);
+}
+
+/// Parameters for the 2048-bit prime field of RFC 5114, section 2.3
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FpModp2048Params;
+
+impl MontParameters<32usize> for FpModp2048Params {
+    type Limb = u64;
+
+    // the prime p of the RFC
+    const MODULUS: [Self::Limb; 32] = [
+        15783228734189475223,
+        7581941891926526410,
+        6996010137958838623,
+        11868466269690310418,
+        8498964804571054339,
+        14097276229718053059,
+        16056459717115635233,
+        13886946167378815062,
+        12955237068909338662,
+        2074694345729279734,
+        7485339806340572197,
+        17771599634420475353,
+        4240196737397050766,
+        17382889171307177131,
+        3265763283026086414,
+        13131030734668478352,
+        5754316942482751350,
+        13563048495307325276,
+        13349220488843729848,
+        7808111532484563080,
+        2513204233243049851,
+        10518691783532566720,
+        6524199841492330477,
+        2350329681708939965,
+        4320123492882094716,
+        1640393357203697578,
+        18084168317814352432,
+        16144833956755888084,
+        6714003739933293312,
+        10155324725699399922,
+        18427552769957779865,
+        9775316006467954236,
+    ];
+
+    const R: [Self::Limb; 32] = [
+        2663515339520076393,
+        10864802181783025205,
+        11450733935750712992,
+        6578277804019241197,
+        9947779269138497276,
+        4349467843991498556,
+        2390284356593916382,
+        4559797906330736553,
+        5491507004800212953,
+        16372049727980271881,
+        10961404267368979418,
+        675144439289076262,
+        14206547336312500849,
+        1063854902402374484,
+        15180980790683465201,
+        5315713339041073263,
+        12692427131226800265,
+        4883695578402226339,
+        5097523584865821767,
+        10638632541224988535,
+        15933539840466501764,
+        7928052290176984895,
+        11922544232217221138,
+        16096414392000611650,
+        14126620580827456899,
+        16806350716505854037,
+        362575755895199183,
+        2301910116953663531,
+        11732740333776258303,
+        8291419348010151693,
+        19191303751771750,
+        8671428067241597379,
+    ];
+
+    const R2: [Self::Limb; 32] = [
+        8953428242918120672,
+        2283122132577296822,
+        3639625430011332920,
+        4888220023103223502,
+        16763814469560431532,
+        14728437172333970079,
+        5494176420650247991,
+        455194678746002505,
+        17052575552791066704,
+        2860827514806933939,
+        13063258412955792363,
+        16424716878206713133,
+        1997238779448981980,
+        4756069183044663519,
+        10967396893915010311,
+        9479219181212662148,
+        4632992559806953976,
+        2530239852074915168,
+        16487652503507306873,
+        15913889901421455170,
+        10019409207372771618,
+        18152239062655145926,
+        14554132218036030628,
+        7966054297294392081,
+        10754601445023413045,
+        12194283825735642061,
+        5563762167159621090,
+        15346950827312940409,
+        9654068922569389639,
+        14797294272253029219,
+        13521741546194991162,
+        7050142036775588695,
+    ];
+
+    const MP: Self::Limb = 5902669834146956761;
+}
+
+/// Parameters for the field of exponents of the 2048-bit group of RFC 5114, section 2.3
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ScalarModp2048Params;
+
+impl MontParameters<4usize> for ScalarModp2048Params {
+    type Limb = u64;
+
+    // 63762351364972653564641699529205510489263266834182771617563631363277932854227
+    const MODULUS: [Self::Limb; 4] = [
+        11747834234657962963,
+        11074813815973704971,
+        12990520383333637538,
+        10157928619432321175,
+    ];
+
+    const R: [Self::Limb; 4] = [
+        6698909839051588653,
+        7371930257735846644,
+        5456223690375914077,
+        8288815454277230440,
+    ];
+
+    const R2: [Self::Limb; 4] = [
+        10074435073711136180,
+        13313630318989116569,
+        15108067999659415286,
+        5173258710059659921,
+    ];
+
+    const MP: Self::Limb = 10007763631695915941;
+}
+
+/// The 2048-bit MODP group with 256-bit prime order subgroup
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Modp2048Parameters;
+
+impl SchnorrParameters for Modp2048Parameters {
+    type BaseField = FpModp2048;
+    type ScalarField = ScalarModp2048;
+
+    const NAME: &'static str = "RFC5114-MODP-2048-256";
+
+    const GENERATOR: LimbInt<u64, 32> = LimbInt::from_limbs([
+        7371068844254959193,
+        6783309237781579138,
+        15440539958195606609,
+        3414581208425697016,
+        1750583302608275139,
+        14378045450840604342,
+        9209905454895410002,
+        12913293182592150583,
+        17064260026053232917,
+        13245855968408986009,
+        14431867598216202425,
+        15792500408768003604,
+        15011264492376091146,
+        11870674978745326720,
+        16125534023618342485,
+        13043650418187527393,
+        2095357517284479379,
+        7270632248805604483,
+        9998966244202966328,
+        8610291133529499746,
+        15993518015822697547,
+        114552832739923954,
+        11915085435996473957,
+        10381405139923417880,
+        13562436069148618594,
+        13707387279283799985,
+        18397183907801576789,
+        1214777075856983615,
+        573216343034929445,
+        5523578696300961876,
+        3348233248616332616,
+        4590061491382865163,
+    ]);
+
+    // (p - 1) / q
+    const COFACTOR: LimbInt<u64, 32> = LimbInt::from_limbs([
+        10536251896344831058,
+        10528122035897891852,
+        16764590148445262645,
+        5746243087569510721,
+        9596051417389022220,
+        4275330922536570766,
+        16429653067223118768,
+        14783463208531835101,
+        18425063475340127636,
+        17893627848957345520,
+        10834117842111405566,
+        9453957438406979154,
+        17719488636629134586,
+        7477193436329714524,
+        4564922180966903974,
+        5739507597686189331,
+        9778443770619485267,
+        15308788415137822899,
+        1496263684464784029,
+        18283189915045862659,
+        13214373350956314176,
+        5638640175080490829,
+        6133036466398892208,
+        18208219533234594155,
+        16839423707553200388,
+        17537901520700072110,
+        9955692150487343547,
+        17751921613820933282,
+        0,
+        0,
+        0,
+        0,
+    ]);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::groups::SchnorrPublic;
+    use crate::{Group, One, PrimeField, PrimeGroup, Zero};
+    use cryp_std::rand::{rngs::ThreadRng, thread_rng, UniformRand};
+    use cryp_std::vec::Vec;
+
+    #[test]
+    fn test_generator_order() {
+        let g = GroupModp1024::generator::<ThreadRng>(None);
+        assert!(GroupModp1024::is_valid(&g));
+
+        let g: GroupModp1024 = g.into();
+        let minus_one = -ScalarModp1024::one();
+        assert_ne!(g, GroupModp1024::identity());
+        assert_eq!(g * &minus_one + g, GroupModp1024::identity());
+        assert_eq!(
+            g.mul_int(&ScalarModp1024::MODULUS),
+            GroupModp1024::identity()
+        );
+
+        let g = GroupModp2048::generator::<ThreadRng>(None);
+        assert!(GroupModp2048::is_valid(&g));
+    }
+
+    #[test]
+    fn test_introspection() {
+        use cryp_std::string::ToString;
+
+        assert_eq!(GroupModp1024::name(), "RFC5114-MODP-1024-160");
+        assert_eq!(GroupModp1024::order(), ScalarModp1024::MODULUS);
+        assert_eq!(GroupModp1024::cofactor(), Modp1024Parameters::COFACTOR);
+
+        let g = GroupModp1024::generator::<ThreadRng>(None);
+        let hex = g.to_string();
+        assert_eq!(hex.len(), 256);
+        assert!(hex.starts_with("a4d1cbd5c3fd34126765a442efb99905f8104dd258ac507f"));
+        assert_eq!(GroupModp1024::from(g).to_string(), hex);
+        assert_eq!(GroupModp1024::identity().to_string(), "0".repeat(255) + "1");
+    }
+
+    #[test]
+    fn test_is_valid() {
+        let mut rng = thread_rng();
+
+        // zero, elements outside the subgroup and non-canonical residues are rejected
+        let zero = SchnorrPublic::<Modp1024Parameters>::new(FpModp1024::zero().as_int());
+        let minus_one = SchnorrPublic::<Modp1024Parameters>::new((-FpModp1024::one()).as_int());
+        let modulus = SchnorrPublic::<Modp1024Parameters>::new(FpModp1024::MODULUS);
+        assert!(!GroupModp1024::is_valid(&zero));
+        assert!(!GroupModp1024::is_valid(&minus_one));
+        assert!(!GroupModp1024::is_valid(&modulus));
+
+        let x = FpModp1024::rand(&mut rng);
+        let random = SchnorrPublic::<Modp1024Parameters>::new(x.as_int());
+        assert!(!GroupModp1024::is_valid(&random));
+
+        let mapped = GroupModp1024::map_to_group(&x).unwrap();
+        assert!(GroupModp1024::is_valid(&mapped.as_public().unwrap()));
+    }
+
+    #[test]
+    fn test_group_operations() {
+        let mut rng = thread_rng();
+        let g: GroupModp1024 = GroupModp1024::generator(Some(&mut rng)).into();
+        let a = ScalarModp1024::rand(&mut rng);
+        let b = ScalarModp1024::rand(&mut rng);
+
+        assert_eq!(g * &a + g * &b, g * &(a + b));
+        assert_eq!(g * &a - g * &b, g * &(a - b));
+        assert_eq!((g * &a) * &b, g * &(a * b));
+        assert_eq!(g.double(), g + g);
+        assert_eq!(-g + g, GroupModp1024::identity());
+        assert_eq!(g.mul_int_non_ct(&a.as_int()), g * &a);
+
+        let public = (g * &a).as_public().unwrap();
+        assert_eq!(g + public, g + g * &a);
+        assert_eq!(g - public, g - g * &a);
+    }
+
+    #[test]
+    fn test_batch_generators_and_msm() {
+        let mut rng = thread_rng();
+        let n = 4;
+        let generators = GroupModp1024::batch_generators(n, &mut rng);
+        let scalars = (0..n)
+            .map(|_| ScalarModp1024::rand(&mut rng))
+            .collect::<Vec<_>>();
+
+        for (i, g) in generators.iter().enumerate() {
+            assert!(GroupModp1024::is_valid(g));
+            assert!(!generators[..i].contains(g));
+        }
+
+        let expected: GroupModp1024 = generators
+            .iter()
+            .zip(scalars.iter())
+            .map(|(g, s)| *g * s)
+            .sum();
+        assert_eq!(GroupModp1024::msm(&generators, &scalars), expected);
+    }
+
+    #[test]
+    fn test_generators_from_label() {
+        let generators = GroupModp1024::batch_generators_from_label(b"test", 3);
+        assert_eq!(generators.len(), 3);
+        for (i, g) in generators.iter().enumerate() {
+            assert!(GroupModp1024::is_valid(g));
+            assert!(!generators[..i].contains(g));
+        }
+
+        // the derivation is deterministic and prefixes agree
+        assert_eq!(
+            GroupModp1024::batch_generators_from_label(b"test", 2),
+            generators[..2]
+        );
+        assert_ne!(
+            GroupModp1024::batch_generators_from_label(b"other", 1)[0],
+            generators[0]
+        );
+    }
+}
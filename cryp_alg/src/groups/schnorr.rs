@@ -0,0 +1,477 @@
+//! Prime order subgroups of the multiplicative group of a prime field
+//!
+//! A Schnorr group is the subgroup of order `q` of `Z_p^*`, where `q` is a prime dividing `p - 1`.
+//! The group operation is multiplication modulo `p`, so that in the additive notation of the
+//! `Group` trait "addition" is field multiplication and "scalar multiplication" is exponentiation.
+//!
+
+use core::borrow::Borrow;
+use cryp_std::{
+    fmt::{Debug, Display},
+    hash::Hash,
+    iter,
+    ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+    rand::{Rng, UniformRand},
+    vec::Vec,
+};
+
+use crate::hash::label_digest;
+use crate::{Bytes, Field, Group, Integer, One, PrimeField, PrimeGroup, Zero};
+
+/// Parameters of a Schnorr group.
+///
+/// The group is the subgroup of order `q = |ScalarField|` of the multiplicative group of
+/// `BaseField`, so `q` must divide `p - 1` where `p = |BaseField|`.
+pub trait SchnorrParameters: 'static + Debug + Send + Sync + Sized {
+    /// The prime field `Z_p`.
+    type BaseField: PrimeField;
+    /// The prime field `Z_q` of exponents.
+    type ScalarField: PrimeField;
+
+    /// A short name identifying the group.
+    const NAME: &'static str;
+
+    /// A generator of the subgroup of order `q`, as a canonical residue modulo `p`.
+    const GENERATOR: <Self::BaseField as PrimeField>::BigInteger;
+
+    /// The cofactor `(p - 1) / q`.
+    const COFACTOR: <Self::BaseField as PrimeField>::BigInteger;
+}
+
+/// An element of the Schnorr group with parameters `P`.
+#[derive(Debug)]
+pub struct SchnorrGroup<P: SchnorrParameters> {
+    element: P::BaseField,
+}
+
+/// The public representation of an element of a Schnorr group.
+///
+/// The element is stored as its canonical residue modulo `p`, i.e. an integer in `[1, p)`.
+#[derive(Debug)]
+pub struct SchnorrPublic<P: SchnorrParameters> {
+    pub value: <P::BaseField as PrimeField>::BigInteger,
+}
+
+impl<P: SchnorrParameters> SchnorrGroup<P> {
+    /// Wraps a field element without checking that it lies in the subgroup.
+    pub fn new(element: P::BaseField) -> Self {
+        Self { element }
+    }
+
+    /// The underlying field element.
+    pub fn element(&self) -> P::BaseField {
+        self.element
+    }
+
+    /// Maps an arbitrary non-zero field element into the subgroup by raising it to the cofactor.
+    ///
+    /// The map sends a uniformly random element of `Z_p^*` to a uniformly random element of the
+    /// subgroup. Returns `None` if the result is the identity.
+    pub fn map_to_group(x: &P::BaseField) -> Option<Self> {
+        let element = x.exp(&P::COFACTOR);
+        if element == P::BaseField::one() {
+            return None;
+        }
+        Some(Self { element })
+    }
+
+    /// Checks if the field element lies in the subgroup of order `q`.
+    pub fn is_in_subgroup(x: &P::BaseField) -> bool {
+        *x != P::BaseField::zero()
+            && x.exp(&<P::ScalarField as PrimeField>::MODULUS) == P::BaseField::one()
+    }
+}
+
+impl<P: SchnorrParameters> SchnorrPublic<P> {
+    pub fn new(value: <P::BaseField as PrimeField>::BigInteger) -> Self {
+        Self { value }
+    }
+
+    pub fn into_group(self) -> SchnorrGroup<P> {
+        SchnorrGroup::new(P::BaseField::from_int(&self.value))
+    }
+}
+
+impl<P: SchnorrParameters> From<SchnorrPublic<P>> for SchnorrGroup<P> {
+    fn from(public: SchnorrPublic<P>) -> Self {
+        public.into_group()
+    }
+}
+
+// -----------------------------------------
+
+// Group and PrimeGroup traits for SchnorrGroup
+
+// -----------------------------------------
+
+impl<P: SchnorrParameters> Group for SchnorrGroup<P> {
+    fn identity() -> Self {
+        Self {
+            element: P::BaseField::one(),
+        }
+    }
+
+    fn double_in_place(&mut self) {
+        self.element.square_in_place();
+    }
+
+    fn mul_int(&self, scalar: &impl Integer) -> Self {
+        Self {
+            element: self.element.exp(scalar),
+        }
+    }
+
+    /// Exponentiation is cheaper than the windowed method, which needs field inversions.
+    fn mul_int_non_ct(&self, scalar: &impl Integer) -> Self {
+        self.mul_int(scalar)
+    }
+}
+
+impl<P: SchnorrParameters> PrimeGroup for SchnorrGroup<P> {
+    type ScalarField = P::ScalarField;
+    type Public = SchnorrPublic<P>;
+    type Cofactor = <P::BaseField as PrimeField>::BigInteger;
+
+    fn name() -> &'static str {
+        P::NAME
+    }
+
+    fn cofactor() -> Self::Cofactor {
+        P::COFACTOR
+    }
+
+    fn generator<R: Rng>(rng: Option<&mut R>) -> Self::Public {
+        let g = SchnorrPublic::new(P::GENERATOR);
+        match rng {
+            Some(rng) => loop {
+                let scalar = P::ScalarField::rand(rng);
+                if scalar != P::ScalarField::zero() {
+                    break (g * &scalar).as_public().unwrap();
+                }
+            },
+            None => g,
+        }
+    }
+
+    /// Checks that the residue is canonical, non-zero and has order dividing `q`.
+    fn is_valid(input: &Self::Public) -> bool {
+        let x = P::BaseField::from_int(&input.value);
+        x.as_int() == input.value && Self::is_in_subgroup(&x)
+    }
+
+    fn as_public(&self) -> Option<Self::Public> {
+        Some(SchnorrPublic::new(self.element.as_int()))
+    }
+
+    /// Derives the generators from a random label, so that their discrete logarithms are
+    /// not known to anyone, including the caller.
+    fn batch_generators<R: Rng>(n: usize, rng: &mut R) -> Vec<Self::Public> {
+        let mut label = [0u8; 32];
+        rng.fill_bytes(&mut label);
+        Self::batch_generators_from_label(&label, n)
+    }
+
+    /// Reduces `len(p) + 16` bytes of digests modulo `p` and maps the result into the
+    /// subgroup, incrementing the counter of `label_digest` for every digest.
+    fn batch_generators_from_label(label: &[u8], n: usize) -> Vec<Self::Public> {
+        let len = Bytes::into_iter_be(&<P::BaseField as PrimeField>::MODULUS).count() + 16;
+        (0..n as u64)
+            .map(|index| {
+                let mut counter = 0;
+                loop {
+                    let mut bytes = Vec::with_capacity(len + 64);
+                    while bytes.len() < len {
+                        bytes.extend(label_digest(P::NAME, label, index, counter));
+                        counter += 1;
+                    }
+                    let x = bytes[..len]
+                        .iter()
+                        .flat_map(|byte| (0..8).rev().map(move |i| (byte >> i) & 1 == 1))
+                        .fold(P::BaseField::zero(), |acc, bit| {
+                            let acc = acc.double();
+                            if bit {
+                                acc + P::BaseField::one()
+                            } else {
+                                acc
+                            }
+                        });
+                    if let Some(g) = Self::map_to_group(&x) {
+                        break g.as_public().unwrap();
+                    }
+                }
+            })
+            .collect()
+    }
+
+    fn msm<I, J>(bases: I, scalars: J) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Self::Public>,
+        J: IntoIterator,
+        J::Item: Borrow<<Self as PrimeGroup>::ScalarField>,
+    {
+        bases
+            .into_iter()
+            .zip(scalars)
+            .map(|(b, s)| *b.borrow() * s.borrow())
+            .sum()
+    }
+}
+
+// -----------------------------------------
+
+// Basic traits (Clone, Copy, Hash) for SchnorrGroup and SchnorrPublic
+
+//------------------------------------------
+
+impl<P: SchnorrParameters> Clone for SchnorrGroup<P> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<P: SchnorrParameters> Copy for SchnorrGroup<P> {}
+
+impl<P: SchnorrParameters> PartialEq for SchnorrGroup<P> {
+    fn eq(&self, other: &Self) -> bool {
+        self.element == other.element
+    }
+}
+
+impl<P: SchnorrParameters> Eq for SchnorrGroup<P> {}
+
+impl<P: SchnorrParameters> Hash for SchnorrGroup<P> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.element.hash(state);
+    }
+}
+
+impl<P: SchnorrParameters> Display for SchnorrGroup<P> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", SchnorrPublic::<P>::new(self.element.as_int()))
+    }
+}
+
+impl<P: SchnorrParameters> Clone for SchnorrPublic<P> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<P: SchnorrParameters> Copy for SchnorrPublic<P> {}
+
+impl<P: SchnorrParameters> PartialEq for SchnorrPublic<P> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<P: SchnorrParameters> Eq for SchnorrPublic<P> {}
+
+impl<P: SchnorrParameters> Hash for SchnorrPublic<P> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.value.hash(state);
+    }
+}
+
+/// The residue in big endian order as a fixed length hexadecimal string.
+impl<P: SchnorrParameters> Display for SchnorrPublic<P> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        Bytes::fmt_hex(Bytes::into_iter_be(&self.value), f)
+    }
+}
+
+// -----------------------------------------
+
+// Implementing the operations as ops traits
+
+// -----------------------------------------
+
+impl<P: SchnorrParameters> AddAssign<&SchnorrGroup<P>> for SchnorrGroup<P> {
+    #[allow(clippy::suspicious_op_assign_impl)]
+    fn add_assign(&mut self, other: &SchnorrGroup<P>) {
+        self.element *= other.element;
+    }
+}
+
+impl<P: SchnorrParameters> AddAssign for SchnorrGroup<P> {
+    fn add_assign(&mut self, other: SchnorrGroup<P>) {
+        *self += &other;
+    }
+}
+
+impl<P: SchnorrParameters> Add<&SchnorrGroup<P>> for SchnorrGroup<P> {
+    type Output = SchnorrGroup<P>;
+
+    fn add(mut self, other: &SchnorrGroup<P>) -> SchnorrGroup<P> {
+        self += other;
+        self
+    }
+}
+
+impl<P: SchnorrParameters> Add for SchnorrGroup<P> {
+    type Output = SchnorrGroup<P>;
+
+    fn add(mut self, other: SchnorrGroup<P>) -> SchnorrGroup<P> {
+        self += other;
+        self
+    }
+}
+
+impl<P: SchnorrParameters> Neg for SchnorrGroup<P> {
+    type Output = SchnorrGroup<P>;
+
+    fn neg(self) -> SchnorrGroup<P> {
+        // elements of the group are non-zero so the inverse always exists
+        Self {
+            element: self.element.inverse().unwrap(),
+        }
+    }
+}
+
+impl<P: SchnorrParameters> SubAssign<&SchnorrGroup<P>> for SchnorrGroup<P> {
+    #[allow(clippy::suspicious_op_assign_impl)]
+    fn sub_assign(&mut self, other: &SchnorrGroup<P>) {
+        self.element /= other.element;
+    }
+}
+
+impl<P: SchnorrParameters> SubAssign for SchnorrGroup<P> {
+    fn sub_assign(&mut self, other: SchnorrGroup<P>) {
+        *self -= &other;
+    }
+}
+
+impl<P: SchnorrParameters> Sub<&SchnorrGroup<P>> for SchnorrGroup<P> {
+    type Output = SchnorrGroup<P>;
+
+    fn sub(mut self, other: &SchnorrGroup<P>) -> SchnorrGroup<P> {
+        self -= other;
+        self
+    }
+}
+
+impl<P: SchnorrParameters> Sub for SchnorrGroup<P> {
+    type Output = SchnorrGroup<P>;
+
+    fn sub(mut self, other: SchnorrGroup<P>) -> SchnorrGroup<P> {
+        self -= other;
+        self
+    }
+}
+
+impl<P: SchnorrParameters> iter::Sum for SchnorrGroup<P> {
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = Self>,
+    {
+        iter.fold(Self::identity(), |acc, x| acc + x)
+    }
+}
+
+impl<'a, P: SchnorrParameters> iter::Sum<&'a SchnorrGroup<P>> for SchnorrGroup<P> {
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = &'a SchnorrGroup<P>>,
+    {
+        iter.fold(Self::identity(), |acc, x| acc + x)
+    }
+}
+
+impl<P: SchnorrParameters> Mul<&P::ScalarField> for SchnorrGroup<P> {
+    type Output = SchnorrGroup<P>;
+
+    fn mul(self, other: &P::ScalarField) -> SchnorrGroup<P> {
+        self.mul_int(&other.as_int())
+    }
+}
+
+impl<P: SchnorrParameters> MulAssign<&P::ScalarField> for SchnorrGroup<P> {
+    fn mul_assign(&mut self, other: &P::ScalarField) {
+        *self = *self * other;
+    }
+}
+
+impl<P: SchnorrParameters> Mul<&P::ScalarField> for SchnorrPublic<P> {
+    type Output = SchnorrGroup<P>;
+
+    fn mul(self, other: &P::ScalarField) -> SchnorrGroup<P> {
+        self.into_group() * other
+    }
+}
+
+impl<P: SchnorrParameters> Add<&SchnorrPublic<P>> for SchnorrPublic<P> {
+    type Output = SchnorrGroup<P>;
+
+    fn add(self, other: &SchnorrPublic<P>) -> SchnorrGroup<P> {
+        self.into_group() + other
+    }
+}
+
+impl<P: SchnorrParameters> Add for SchnorrPublic<P> {
+    type Output = SchnorrGroup<P>;
+
+    fn add(self, other: SchnorrPublic<P>) -> SchnorrGroup<P> {
+        self.into_group() + other
+    }
+}
+
+impl<P: SchnorrParameters> AddAssign<&SchnorrPublic<P>> for SchnorrGroup<P> {
+    fn add_assign(&mut self, other: &SchnorrPublic<P>) {
+        *self += other.into_group();
+    }
+}
+
+impl<P: SchnorrParameters> AddAssign<SchnorrPublic<P>> for SchnorrGroup<P> {
+    fn add_assign(&mut self, other: SchnorrPublic<P>) {
+        *self += &other;
+    }
+}
+
+impl<P: SchnorrParameters> Add<&SchnorrPublic<P>> for SchnorrGroup<P> {
+    type Output = SchnorrGroup<P>;
+
+    fn add(mut self, other: &SchnorrPublic<P>) -> SchnorrGroup<P> {
+        self += other;
+        self
+    }
+}
+
+impl<P: SchnorrParameters> Add<SchnorrPublic<P>> for SchnorrGroup<P> {
+    type Output = SchnorrGroup<P>;
+
+    fn add(mut self, other: SchnorrPublic<P>) -> SchnorrGroup<P> {
+        self += other;
+        self
+    }
+}
+
+impl<P: SchnorrParameters> SubAssign<&SchnorrPublic<P>> for SchnorrGroup<P> {
+    fn sub_assign(&mut self, other: &SchnorrPublic<P>) {
+        *self -= other.into_group();
+    }
+}
+
+impl<P: SchnorrParameters> SubAssign<SchnorrPublic<P>> for SchnorrGroup<P> {
+    fn sub_assign(&mut self, other: SchnorrPublic<P>) {
+        *self -= &other;
+    }
+}
+
+impl<P: SchnorrParameters> Sub<&SchnorrPublic<P>> for SchnorrGroup<P> {
+    type Output = SchnorrGroup<P>;
+
+    fn sub(mut self, other: &SchnorrPublic<P>) -> SchnorrGroup<P> {
+        self -= other;
+        self
+    }
+}
+
+impl<P: SchnorrParameters> Sub<SchnorrPublic<P>> for SchnorrGroup<P> {
+    type Output = SchnorrGroup<P>;
+
+    fn sub(mut self, other: SchnorrPublic<P>) -> SchnorrGroup<P> {
+        self -= other;
+        self
+    }
+}
@@ -1,13 +1,14 @@
 use cryp_std::{
+    cmp::Ordering,
     fmt::{Debug, Display},
     hash::Hash,
     iter,
     ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
-    rand::{Rng, UniformRand},
+    rand::Rng,
     vec::Vec,
 };
 
-use crate::{Bits, Integer};
+use crate::{Bits, Integer, Wnaf};
 
 use core::borrow::Borrow;
 
@@ -15,6 +16,9 @@ use crate::PrimeField;
 
 //use zeroize::Zeroize;
 
+/// The window width used for variable time scalar multiplication.
+const WNAF_WIDTH: usize = 5;
+
 /// Interface for a group
 pub trait Group:
     'static
@@ -22,7 +26,7 @@ pub trait Group:
     + Clone
     + PartialEq
     + Eq
-    //+ Display 
+    + Display
     + Debug
     + Send
     + Sync
@@ -72,14 +76,29 @@ pub trait Group:
         res
     }
 
+    /// Scalar multiplication by a public integer.
+    ///
+    /// Does not run in constant time. The default implementation uses the width-`w`
+    /// non-adjacent form of the scalar with a table of the odd multiples of the base.
     fn mul_int_non_ct(&self, scalar: & impl Integer) -> Self {
-        let mut res = Self::identity();
-        let base = *self;
+        let digits = Wnaf::recode(scalar, WNAF_WIDTH);
+
+        // table[i] = (2i + 1) * self
+        let double = self.double();
+        let mut table = Vec::with_capacity(1 << (WNAF_WIDTH - 2));
+        table.push(*self);
+        for i in 1..(1 << (WNAF_WIDTH - 2)) {
+            let next = table[i - 1] + double;
+            table.push(next);
+        }
 
-        for bit in Bits::into_iter_be(scalar) {
-            res = res.double();
-            if bit {
-                res += base;
+        let mut res = Self::identity();
+        for digit in digits.iter().rev() {
+            res.double_in_place();
+            match digit.cmp(&0) {
+                Ordering::Greater => res += table[(digit / 2) as usize],
+                Ordering::Less => res -= table[(-digit / 2) as usize],
+                Ordering::Equal => {}
             }
         }
         res
@@ -109,11 +128,14 @@ pub trait PrimeGroup:
     /// information about the private key.
     ///
     /// An element in the public representation can be turned into an element in the internal representation.
+    ///
+    /// The `Display` implementation gives the canonical encoding of the element in hexadecimal.
     type Public: Clone
         + Copy
         + PartialEq
         + Eq
-        //+ Debug
+        + Debug
+        + Display
         + Hash
         + Send
         + Sync
@@ -124,6 +146,23 @@ pub trait PrimeGroup:
         + Add<Self::Public, Output = Self>
         + for<'a> Add<&'a Self::Public, Output = Self>;
 
+    /// The cofactor as an integer type.
+    type Cofactor: Integer + Debug + Clone + Copy + PartialEq + Eq + Send + Sync + 'static;
+
+    /// A short name identifying the group.
+    fn name() -> &'static str;
+
+    /// The order of the group, which is the modulus of the scalar field.
+    fn order() -> <Self::ScalarField as PrimeField>::BigInteger {
+        Self::ScalarField::MODULUS
+    }
+
+    /// The index of the group in the ambient group it is defined in.
+    ///
+    /// For a prime order subgroup of an elliptic curve, this is the number of points on the
+    /// curve divided by the order of the subgroup.
+    fn cofactor() -> Self::Cofactor;
+
     /// Gives a generator for the group.
     fn generator<R: Rng>(rng: Option<&mut R>) -> Self::Public;
 
@@ -133,12 +172,27 @@ pub trait PrimeGroup:
     /// Attempts to convert an an element of the group into the `Public` type.
     fn as_public(&self) -> Option<Self::Public>;
 
+    /// Attempts to convert several elements of the group into the `Public` type.
+    ///
+    /// Elliptic curve groups share a single field inversion between the elements. The default
+    /// implementation converts the elements one by one.
+    fn batch_as_public(elements: &[Self]) -> Vec<Option<Self::Public>> {
+        elements.iter().map(Self::as_public).collect()
+    }
+
     /// Gives a vector of generators of the group of size `n`.
     ///
     /// The generators should be independent in the sense that the mutual
     /// discrete logarithms are not known.
     fn batch_generators<R: Rng>(n: usize, rng: &mut R) -> Vec<Self::Public>;
 
+    /// Derives `n` generators of the group from a public label.
+    ///
+    /// The generators are obtained by hashing the label and the index of each generator into
+    /// the group, see `hash::label_digest`. Anyone can reproduce them from the label and no
+    /// discrete logarithm relation between them is known. Protocols should use distinct labels.
+    fn batch_generators_from_label(label: &[u8], n: usize) -> Vec<Self::Public>;
+
     /// Multi-scalar multiplication with a vector of secret scalars.
     ///
     /// The iteretors should be of the same length (this is not checked).
@@ -151,4 +205,17 @@ pub trait PrimeGroup:
         I::Item: Borrow<Self::Public>,
         J: IntoIterator,
         J::Item: Borrow<<Self as PrimeGroup>::ScalarField>;
+
+    /// Multi-scalar multiplication with a vector of public scalars.
+    ///
+    /// Does not run in constant time. The default implementation uses `msm`.
+    fn msm_non_ct<I, J>(bases: I, scalars: J) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Self::Public>,
+        J: IntoIterator,
+        J::Item: Borrow<<Self as PrimeGroup>::ScalarField>,
+    {
+        Self::msm(bases, scalars)
+    }
 }
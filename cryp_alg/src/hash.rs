@@ -0,0 +1,27 @@
+//! Hash functions used to derive group elements from public inputs.
+//!
+//! The hash functions are those of the `cryp_hash` crate, re-exported here, and this module
+//! adds the derivation of the digests from which `PrimeGroup::batch_generators_from_label`
+//! builds generators.
+
+pub use cryp_hash::{
+    Digest, ExtendableOutput, Sha256, Sha3_224, Sha3_256, Sha3_384, Sha3_512, Sha512, Shake128,
+    Shake256,
+};
+
+/// The digest used to derive the generators of `PrimeGroup::batch_generators_from_label`.
+///
+/// The SHA-512 input is `name || 0x00 || len(label) || label || index || counter`, where the
+/// length and the integers are encoded as 8 byte big endian. Including the name of the group
+/// separates the generators of different groups derived from the same label, and the counter
+/// is incremented until the digest gives an element of the group.
+pub fn label_digest(name: &str, label: &[u8], index: u64, counter: u64) -> [u8; 64] {
+    let mut hasher = Sha512::new();
+    hasher.update(name.as_bytes());
+    hasher.update(&[0]);
+    hasher.update(&(label.len() as u64).to_be_bytes());
+    hasher.update(label);
+    hasher.update(&index.to_be_bytes());
+    hasher.update(&counter.to_be_bytes());
+    hasher.finalize()
+}
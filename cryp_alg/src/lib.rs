@@ -1,30 +1,55 @@
-//!
-
 #![cfg_attr(not(feature = "std"), no_std)]
 
 mod biginteger;
 mod fields;
 mod groups;
+pub mod hash;
+mod pairing;
 mod rings;
 
-pub use biginteger::{Bits, Bytes, Integer, LimbInt};
+pub use biginteger::{Bits, Bytes, Integer, LimbInt, Wnaf};
 pub use fields::{
-    Field, MontParameters, MontgomeryOperations, PrimeField, PrimeFieldOperations, F,
+    CubicExtField, CubicExtParameters, FftField, FftParameters, Field, MontParameters,
+    MontgomeryOperations, PrimeField, PrimeFieldOperations, QuadExtField, QuadExtParameters, F,
 };
 pub use groups::{Group, PrimeGroup};
+pub use pairing::Pairing;
 
 pub use rings::Ring;
 
+/// Discrete logarithm groups that are not elliptic curves.
+pub mod dlog {
+    pub use crate::groups::{
+        FpModp1024, FpModp1024Params, FpModp2048, FpModp2048Params, GroupModp1024, GroupModp2048,
+        Modp1024Parameters, Modp2048Parameters, ScalarModp1024, ScalarModp1024Params,
+        ScalarModp2048, ScalarModp2048Params, SchnorrGroup, SchnorrParameters, SchnorrPublic,
+    };
+    pub use crate::{Group, PrimeGroup};
+}
+
 pub mod ff {
-    pub use crate::biginteger::{Bits, Bytes, Integer};
+    pub use crate::biginteger::{Bits, Bytes, Integer, Wnaf};
     pub use crate::fields::{
-        Field, GeneralReduction, GeneralReductionOperations, MontParameters, MontgomeryOperations,
-        PrimeField, PrimeFieldOperations, SolinasParameters, SolinasReduction, F,
+        CubicExtField, CubicExtParameters, FftField, FftParameters, Field, GeneralReduction,
+        GeneralReductionOperations, GoldilocksParameters, GoldilocksReduction, MontParameters,
+        MontgomeryOperations, PrimeField, PrimeFieldOperations, QuadExtField, QuadExtParameters,
+        SolinasParameters, SolinasReduction, F,
     };
     pub use crate::{One, Zero};
     pub use cryp_std::rand::UniformRand;
 }
 
+// ===========================================================================
+// General traits
+
+pub trait Zero {
+    fn zero() -> Self;
+}
+
+pub trait One {
+    fn one() -> Self;
+}
+
 #[cfg(test)]
 pub(crate) mod helper {
     use cryp_std::vec::Vec;
@@ -39,14 +64,3 @@ pub(crate) mod helper {
         BigUint::from_slice(v_u32.as_slice())
     }
 }
-
-// ===========================================================================
-// General trais
-
-pub trait Zero {
-    fn zero() -> Self;
-}
-
-pub trait One {
-    fn one() -> Self;
-}
@@ -0,0 +1,67 @@
+//! Bilinear pairings
+//!
+//! A pairing is a non-degenerate bilinear map `e: G1 x G2 -> GT` between groups of the same
+//! prime order `r`. As everywhere in this crate the groups are written additively, so
+//! bilinearity reads `e(a * P, b * Q) = (a * b) * e(P, Q)`.
+//!
+//! Pairings on elliptic curves are computed in two steps: a Miller loop producing an element
+//! of an extension field, followed by a final exponentiation mapping it into `GT`. Products
+//! of pairings only need one final exponentiation, which is why both steps are exposed.
+
+use core::borrow::Borrow;
+use cryp_std::fmt::Debug;
+
+use crate::{Field, PrimeField, PrimeGroup};
+
+/// Interface for a pairing `e: G1 x G2 -> GT`.
+pub trait Pairing: 'static + Debug + Send + Sync + Sized {
+    /// The common scalar field of the three groups.
+    type ScalarField: PrimeField;
+
+    /// The first source group.
+    type G1: PrimeGroup<ScalarField = Self::ScalarField>;
+
+    /// The second source group.
+    type G2: PrimeGroup<ScalarField = Self::ScalarField>;
+
+    /// The target group.
+    type GT: PrimeGroup<ScalarField = Self::ScalarField>;
+
+    /// The field in which the Miller loop is computed.
+    type TargetField: Field;
+
+    /// Computes the product of the Miller loops of the pairs `(a_i, b_i)`.
+    ///
+    /// The iterators should be of the same length (this is not checked).
+    fn multi_miller_loop<I, J>(a: I, b: J) -> Self::TargetField
+    where
+        I: IntoIterator,
+        I::Item: Borrow<<Self::G1 as PrimeGroup>::Public>,
+        J: IntoIterator,
+        J::Item: Borrow<<Self::G2 as PrimeGroup>::Public>;
+
+    /// Maps the output of a Miller loop into the target group.
+    ///
+    /// Returns `None` if the input is zero.
+    fn final_exponentiation(f: &Self::TargetField) -> Option<Self::GT>;
+
+    /// Computes the sum of the pairings `e(a_i, b_i)`.
+    fn multi_pairing<I, J>(a: I, b: J) -> Self::GT
+    where
+        I: IntoIterator,
+        I::Item: Borrow<<Self::G1 as PrimeGroup>::Public>,
+        J: IntoIterator,
+        J::Item: Borrow<<Self::G2 as PrimeGroup>::Public>,
+    {
+        // a product of Miller loops is never zero
+        Self::final_exponentiation(&Self::multi_miller_loop(a, b)).unwrap()
+    }
+
+    /// Computes the pairing `e(p, q)`.
+    fn pairing(
+        p: &<Self::G1 as PrimeGroup>::Public,
+        q: &<Self::G2 as PrimeGroup>::Public,
+    ) -> Self::GT {
+        Self::multi_pairing([*p], [*q])
+    }
+}
@@ -1,15 +1,12 @@
 use cryp_std::{
     fmt::{Debug, Display},
-    hash::Hash,
     iter,
-    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+    ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
     rand::UniformRand,
 };
 
 use super::{Field, One, Zero};
-use crate::Integer;
 
-use zeroize::Zeroize;
 
 pub trait Ring:
     'static
@@ -42,4 +39,5 @@ pub trait Ring:
 }
 
 /// A wrapper to represent the underlying ring of a Field
+#[allow(dead_code)]
 pub struct AsRing<F: Field>(pub F);
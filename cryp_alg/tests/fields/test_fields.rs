@@ -1,19 +1,5 @@
 use cryp_alg::ff::*;
 
-pub type F5 = F<MontgomeryOperations<1, F5Params>>;
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
-pub struct F5Params;
-
-impl MontParameters<1usize> for F5Params {
-    type Limb = u32;
-
-    const MODULUS: [u32; 1] = [5];
-
-    const R: [u32; 1] = [1];
-    const MP: Self::Limb = 858993459u32;
-    const R2: [Self::Limb; 1] = [1];
-}
-
 pub type Fp25519Sol = F<GeneralReductionOperations<4, SolinasReduction<4, Fp25519Params>>>;
 pub type Fp25519Mont = F<MontgomeryOperations<4, Fp25519Params>>;
 
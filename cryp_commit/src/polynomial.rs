@@ -1 +1,74 @@
+//! Polynomials over finite fields
+//!
+//! This module contains the polynomial types used to build polynomial commitment schemes.
+//!
+//! - `DensePolynomial`: a univariate polynomial stored as its full list of coefficients,
+//!   with schoolbook and FFT-based arithmetic.
+//! - `Radix2Domain`: a multiplicative subgroup of order `2^k` of an `FftField`, used for
+//!   fast evaluation and interpolation.
+//! - `SparsePolynomial`: a univariate polynomial stored as its non-zero terms.
+//! - `MultilinearPolynomial`: a multilinear polynomial stored as its evaluations over the
+//!   boolean hypercube, together with the `eq(x, r)` polynomial used in sum-check style protocols.
+//!
 
+use cryp_alg::ff::*;
+use cryp_std::{vec, vec::Vec};
+
+mod dense;
+mod domain;
+mod multilinear;
+mod sparse;
+
+pub use dense::DensePolynomial;
+pub use domain::Radix2Domain;
+pub use multilinear::{eq_evaluation, eq_table, MultilinearPolynomial};
+pub use sparse::SparsePolynomial;
+
+/// Minimal interface of a polynomial over a given field
+pub trait Polynomial {
+    type Field: Field;
+    /// The type of points at which the polynomial can be evaluated.
+    type Point: ?Sized;
+
+    /// The degree of the polynomial.
+    ///
+    /// The zero polynomial is considered to have degree zero.
+    fn degree(&self) -> usize;
+
+    /// Evaluates the polynomial at the given point.
+    fn evaluate(&self, point: &Self::Point) -> Self::Field;
+}
+
+#[cfg(test)]
+pub(crate) mod test_fields {
+    use cryp_alg::ff::*;
+
+    /// The "Goldilocks" field of size `2^64 - 2^32 + 1`, which has two-adicity 32.
+    pub type Goldilocks = F<MontgomeryOperations<1, GoldilocksParams>>;
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct GoldilocksParams;
+
+    impl MontParameters<1usize> for GoldilocksParams {
+        type Limb = u64;
+
+        // p = 2^64 - 2^32 + 1 = 18446744069414584321
+        const MODULUS: [Self::Limb; 1] = [18446744069414584321];
+
+        // 2^64 mod p
+        const R: [Self::Limb; 1] = [4294967295];
+
+        // R^2 mod p
+        const R2: [Self::Limb; 1] = [18446744065119617025];
+
+        // -p^-1 mod 2^64
+        const MP: Self::Limb = 18446744069414584319;
+    }
+
+    impl FftParameters<1usize> for GoldilocksParams {
+        const TWO_ADICITY: u32 = 32;
+
+        // 7^((p-1)/2^32) mod p
+        const TWO_ADIC_ROOT_OF_UNITY: [Self::Limb; 1] = [1753635133440165772];
+    }
+}
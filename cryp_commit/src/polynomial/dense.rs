@@ -0,0 +1,485 @@
+use super::*;
+use cryp_std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A univariate polynomial in dense (coefficient) representation.
+///
+/// The coefficients are stored from the lowest degree to the highest, and the
+/// representation is kept normalized so that the leading coefficient is non-zero.
+/// The zero polynomial has an empty list of coefficients.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DensePolynomial<F: Field> {
+    coeffs: Vec<F>,
+}
+
+impl<F: Field> DensePolynomial<F> {
+    /// Constructs a polynomial from its coefficients, lowest degree first.
+    pub fn from_coefficients_vec(coeffs: Vec<F>) -> Self {
+        let mut result = Self { coeffs };
+        result.truncate_leading_zeros();
+        result
+    }
+
+    /// Constructs a polynomial from a slice of coefficients, lowest degree first.
+    pub fn from_coefficients_slice(coeffs: &[F]) -> Self {
+        Self::from_coefficients_vec(coeffs.to_vec())
+    }
+
+    /// The coefficients of the polynomial, lowest degree first.
+    pub fn coeffs(&self) -> &[F] {
+        &self.coeffs
+    }
+
+    /// Checks if the polynomial is the zero polynomial.
+    pub fn is_zero(&self) -> bool {
+        self.coeffs.is_empty()
+    }
+
+    /// The degree of the polynomial, where the zero polynomial has degree zero.
+    pub fn degree(&self) -> usize {
+        self.coeffs.len().saturating_sub(1)
+    }
+
+    /// The leading coefficient, if the polynomial is not zero.
+    pub fn leading_coefficient(&self) -> Option<&F> {
+        self.coeffs.last()
+    }
+
+    fn truncate_leading_zeros(&mut self) {
+        while self.coeffs.last().is_some_and(|c| *c == F::zero()) {
+            self.coeffs.pop();
+        }
+    }
+
+    /// Evaluates the polynomial at `point` using Horner's method.
+    pub fn evaluate(&self, point: &F) -> F {
+        self.coeffs
+            .iter()
+            .rev()
+            .fold(F::zero(), |acc, c| acc * point + c)
+    }
+
+    /// Evaluates the polynomial at every point in `points`.
+    ///
+    /// Each evaluation uses Horner's method, for a total of `O(n * m)` field operations.
+    /// For evaluations over a subgroup of an `FftField`, use `evaluate_over_domain`.
+    pub fn evaluate_many(&self, points: &[F]) -> Vec<F> {
+        points.iter().map(|x| self.evaluate(x)).collect()
+    }
+
+    /// Multiplies the polynomial by a scalar.
+    pub fn scale(&self, scalar: &F) -> Self {
+        Self::from_coefficients_vec(self.coeffs.iter().map(|c| *c * scalar).collect())
+    }
+
+    /// Multiplication using the schoolbook algorithm.
+    pub fn mul_naive(&self, other: &Self) -> Self {
+        if self.is_zero() || other.is_zero() {
+            return Self::zero();
+        }
+        let mut coeffs = vec![F::zero(); self.coeffs.len() + other.coeffs.len() - 1];
+        for (i, a) in self.coeffs.iter().enumerate() {
+            for (j, b) in other.coeffs.iter().enumerate() {
+                coeffs[i + j] += *a * b;
+            }
+        }
+        Self::from_coefficients_vec(coeffs)
+    }
+
+    /// Division with remainder.
+    ///
+    /// Returns `(q, r)` such that `self = q * divisor + r` and `deg(r) < deg(divisor)`,
+    /// or `None` if the divisor is the zero polynomial.
+    pub fn divide_with_remainder(&self, divisor: &Self) -> Option<(Self, Self)> {
+        let lead_inv = divisor.leading_coefficient()?.inverse()?;
+
+        if self.coeffs.len() < divisor.coeffs.len() {
+            return Some((Self::zero(), self.clone()));
+        }
+
+        let d = divisor.coeffs.len() - 1;
+        let mut remainder = self.coeffs.clone();
+        let mut quotient = vec![F::zero(); self.coeffs.len() - d];
+
+        for i in (0..quotient.len()).rev() {
+            let c = remainder[i + d] * lead_inv;
+            quotient[i] = c;
+            for (j, b) in divisor.coeffs.iter().enumerate() {
+                remainder[i + j] -= c * b;
+            }
+        }
+        remainder.truncate(d);
+
+        Some((
+            Self::from_coefficients_vec(quotient),
+            Self::from_coefficients_vec(remainder),
+        ))
+    }
+
+    /// Lagrange interpolation of the points `(xs[i], ys[i])`.
+    ///
+    /// Returns the unique polynomial of degree less than `xs.len()` passing through
+    /// all points, or `None` if the lengths differ or the `xs` are not distinct.
+    pub fn interpolate(xs: &[F], ys: &[F]) -> Option<Self> {
+        if xs.len() != ys.len() {
+            return None;
+        }
+
+        // The vanishing polynomial Z(x) = prod (x - x_i)
+        let vanishing = xs
+            .iter()
+            .fold(Self::from_coefficients_vec(vec![F::one()]), |acc, x| {
+                acc.mul_naive(&Self::from_coefficients_vec(vec![-*x, F::one()]))
+            });
+
+        let mut coeffs = vec![F::zero(); xs.len()];
+        for (x, y) in xs.iter().zip(ys) {
+            // Z_i(x) = Z(x) / (x - x_i) via synthetic division
+            let mut basis = vec![F::zero(); xs.len()];
+            let mut carry = F::zero();
+            for k in (0..xs.len()).rev() {
+                carry = vanishing.coeffs[k + 1] + carry * x;
+                basis[k] = carry;
+            }
+
+            // The weight y_i / Z_i(x_i), which fails if x_i appears twice
+            let denominator = basis.iter().rev().fold(F::zero(), |acc, c| acc * x + c);
+            let weight = *y * denominator.inverse()?;
+
+            for (c, b) in coeffs.iter_mut().zip(basis.iter()) {
+                *c += weight * b;
+            }
+        }
+        Some(Self::from_coefficients_vec(coeffs))
+    }
+
+    /// The formal derivative of the polynomial.
+    pub fn derivative(&self) -> Self {
+        let mut index = F::zero();
+        let coeffs = self
+            .coeffs
+            .iter()
+            .skip(1)
+            .map(|c| {
+                index += F::one();
+                *c * index
+            })
+            .collect();
+        Self::from_coefficients_vec(coeffs)
+    }
+
+    /// The composition `self(other(x))`.
+    pub fn compose(&self, other: &Self) -> Self {
+        self.coeffs.iter().rev().fold(Self::zero(), |acc, c| {
+            &acc.mul_naive(other) + &Self::from_coefficients_vec(vec![*c])
+        })
+    }
+}
+
+impl<F: FftField> DensePolynomial<F> {
+    /// Multiplication using the fast Fourier transform.
+    ///
+    /// Falls back to schoolbook multiplication if the field does not have a
+    /// large enough subgroup of 2-power order.
+    pub fn mul_fft(&self, other: &Self) -> Self {
+        if self.is_zero() || other.is_zero() {
+            return Self::zero();
+        }
+        let size = self.coeffs.len() + other.coeffs.len() - 1;
+        match Radix2Domain::new(size) {
+            Some(domain) => {
+                let lhs = domain.fft(&self.coeffs);
+                let rhs = domain.fft(&other.coeffs);
+                let product = lhs
+                    .iter()
+                    .zip(rhs.iter())
+                    .map(|(a, b)| *a * b)
+                    .collect::<Vec<_>>();
+                Self::from_coefficients_vec(domain.ifft(&product))
+            }
+            None => self.mul_naive(other),
+        }
+    }
+
+    /// Evaluates the polynomial at every element of `domain`.
+    pub fn evaluate_over_domain(&self, domain: &Radix2Domain<F>) -> Vec<F> {
+        domain.fft(&self.coeffs)
+    }
+
+    /// Interpolates evaluations over `domain` into a polynomial.
+    pub fn interpolate_over_domain(domain: &Radix2Domain<F>, evals: &[F]) -> Self {
+        Self::from_coefficients_vec(domain.ifft(evals))
+    }
+}
+
+impl<F: Field> Polynomial for DensePolynomial<F> {
+    type Field = F;
+    type Point = F;
+
+    fn degree(&self) -> usize {
+        self.degree()
+    }
+
+    fn evaluate(&self, point: &F) -> F {
+        self.evaluate(point)
+    }
+}
+
+impl<F: Field> Zero for DensePolynomial<F> {
+    fn zero() -> Self {
+        Self { coeffs: Vec::new() }
+    }
+}
+
+// -----------------------------------------
+
+// Implementing the operations as ops traits
+
+// -----------------------------------------
+
+impl<F: Field> AddAssign<&DensePolynomial<F>> for DensePolynomial<F> {
+    fn add_assign(&mut self, other: &DensePolynomial<F>) {
+        if self.coeffs.len() < other.coeffs.len() {
+            self.coeffs.resize(other.coeffs.len(), F::zero());
+        }
+        for (a, b) in self.coeffs.iter_mut().zip(other.coeffs.iter()) {
+            *a += b;
+        }
+        self.truncate_leading_zeros();
+    }
+}
+
+impl<F: Field> AddAssign for DensePolynomial<F> {
+    fn add_assign(&mut self, other: DensePolynomial<F>) {
+        *self += &other;
+    }
+}
+
+impl<F: Field> Add<&DensePolynomial<F>> for &DensePolynomial<F> {
+    type Output = DensePolynomial<F>;
+
+    fn add(self, other: &DensePolynomial<F>) -> DensePolynomial<F> {
+        let mut result = self.clone();
+        result += other;
+        result
+    }
+}
+
+impl<F: Field> Add for DensePolynomial<F> {
+    type Output = DensePolynomial<F>;
+
+    fn add(mut self, other: DensePolynomial<F>) -> DensePolynomial<F> {
+        self += &other;
+        self
+    }
+}
+
+impl<F: Field> SubAssign<&DensePolynomial<F>> for DensePolynomial<F> {
+    fn sub_assign(&mut self, other: &DensePolynomial<F>) {
+        if self.coeffs.len() < other.coeffs.len() {
+            self.coeffs.resize(other.coeffs.len(), F::zero());
+        }
+        for (a, b) in self.coeffs.iter_mut().zip(other.coeffs.iter()) {
+            *a -= b;
+        }
+        self.truncate_leading_zeros();
+    }
+}
+
+impl<F: Field> SubAssign for DensePolynomial<F> {
+    fn sub_assign(&mut self, other: DensePolynomial<F>) {
+        *self -= &other;
+    }
+}
+
+impl<F: Field> Sub<&DensePolynomial<F>> for &DensePolynomial<F> {
+    type Output = DensePolynomial<F>;
+
+    fn sub(self, other: &DensePolynomial<F>) -> DensePolynomial<F> {
+        let mut result = self.clone();
+        result -= other;
+        result
+    }
+}
+
+impl<F: Field> Sub for DensePolynomial<F> {
+    type Output = DensePolynomial<F>;
+
+    fn sub(mut self, other: DensePolynomial<F>) -> DensePolynomial<F> {
+        self -= &other;
+        self
+    }
+}
+
+impl<F: Field> Neg for DensePolynomial<F> {
+    type Output = DensePolynomial<F>;
+
+    fn neg(mut self) -> DensePolynomial<F> {
+        for c in self.coeffs.iter_mut() {
+            *c = -*c;
+        }
+        self
+    }
+}
+
+/// Multiplication is implemented for every field, so it uses the schoolbook algorithm. Over
+/// an `FftField`, `mul_fft` is faster for polynomials of large degree.
+impl<F: Field> Mul<&DensePolynomial<F>> for &DensePolynomial<F> {
+    type Output = DensePolynomial<F>;
+
+    fn mul(self, other: &DensePolynomial<F>) -> DensePolynomial<F> {
+        self.mul_naive(other)
+    }
+}
+
+/// The schoolbook product, see `mul_fft` for the FFT-based one.
+impl<F: Field> Mul for DensePolynomial<F> {
+    type Output = DensePolynomial<F>;
+
+    fn mul(self, other: DensePolynomial<F>) -> DensePolynomial<F> {
+        self.mul_naive(&other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::polynomial::test_fields::Goldilocks;
+    use cryp_ec::curves::edwards25519::ScalarEd25519;
+    use cryp_std::rand::{thread_rng, Rng};
+
+    type Poly = DensePolynomial<ScalarEd25519>;
+
+    fn rand_poly<F: Field, R: Rng>(degree: usize, rng: &mut R) -> DensePolynomial<F> {
+        DensePolynomial::from_coefficients_vec((0..=degree).map(|_| F::rand(rng)).collect())
+    }
+
+    #[test]
+    fn test_normalization() {
+        let zero = ScalarEd25519::zero();
+        let one = ScalarEd25519::one();
+
+        let p = Poly::from_coefficients_vec(vec![one, zero, zero]);
+        assert_eq!(p.coeffs(), &[one]);
+        assert_eq!(p.degree(), 0);
+
+        let z = Poly::from_coefficients_vec(vec![zero, zero]);
+        assert!(z.is_zero());
+        assert_eq!(z, Poly::zero());
+        assert_eq!(z.evaluate(&one), zero);
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let mut rng = thread_rng();
+
+        for _ in 0..10 {
+            let a: Poly = rand_poly(10, &mut rng);
+            let b: Poly = rand_poly(7, &mut rng);
+            let x = ScalarEd25519::rand(&mut rng);
+
+            assert_eq!((&a + &b).evaluate(&x), a.evaluate(&x) + b.evaluate(&x));
+            assert_eq!((&a - &b).evaluate(&x), a.evaluate(&x) - b.evaluate(&x));
+            assert_eq!((&a * &b).evaluate(&x), a.evaluate(&x) * b.evaluate(&x));
+            assert_eq!((-a.clone()).evaluate(&x), -a.evaluate(&x));
+            assert_eq!(a.scale(&x).evaluate(&x), a.evaluate(&x) * x);
+
+            assert_eq!((&a * &b).degree(), 17);
+            assert!((&a - &a).is_zero());
+            assert_eq!(
+                a.evaluate_many(&[x, x + x]),
+                vec![a.evaluate(&x), a.evaluate(&(x + x))]
+            );
+        }
+    }
+
+    #[test]
+    fn test_mul_fft() {
+        let mut rng = thread_rng();
+
+        for (d_a, d_b) in [(0, 0), (1, 5), (15, 16), (40, 23)] {
+            let a: DensePolynomial<Goldilocks> = rand_poly(d_a, &mut rng);
+            let b: DensePolynomial<Goldilocks> = rand_poly(d_b, &mut rng);
+            assert_eq!(a.mul_fft(&b), a.mul_naive(&b));
+        }
+        let a: DensePolynomial<Goldilocks> = rand_poly(5, &mut rng);
+        assert!(a.mul_fft(&DensePolynomial::zero()).is_zero());
+    }
+
+    #[test]
+    fn test_domain_evaluation() {
+        let mut rng = thread_rng();
+        let domain = Radix2Domain::<Goldilocks>::new(16).unwrap();
+        let a: DensePolynomial<Goldilocks> = rand_poly(12, &mut rng);
+
+        let evals = a.evaluate_over_domain(&domain);
+        assert_eq!(evals, a.evaluate_many(&domain.elements()));
+        assert_eq!(DensePolynomial::interpolate_over_domain(&domain, &evals), a);
+    }
+
+    #[test]
+    fn test_division() {
+        let mut rng = thread_rng();
+
+        for (d_a, d_b) in [(10, 3), (3, 3), (2, 5), (8, 0)] {
+            let a: Poly = rand_poly(d_a, &mut rng);
+            let b: Poly = rand_poly(d_b, &mut rng);
+
+            let (q, r) = a.divide_with_remainder(&b).unwrap();
+            assert_eq!(&(&q * &b) + &r, a);
+            assert!(r.is_zero() || r.degree() < b.degree());
+        }
+
+        let a: Poly = rand_poly(4, &mut rng);
+        assert!(a.divide_with_remainder(&Poly::zero()).is_none());
+    }
+
+    #[test]
+    fn test_interpolation() {
+        let mut rng = thread_rng();
+        let a: Poly = rand_poly(9, &mut rng);
+
+        let xs = (0..10)
+            .map(|_| ScalarEd25519::rand(&mut rng))
+            .collect::<Vec<_>>();
+        let ys = a.evaluate_many(&xs);
+
+        assert_eq!(Poly::interpolate(&xs, &ys).unwrap(), a);
+
+        let mut repeated = xs.clone();
+        repeated[3] = repeated[7];
+        assert!(Poly::interpolate(&repeated, &ys).is_none());
+        assert!(Poly::interpolate(&xs[1..], &ys).is_none());
+    }
+
+    #[test]
+    fn test_derivative_and_composition() {
+        let mut rng = thread_rng();
+        let one = ScalarEd25519::one();
+        let zero = ScalarEd25519::zero();
+
+        // d/dx x^3 = 3x^2
+        let cube = Poly::from_coefficients_vec(vec![zero, zero, zero, one]);
+        let three = one + one + one;
+        assert_eq!(
+            cube.derivative(),
+            Poly::from_coefficients_vec(vec![zero, zero, three])
+        );
+
+        // product rule and chain rule
+        let a: Poly = rand_poly(6, &mut rng);
+        let b: Poly = rand_poly(4, &mut rng);
+        assert_eq!(
+            (&a * &b).derivative(),
+            &(&a.derivative() * &b) + &(&a * &b.derivative())
+        );
+
+        let composed = a.compose(&b);
+        let x = ScalarEd25519::rand(&mut rng);
+        assert_eq!(composed.evaluate(&x), a.evaluate(&b.evaluate(&x)));
+        assert_eq!(composed.degree(), 24);
+        assert_eq!(
+            composed.derivative(),
+            &a.derivative().compose(&b) * &b.derivative()
+        );
+    }
+}
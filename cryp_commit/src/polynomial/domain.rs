@@ -0,0 +1,199 @@
+use super::*;
+
+/// A multiplicative subgroup of size `2^k` of an FFT-friendly field.
+///
+/// The domain is the set `{1, w, w^2, ..., w^(n-1)}` where `w` is a primitive `n`-th
+/// root of unity. Evaluation and interpolation over the domain are done with the
+/// iterative radix-2 Cooley-Tukey algorithm in `O(n log n)` field operations.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Radix2Domain<F: FftField> {
+    size: usize,
+    log_size: u32,
+    group_gen: F,
+    group_gen_inv: F,
+    size_inv: F,
+}
+
+impl<F: FftField> Radix2Domain<F> {
+    /// Constructs the smallest domain of size at least `num_elements`.
+    ///
+    /// Returns `None` if the field does not have a subgroup of the required size.
+    pub fn new(num_elements: usize) -> Option<Self> {
+        let size = num_elements.max(1).checked_next_power_of_two()?;
+        let log_size = size.trailing_zeros();
+
+        let group_gen = F::root_of_unity(log_size)?;
+        let group_gen_inv = group_gen.inverse()?;
+
+        let mut size_as_field = F::one();
+        for _ in 0..log_size {
+            size_as_field.double_in_place();
+        }
+        let size_inv = size_as_field.inverse()?;
+
+        Some(Self {
+            size,
+            log_size,
+            group_gen,
+            group_gen_inv,
+            size_inv,
+        })
+    }
+
+    /// The number of elements in the domain.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// The base-two logarithm of the size of the domain.
+    pub fn log_size(&self) -> u32 {
+        self.log_size
+    }
+
+    /// The generator `w` of the domain.
+    pub fn group_gen(&self) -> F {
+        self.group_gen
+    }
+
+    /// The elements `1, w, ..., w^(n-1)` of the domain.
+    pub fn elements(&self) -> Vec<F> {
+        let mut elements = Vec::with_capacity(self.size);
+        let mut current = F::one();
+        for _ in 0..self.size {
+            elements.push(current);
+            current *= self.group_gen;
+        }
+        elements
+    }
+
+    /// Evaluates the polynomial with coefficients `coeffs` over the domain.
+    ///
+    /// Coefficients beyond the size of the domain are folded using `w^n = 1`.
+    pub fn fft(&self, coeffs: &[F]) -> Vec<F> {
+        let mut values = vec![F::zero(); self.size];
+        for (i, c) in coeffs.iter().enumerate() {
+            values[i % self.size] += c;
+        }
+        Self::fft_in_place(&mut values, self.group_gen);
+        values
+    }
+
+    /// Interpolates the evaluations `evals` over the domain into coefficients.
+    ///
+    /// The number of evaluations must be at most the size of the domain, missing
+    /// evaluations are treated as zero.
+    pub fn ifft(&self, evals: &[F]) -> Vec<F> {
+        assert!(evals.len() <= self.size, "Too many evaluations for domain");
+        let mut values = evals.to_vec();
+        values.resize(self.size, F::zero());
+
+        Self::fft_in_place(&mut values, self.group_gen_inv);
+        for v in values.iter_mut() {
+            *v *= self.size_inv;
+        }
+        values
+    }
+
+    /// Evaluates the vanishing polynomial `x^n - 1` of the domain at `point`.
+    pub fn evaluate_vanishing_polynomial(&self, point: &F) -> F {
+        let mut power = *point;
+        for _ in 0..self.log_size {
+            power.square_in_place();
+        }
+        power - F::one()
+    }
+
+    /// Iterative in-place radix-2 FFT with respect to the root of unity `omega`.
+    ///
+    /// The length of `values` must be a power of two and `omega` must have order
+    /// equal to that length.
+    fn fft_in_place(values: &mut [F], omega: F) {
+        let n = values.len();
+        let log_n = n.trailing_zeros();
+
+        // bit-reversal permutation
+        for k in 0..n {
+            let rk = bit_reverse(k, log_n);
+            if k < rk {
+                values.swap(k, rk);
+            }
+        }
+
+        // butterflies
+        let mut m = 1;
+        while m < n {
+            // w_m is a primitive (2m)-th root of unity
+            let mut w_m = omega;
+            let mut step = n / (2 * m);
+            while step > 1 {
+                w_m.square_in_place();
+                step >>= 1;
+            }
+
+            for k in (0..n).step_by(2 * m) {
+                let mut w = F::one();
+                for j in 0..m {
+                    let t = w * values[k + j + m];
+                    let u = values[k + j];
+                    values[k + j] = u + t;
+                    values[k + j + m] = u - t;
+                    w *= w_m;
+                }
+            }
+            m *= 2;
+        }
+    }
+}
+
+#[inline]
+fn bit_reverse(n: usize, log_n: u32) -> usize {
+    if log_n == 0 {
+        return n;
+    }
+    n.reverse_bits() >> (usize::BITS - log_n)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::polynomial::test_fields::Goldilocks;
+    use cryp_std::rand::thread_rng;
+
+    #[test]
+    fn test_domain_elements() {
+        let domain = Radix2Domain::<Goldilocks>::new(13).unwrap();
+        assert_eq!(domain.size(), 16);
+        assert_eq!(domain.log_size(), 4);
+
+        let elements = domain.elements();
+        assert_eq!(elements[0], Goldilocks::one());
+        assert_eq!(elements[15] * domain.group_gen(), Goldilocks::one());
+        assert_ne!(elements[8], Goldilocks::one());
+        for x in elements.iter() {
+            assert_eq!(domain.evaluate_vanishing_polynomial(x), Goldilocks::zero());
+        }
+
+        assert!(Radix2Domain::<Goldilocks>::new(1 << 33).is_none());
+    }
+
+    #[test]
+    fn test_fft_ifft() {
+        let mut rng = thread_rng();
+        let domain = Radix2Domain::<Goldilocks>::new(32).unwrap();
+
+        let coeffs = (0..32)
+            .map(|_| Goldilocks::rand(&mut rng))
+            .collect::<Vec<_>>();
+        let evals = domain.fft(&coeffs);
+
+        for (x, y) in domain.elements().iter().zip(evals.iter()) {
+            let expected = coeffs
+                .iter()
+                .rev()
+                .fold(Goldilocks::zero(), |acc, c| acc * x + c);
+            assert_eq!(*y, expected);
+        }
+
+        assert_eq!(domain.ifft(&evals), coeffs);
+    }
+}
@@ -0,0 +1,290 @@
+use super::*;
+use cryp_std::ops::{Add, AddAssign, Neg, Sub, SubAssign};
+
+/// A multilinear polynomial in `n` variables, represented by its evaluations over the
+/// boolean hypercube `{0, 1}^n`.
+///
+/// The evaluation at index `i` is the value of the polynomial at the point whose `k`-th
+/// coordinate is the `k`-th bit of `i` (least significant bit first). This is the unique
+/// multilinear extension of the given evaluations.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct MultilinearPolynomial<F: Field> {
+    num_vars: usize,
+    evaluations: Vec<F>,
+}
+
+impl<F: Field> MultilinearPolynomial<F> {
+    /// Constructs the multilinear extension of the given evaluations over `{0, 1}^num_vars`.
+    ///
+    /// Panics if the number of evaluations is not `2^num_vars`.
+    pub fn from_evaluations_vec(num_vars: usize, evaluations: Vec<F>) -> Self {
+        assert_eq!(
+            evaluations.len(),
+            1 << num_vars,
+            "The number of evaluations must be 2^num_vars"
+        );
+        Self {
+            num_vars,
+            evaluations,
+        }
+    }
+
+    /// Constructs the multilinear extension from a slice of evaluations over `{0, 1}^num_vars`.
+    pub fn from_evaluations_slice(num_vars: usize, evaluations: &[F]) -> Self {
+        Self::from_evaluations_vec(num_vars, evaluations.to_vec())
+    }
+
+    /// The polynomial `eq(x, r)` as a multilinear polynomial in `x`.
+    pub fn eq_polynomial(r: &[F]) -> Self {
+        Self::from_evaluations_vec(r.len(), eq_table(r))
+    }
+
+    /// The number of variables.
+    pub fn num_vars(&self) -> usize {
+        self.num_vars
+    }
+
+    /// The evaluations over the boolean hypercube.
+    pub fn evaluations(&self) -> &[F] {
+        &self.evaluations
+    }
+
+    /// Fixes the first `partial_point.len()` variables to the given values.
+    ///
+    /// The result is a multilinear polynomial in the remaining variables.
+    /// Panics if more values than variables are given.
+    pub fn fix_variables(&self, partial_point: &[F]) -> Self {
+        assert!(
+            partial_point.len() <= self.num_vars,
+            "Too many variables to fix"
+        );
+        let mut evaluations = self.evaluations.clone();
+
+        for (k, r) in partial_point.iter().enumerate() {
+            let half = 1 << (self.num_vars - k - 1);
+            for i in 0..half {
+                let low = evaluations[2 * i];
+                let high = evaluations[2 * i + 1];
+                evaluations[i] = low + (high - low) * r;
+            }
+            evaluations.truncate(half);
+        }
+
+        Self {
+            num_vars: self.num_vars - partial_point.len(),
+            evaluations,
+        }
+    }
+
+    /// Evaluates the polynomial at an arbitrary point of `F^n`.
+    ///
+    /// Panics if the point does not have `num_vars` coordinates.
+    pub fn evaluate(&self, point: &[F]) -> F {
+        assert_eq!(
+            point.len(),
+            self.num_vars,
+            "The point must have num_vars coordinates"
+        );
+        self.fix_variables(point).evaluations[0]
+    }
+}
+
+/// The table of evaluations of `eq(x, r)` for all `x` in `{0, 1}^n`, with `n = r.len()`.
+///
+/// The entries follow the same indexing as `MultilinearPolynomial`, so that
+/// `p(r) = sum_x p(x) * eq(x, r)`. The table is built in `O(2^n)` field multiplications.
+pub fn eq_table<F: Field>(r: &[F]) -> Vec<F> {
+    let mut table = vec![F::zero(); 1 << r.len()];
+    table[0] = F::one();
+
+    // After processing k variables, the first 2^k entries hold eq over those variables.
+    for (k, r_k) in r.iter().enumerate() {
+        let size = 1 << k;
+        for i in (0..size).rev() {
+            let high = table[i] * r_k;
+            table[i + size] = high;
+            table[i] -= high;
+        }
+    }
+    table
+}
+
+/// Evaluates `eq(x, r) = prod_i (x_i * r_i + (1 - x_i) * (1 - r_i))`.
+///
+/// Panics if `x` and `r` have different lengths.
+pub fn eq_evaluation<F: Field>(x: &[F], r: &[F]) -> F {
+    assert_eq!(x.len(), r.len(), "Points must have the same length");
+    let one = F::one();
+    x.iter()
+        .zip(r.iter())
+        .map(|(x_i, r_i)| *x_i * r_i + (one - x_i) * (one - r_i))
+        .product()
+}
+
+impl<F: Field> Polynomial for MultilinearPolynomial<F> {
+    type Field = F;
+    type Point = [F];
+
+    /// The total degree bound, which is the number of variables.
+    fn degree(&self) -> usize {
+        self.num_vars
+    }
+
+    fn evaluate(&self, point: &[F]) -> F {
+        self.evaluate(point)
+    }
+}
+
+// -----------------------------------------
+
+// Implementing the operations as ops traits
+
+// -----------------------------------------
+
+impl<F: Field> AddAssign<&MultilinearPolynomial<F>> for MultilinearPolynomial<F> {
+    fn add_assign(&mut self, other: &MultilinearPolynomial<F>) {
+        assert_eq!(
+            self.num_vars, other.num_vars,
+            "Mismatched number of variables"
+        );
+        for (a, b) in self.evaluations.iter_mut().zip(other.evaluations.iter()) {
+            *a += b;
+        }
+    }
+}
+
+impl<F: Field> Add<&MultilinearPolynomial<F>> for &MultilinearPolynomial<F> {
+    type Output = MultilinearPolynomial<F>;
+
+    fn add(self, other: &MultilinearPolynomial<F>) -> MultilinearPolynomial<F> {
+        let mut result = self.clone();
+        result += other;
+        result
+    }
+}
+
+impl<F: Field> Add for MultilinearPolynomial<F> {
+    type Output = MultilinearPolynomial<F>;
+
+    fn add(mut self, other: MultilinearPolynomial<F>) -> MultilinearPolynomial<F> {
+        self += &other;
+        self
+    }
+}
+
+impl<F: Field> SubAssign<&MultilinearPolynomial<F>> for MultilinearPolynomial<F> {
+    fn sub_assign(&mut self, other: &MultilinearPolynomial<F>) {
+        assert_eq!(
+            self.num_vars, other.num_vars,
+            "Mismatched number of variables"
+        );
+        for (a, b) in self.evaluations.iter_mut().zip(other.evaluations.iter()) {
+            *a -= b;
+        }
+    }
+}
+
+impl<F: Field> Sub<&MultilinearPolynomial<F>> for &MultilinearPolynomial<F> {
+    type Output = MultilinearPolynomial<F>;
+
+    fn sub(self, other: &MultilinearPolynomial<F>) -> MultilinearPolynomial<F> {
+        let mut result = self.clone();
+        result -= other;
+        result
+    }
+}
+
+impl<F: Field> Sub for MultilinearPolynomial<F> {
+    type Output = MultilinearPolynomial<F>;
+
+    fn sub(mut self, other: MultilinearPolynomial<F>) -> MultilinearPolynomial<F> {
+        self -= &other;
+        self
+    }
+}
+
+impl<F: Field> Neg for MultilinearPolynomial<F> {
+    type Output = MultilinearPolynomial<F>;
+
+    fn neg(mut self) -> MultilinearPolynomial<F> {
+        for e in self.evaluations.iter_mut() {
+            *e = -*e;
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cryp_ec::curves::edwards25519::ScalarEd25519;
+    use cryp_std::rand::{thread_rng, Rng};
+
+    type Mle = MultilinearPolynomial<ScalarEd25519>;
+
+    fn rand_point<R: Rng>(n: usize, rng: &mut R) -> Vec<ScalarEd25519> {
+        (0..n).map(|_| ScalarEd25519::rand(rng)).collect()
+    }
+
+    fn boolean_point(index: usize, n: usize) -> Vec<ScalarEd25519> {
+        (0..n)
+            .map(|k| {
+                if (index >> k) & 1 == 1 {
+                    ScalarEd25519::one()
+                } else {
+                    ScalarEd25519::zero()
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_evaluate_on_hypercube() {
+        let mut rng = thread_rng();
+        let n = 4;
+        let p = Mle::from_evaluations_vec(n, rand_point(1 << n, &mut rng));
+
+        for i in 0..(1 << n) {
+            assert_eq!(p.evaluate(&boolean_point(i, n)), p.evaluations()[i]);
+        }
+    }
+
+    #[test]
+    fn test_evaluate_matches_eq_table() {
+        let mut rng = thread_rng();
+        let n = 5;
+        let p = Mle::from_evaluations_vec(n, rand_point(1 << n, &mut rng));
+        let r = rand_point(n, &mut rng);
+
+        let table = eq_table(&r);
+        let expected: ScalarEd25519 = p
+            .evaluations()
+            .iter()
+            .zip(table.iter())
+            .map(|(a, b)| *a * b)
+            .sum();
+        assert_eq!(p.evaluate(&r), expected);
+
+        for (i, t) in table.iter().enumerate() {
+            assert_eq!(*t, eq_evaluation(&boolean_point(i, n), &r));
+        }
+        assert_eq!(Mle::eq_polynomial(&r).evaluate(&r), eq_evaluation(&r, &r));
+    }
+
+    #[test]
+    fn test_fix_variables() {
+        let mut rng = thread_rng();
+        let n = 6;
+        let p = Mle::from_evaluations_vec(n, rand_point(1 << n, &mut rng));
+        let r = rand_point(n, &mut rng);
+
+        let partial = p.fix_variables(&r[..2]);
+        assert_eq!(partial.num_vars(), 4);
+        assert_eq!(partial.evaluate(&r[2..]), p.evaluate(&r));
+
+        let q = Mle::from_evaluations_vec(n, rand_point(1 << n, &mut rng));
+        assert_eq!((&p + &q).evaluate(&r), p.evaluate(&r) + q.evaluate(&r));
+        assert_eq!((&p - &q).evaluate(&r), p.evaluate(&r) - q.evaluate(&r));
+        assert_eq!((-p.clone()).evaluate(&r), -p.evaluate(&r));
+    }
+}
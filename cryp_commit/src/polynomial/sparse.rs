@@ -0,0 +1,243 @@
+use super::*;
+use cryp_std::ops::{Add, Mul, Neg, Sub};
+
+/// A univariate polynomial in sparse representation.
+///
+/// The polynomial is stored as a list of `(degree, coefficient)` pairs sorted by degree,
+/// with no zero coefficients and no repeated degrees.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SparsePolynomial<F: Field> {
+    coeffs: Vec<(usize, F)>,
+}
+
+impl<F: Field> SparsePolynomial<F> {
+    /// Constructs a polynomial from a list of `(degree, coefficient)` pairs.
+    ///
+    /// The pairs can come in any order, coefficients of repeated degrees are added together.
+    pub fn from_coefficients_vec(mut coeffs: Vec<(usize, F)>) -> Self {
+        coeffs.sort_by_key(|(d, _)| *d);
+
+        let mut normalized: Vec<(usize, F)> = Vec::with_capacity(coeffs.len());
+        for (d, c) in coeffs {
+            match normalized.last_mut() {
+                Some((last_d, last_c)) if *last_d == d => *last_c += c,
+                _ => normalized.push((d, c)),
+            }
+        }
+        normalized.retain(|(_, c)| *c != F::zero());
+
+        Self { coeffs: normalized }
+    }
+
+    /// Constructs a polynomial from a slice of `(degree, coefficient)` pairs.
+    pub fn from_coefficients_slice(coeffs: &[(usize, F)]) -> Self {
+        Self::from_coefficients_vec(coeffs.to_vec())
+    }
+
+    /// The non-zero terms of the polynomial as `(degree, coefficient)` pairs, sorted by degree.
+    pub fn terms(&self) -> &[(usize, F)] {
+        &self.coeffs
+    }
+
+    /// Checks if the polynomial is the zero polynomial.
+    pub fn is_zero(&self) -> bool {
+        self.coeffs.is_empty()
+    }
+
+    /// The degree of the polynomial, where the zero polynomial has degree zero.
+    pub fn degree(&self) -> usize {
+        self.coeffs.last().map_or(0, |(d, _)| *d)
+    }
+
+    /// Evaluates the polynomial at `point`.
+    ///
+    /// The powers of `point` are computed incrementally by exponentiating with the gaps
+    /// between consecutive degrees.
+    pub fn evaluate(&self, point: &F) -> F {
+        let mut result = F::zero();
+        let mut power = F::one();
+        let mut current_degree = 0;
+        for (d, c) in self.coeffs.iter() {
+            power *= point.pow((d - current_degree) as u64);
+            current_degree = *d;
+            result += power * c;
+        }
+        result
+    }
+
+    /// Converts the polynomial into a dense representation.
+    pub fn to_dense(&self) -> DensePolynomial<F> {
+        if self.is_zero() {
+            return DensePolynomial::zero();
+        }
+        let mut coeffs = vec![F::zero(); self.degree() + 1];
+        for (d, c) in self.coeffs.iter() {
+            coeffs[*d] = *c;
+        }
+        DensePolynomial::from_coefficients_vec(coeffs)
+    }
+}
+
+impl<F: Field> From<&DensePolynomial<F>> for SparsePolynomial<F> {
+    fn from(dense: &DensePolynomial<F>) -> Self {
+        Self::from_coefficients_vec(dense.coeffs().iter().copied().enumerate().collect())
+    }
+}
+
+impl<F: Field> From<SparsePolynomial<F>> for DensePolynomial<F> {
+    fn from(sparse: SparsePolynomial<F>) -> Self {
+        sparse.to_dense()
+    }
+}
+
+impl<F: Field> Polynomial for SparsePolynomial<F> {
+    type Field = F;
+    type Point = F;
+
+    fn degree(&self) -> usize {
+        self.degree()
+    }
+
+    fn evaluate(&self, point: &F) -> F {
+        self.evaluate(point)
+    }
+}
+
+impl<F: Field> Zero for SparsePolynomial<F> {
+    fn zero() -> Self {
+        Self { coeffs: Vec::new() }
+    }
+}
+
+// -----------------------------------------
+
+// Implementing the operations as ops traits
+
+// -----------------------------------------
+
+impl<F: Field> Add<&SparsePolynomial<F>> for &SparsePolynomial<F> {
+    type Output = SparsePolynomial<F>;
+
+    fn add(self, other: &SparsePolynomial<F>) -> SparsePolynomial<F> {
+        let terms = self.coeffs.iter().chain(other.coeffs.iter()).copied();
+        SparsePolynomial::from_coefficients_vec(terms.collect())
+    }
+}
+
+impl<F: Field> Add for SparsePolynomial<F> {
+    type Output = SparsePolynomial<F>;
+
+    fn add(self, other: SparsePolynomial<F>) -> SparsePolynomial<F> {
+        &self + &other
+    }
+}
+
+impl<F: Field> Neg for SparsePolynomial<F> {
+    type Output = SparsePolynomial<F>;
+
+    fn neg(mut self) -> SparsePolynomial<F> {
+        for (_, c) in self.coeffs.iter_mut() {
+            *c = -*c;
+        }
+        self
+    }
+}
+
+impl<F: Field> Sub<&SparsePolynomial<F>> for &SparsePolynomial<F> {
+    type Output = SparsePolynomial<F>;
+
+    fn sub(self, other: &SparsePolynomial<F>) -> SparsePolynomial<F> {
+        let terms = self
+            .coeffs
+            .iter()
+            .copied()
+            .chain(other.coeffs.iter().map(|(d, c)| (*d, -*c)));
+        SparsePolynomial::from_coefficients_vec(terms.collect())
+    }
+}
+
+impl<F: Field> Sub for SparsePolynomial<F> {
+    type Output = SparsePolynomial<F>;
+
+    fn sub(self, other: SparsePolynomial<F>) -> SparsePolynomial<F> {
+        &self - &other
+    }
+}
+
+impl<F: Field> Mul<&SparsePolynomial<F>> for &SparsePolynomial<F> {
+    type Output = SparsePolynomial<F>;
+
+    fn mul(self, other: &SparsePolynomial<F>) -> SparsePolynomial<F> {
+        let mut terms = Vec::with_capacity(self.coeffs.len() * other.coeffs.len());
+        for (d_a, c_a) in self.coeffs.iter() {
+            for (d_b, c_b) in other.coeffs.iter() {
+                terms.push((d_a + d_b, *c_a * c_b));
+            }
+        }
+        SparsePolynomial::from_coefficients_vec(terms)
+    }
+}
+
+impl<F: Field> Mul for SparsePolynomial<F> {
+    type Output = SparsePolynomial<F>;
+
+    fn mul(self, other: SparsePolynomial<F>) -> SparsePolynomial<F> {
+        &self * &other
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cryp_ec::curves::edwards25519::ScalarEd25519;
+    use cryp_std::rand::{thread_rng, Rng};
+
+    type Poly = SparsePolynomial<ScalarEd25519>;
+
+    fn rand_sparse<R: Rng>(num_terms: usize, rng: &mut R) -> Poly {
+        Poly::from_coefficients_vec(
+            (0..num_terms)
+                .map(|_| (rng.gen_range(0..1000), ScalarEd25519::rand(rng)))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_normalization() {
+        let one = ScalarEd25519::one();
+
+        let p = Poly::from_coefficients_vec(vec![(5, one), (0, one), (5, -one), (2, one)]);
+        assert_eq!(p.terms(), &[(0, one), (2, one)]);
+        assert_eq!(p.degree(), 2);
+        assert!(Poly::from_coefficients_vec(vec![(3, one), (3, -one)]).is_zero());
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let mut rng = thread_rng();
+
+        for _ in 0..10 {
+            let a = rand_sparse(8, &mut rng);
+            let b = rand_sparse(5, &mut rng);
+            let x = ScalarEd25519::rand(&mut rng);
+
+            assert_eq!((&a + &b).evaluate(&x), a.evaluate(&x) + b.evaluate(&x));
+            assert_eq!((&a - &b).evaluate(&x), a.evaluate(&x) - b.evaluate(&x));
+            assert_eq!((&a * &b).evaluate(&x), a.evaluate(&x) * b.evaluate(&x));
+            assert_eq!((-a.clone()).evaluate(&x), -a.evaluate(&x));
+            assert!((&a - &a).is_zero());
+        }
+    }
+
+    #[test]
+    fn test_dense_conversion() {
+        let mut rng = thread_rng();
+        let a = rand_sparse(10, &mut rng);
+        let x = ScalarEd25519::rand(&mut rng);
+
+        let dense = a.to_dense();
+        assert_eq!(dense.degree(), a.degree());
+        assert_eq!(dense.evaluate(&x), a.evaluate(&x));
+        assert_eq!(SparsePolynomial::from(&dense), a);
+    }
+}
@@ -5,7 +5,7 @@ use cryp_std::vec::Vec;
 
 mod pedersen;
 
-pub use pedersen::Pedersen;
+pub use pedersen::{Pedersen, PedersenVec, PEDERSEN_VEC_LABEL};
 
 pub trait VCPublicParameters: Clone {
     fn max_dim(&self) -> usize;
@@ -44,7 +44,7 @@ pub trait VectorCommitment<V: Vector> {
     ///
     /// - The number of group operations should be independent of the input itself (can depend on the length of the input)
     /// - If rng is not None, the commitment is hiding, and takes a different amount of time from
-    /// a non-hiding commitment.
+    ///   a non-hiding commitment.
     fn commit(
         pp: &Self::PublicParameters,
         input: &V,
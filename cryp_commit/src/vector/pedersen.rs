@@ -17,6 +17,9 @@ pub struct PedersenVec<G: PrimeGroup> {
     _marker: cryp_std::marker::PhantomData<G>,
 }
 
+/// The label from which `PedersenVec::setup` derives its generators.
+pub const PEDERSEN_VEC_LABEL: &[u8] = b"cryp_commit PedersenVec generators";
+
 #[derive(Clone)]
 pub struct PedersenPP<G: PrimeGroup, const N: usize> {
     g_vec: [G::Public; N],
@@ -35,17 +38,20 @@ impl<G: PrimeGroup, const N: usize> VectorCommitment<[G::ScalarField; N]> for Pe
     type Randomness = G::Public;
     type Error = ();
 
+    /// The generators are those of `PedersenVec` for the dimension `N`, derived from the
+    /// label `PEDERSEN_VEC_LABEL`, so the setup is transparent and does not use the
+    /// randomness.
     fn setup<R: cryp_std::rand::Rng>(
-        rng: &mut R,
+        _rng: &mut R,
         max_dim: usize,
     ) -> Result<Self::PublicParameters, Self::Error> {
         assert!(max_dim == N);
-        let group_elements = G::batch_generators(N + 1, rng);
+        let group_elements = G::batch_generators_from_label(PEDERSEN_VEC_LABEL, N + 1);
         assert!(group_elements.len() == N + 1);
 
         // Should succeed because of assert
-        let g_vec: [G::Public; N] = group_elements[0..N].try_into().unwrap();
-        let h = group_elements[N];
+        let h = group_elements[0];
+        let g_vec: [G::Public; N] = group_elements[1..].try_into().unwrap();
 
         Ok(PedersenPP { g_vec, h })
     }
@@ -63,23 +69,25 @@ impl<G: PrimeGroup, const N: usize> VectorCommitment<[G::ScalarField; N]> for Pe
         assert_eq!(pp.g_vec.len(), N);
         // random field element
         // TODO: Error handling
-        let h_rand = rng.map(G::ScalarField::rand).map(|r| pp.h * &r).map(|hr| {
-            hr.as_public()
-                .expect("The group element should be able to convert to public")
-        });
+        let h_rand = rng.map(G::ScalarField::rand).map(|r| pp.h * &r);
 
         let commit_g = G::msm(&pp.g_vec, input);
 
-        let (commit_priv, randomness) = match h_rand {
-            Some(hr) => (commit_g + hr, hr),
-            None => (commit_g, pp.h),
+        // Convert to public (avoids projective coordinate attacks), together with h^r so
+        // that elliptic curve groups share a single inversion
+        let (commitment, randomness) = match h_rand {
+            Some(hr) => match G::batch_as_public(&[commit_g + hr, hr])[..] {
+                [Some(commitment), Some(hr)] => (commitment, hr),
+                _ => panic!("The group element should be able to convert to public"),
+            },
+            None => (
+                commit_g
+                    .as_public()
+                    .expect("The group element should be able to convert to public"),
+                pp.h,
+            ),
         };
 
-        // Convert to public (avoids projective coordinate attacks)
-        let commitment = commit_priv
-            .as_public()
-            .expect("The group element should be able to convert to public");
-
         Ok((commitment, randomness))
     }
 
@@ -90,7 +98,7 @@ impl<G: PrimeGroup, const N: usize> VectorCommitment<[G::ScalarField; N]> for Pe
         randomness: &Self::Randomness,
     ) -> Result<bool, Self::Error> {
         // necessary checks
-        assert!(G::is_valid(&commitment));
+        assert!(G::is_valid(commitment));
         assert_eq!(pp.g_vec.len(), N);
 
         // cverify commitment
@@ -119,16 +127,21 @@ impl<G: PrimeGroup> VectorCommitment<Vec<G::ScalarField>> for PedersenVec<G> {
     type Randomness = G::Public;
     type Error = ();
 
+    /// The generators are derived from the label `PEDERSEN_VEC_LABEL`, so the setup is
+    /// transparent and does not use the randomness.
+    ///
+    /// The first generator is `h`, so the generators for a smaller dimension are a prefix of
+    /// those for a larger one.
     fn setup<R: cryp_std::rand::Rng>(
-        rng: &mut R,
+        _rng: &mut R,
         max_dim: usize,
     ) -> Result<Self::PublicParameters, Self::Error> {
-        let group_elements = G::batch_generators(max_dim + 1, rng);
+        let group_elements = G::batch_generators_from_label(PEDERSEN_VEC_LABEL, max_dim + 1);
         assert!(group_elements.len() == max_dim + 1);
 
         // Should succeed because of assert
-        let g_vec = group_elements[0..max_dim].to_vec();
-        let h = group_elements[max_dim];
+        let h = group_elements[0];
+        let g_vec = group_elements[1..].to_vec();
 
         Ok(PedersenVecPP { g_vec, h })
     }
@@ -147,23 +160,25 @@ impl<G: PrimeGroup> VectorCommitment<Vec<G::ScalarField>> for PedersenVec<G> {
         // random field element, compute h^r
         // TODO: Error handling
 
-        let h_rand = rng.map(G::ScalarField::rand).map(|r| pp.h * &r).map(|hr| {
-            hr.as_public()
-                .expect("The group element should be able to convert to public")
-        });
+        let h_rand = rng.map(G::ScalarField::rand).map(|r| pp.h * &r);
 
         let commit_g = G::msm(&pp.g_vec, input);
 
-        let (commit_priv, randomness) = match h_rand {
-            Some(hr) => (commit_g + hr, hr),
-            None => (commit_g, pp.h),
+        // Convert to public (avoids projective coordinate attacks), together with h^r so
+        // that elliptic curve groups share a single inversion
+        let (commitment, randomness) = match h_rand {
+            Some(hr) => match G::batch_as_public(&[commit_g + hr, hr])[..] {
+                [Some(commitment), Some(hr)] => (commitment, hr),
+                _ => panic!("The group element should be able to convert to public"),
+            },
+            None => (
+                commit_g
+                    .as_public()
+                    .expect("The group element should be able to convert to public"),
+                pp.h,
+            ),
         };
 
-        // Convert to public (avoids projective coordinate attacks)
-        let commitment = commit_priv
-            .as_public()
-            .expect("The group element should be able to convert to public");
-
         Ok((commitment, randomness))
     }
 
@@ -174,7 +189,7 @@ impl<G: PrimeGroup> VectorCommitment<Vec<G::ScalarField>> for PedersenVec<G> {
         randomness: &Self::Randomness,
     ) -> Result<bool, Self::Error> {
         // Necessary checks
-        assert!(G::is_valid(&commitment));
+        assert!(G::is_valid(commitment));
         assert!(input.len() <= pp.g_vec.len(), "Input vector is too long");
 
         // Verify commitment
@@ -226,8 +241,8 @@ mod tests {
         const D: usize = 10;
         pub type PedEdVec = Pedersen<GroupEd25519, D>;
         let mut input = [ScalarEd25519::zero(); D];
-        for i in 0..D {
-            input[i] = ScalarEd25519::rand(&mut rng);
+        for x in input.iter_mut() {
+            *x = ScalarEd25519::rand(&mut rng);
         }
         let pp = PedEdVec::setup(&mut rng, D).unwrap();
         let (commitment, randomness) = PedEdVec::commit(&pp, &input, Some(&mut rng)).unwrap();
@@ -252,5 +267,41 @@ mod tests {
         assert_ne!(commitment, GroupEd25519::generator::<ThreadRng>(None));
 
         assert!(PedVec::verify(&pp, &commitment, &input, &randomness).unwrap());
+
+        // The setup is reproducible and the generators of a smaller dimension are a prefix
+        let pp_small = PedVec::setup(&mut rng, m).unwrap();
+        assert_eq!(pp_small.h, pp.h);
+        assert_eq!(pp_small.g_vec[..], pp.g_vec[..m]);
+        let (small_commitment, _) =
+            PedVec::commit(&pp_small, &input, None::<&mut ThreadRng>).unwrap();
+        let (commitment, _) = PedVec::commit(&pp, &input, None::<&mut ThreadRng>).unwrap();
+        assert_eq!(small_commitment, commitment);
+
+        // The fixed length scheme uses the same generators
+        let pp_fixed = Pedersen::<GroupEd25519, 10>::setup(&mut rng, m).unwrap();
+        assert_eq!(pp_fixed.h, pp.h);
+        assert_eq!(pp_fixed.g_vec[..], pp.g_vec[..m]);
+    }
+
+    #[test]
+    fn test_pedersen_dlog() {
+        use cryp_alg::dlog::*;
+
+        let mut rng = thread_rng();
+
+        const D: usize = 3;
+        pub type PedModp = Pedersen<GroupModp1024, D>;
+        let mut input = [ScalarModp1024::zero(); D];
+        for x in input.iter_mut() {
+            *x = ScalarModp1024::rand(&mut rng);
+        }
+        let pp = PedModp::setup(&mut rng, D).unwrap();
+        let (commitment, randomness) = PedModp::commit(&pp, &input, Some(&mut rng)).unwrap();
+
+        assert!(GroupModp1024::is_valid(&commitment));
+        assert!(PedModp::verify(&pp, &commitment, &input, &randomness).unwrap());
+
+        input[0] += ScalarModp1024::one();
+        assert!(!PedModp::verify(&pp, &commitment, &input, &randomness).unwrap());
     }
 }
@@ -1,7 +1,7 @@
 use criterion::{criterion_group, criterion_main, Criterion, BenchmarkId};
-use cryp_alg::PrimeField;
+use cryp_alg::{Group, PrimeField};
 use cryp_ec::curves::edwards25519::*;
-use cryp_std::rand::thread_rng;
+use cryp_std::rand::{thread_rng, UniformRand};
 
 pub fn bench_scalar_mul_ed25519(c : &mut Criterion) {
     let mut rng = thread_rng();
@@ -12,6 +12,46 @@ pub fn bench_scalar_mul_ed25519(c : &mut Criterion) {
     b.iter(|| generator * &scalar));
 }
 
+pub fn bench_mul_int_ed25519(c : &mut Criterion) {
+    let mut rng = thread_rng();
+    let generator = GroupEd25519::from(GroupEd25519::generator(Some(&mut rng)));
+    let scalar = ScalarEd25519::rand(&mut rng).as_int();
+
+    let mut group = c.benchmark_group("mul_int");
+    group.bench_with_input(BenchmarkId::new("ladder", "random"),
+    &(generator, scalar), |b, (generator, scalar)|
+    b.iter(|| generator.mul_int(scalar)));
+    group.bench_with_input(BenchmarkId::new("wnaf", "random"),
+    &(generator, scalar), |b, (generator, scalar)|
+    b.iter(|| generator.mul_int_non_ct(scalar)));
+    group.finish();
+}
+
+pub fn bench_double_ed25519(c : &mut Criterion) {
+    let mut rng = thread_rng();
+    let generator = GroupEd25519::from(GroupEd25519::generator(Some(&mut rng)));
+
+    let mut group = c.benchmark_group("double");
+    group.bench_with_input(BenchmarkId::new("unified_add", "single"),
+    &generator, |b, generator|
+    b.iter(|| *generator + generator));
+    group.bench_with_input(BenchmarkId::new("dedicated", "single"),
+    &generator, |b, generator|
+    b.iter(|| generator.double()));
+    // 64 doublings, maintaining T at every step or only at the end
+    group.bench_with_input(BenchmarkId::new("extended", "chain_64"),
+    &generator, |b, generator|
+    b.iter(|| (0..64).fold(*generator, |p, _| p.double())));
+    let projective = Projective::from(ExtendedPoint::from(GroupEd25519::generator(Some(&mut rng)).point));
+    group.bench_with_input(BenchmarkId::new("projective", "chain_64"),
+    &projective, |b, projective| {
+    type Operations = EdwardsAM1UnifiedOperations<Ed25519Parameters>;
+    b.iter(|| {
+        let p = (1..64).fold(*projective, |p, _| Operations::double_projective(&p).into_projective());
+        Operations::double_projective(&p).into_extended()
+    })});
+    group.finish();
+}
 
-criterion_group!(benches, bench_scalar_mul_ed25519);
+criterion_group!(benches, bench_scalar_mul_ed25519, bench_mul_int_ed25519, bench_double_ed25519);
 criterion_main!(benches);
\ No newline at end of file
@@ -0,0 +1,22 @@
+use criterion::{criterion_group, criterion_main, Criterion, BenchmarkId};
+use cryp_alg::{Group, PrimeField};
+use cryp_ec::curves::secp256k1::*;
+use cryp_std::rand::{thread_rng, UniformRand};
+
+pub fn bench_scalar_mul_secp256k1(c : &mut Criterion) {
+    let mut rng = thread_rng();
+    let generator = GroupSecp256k1::from(GroupSecp256k1::generator(Some(&mut rng)));
+    let scalar = ScalarSecp256k1::rand(&mut rng);
+
+    let mut group = c.benchmark_group("scalar_mul_secp256k1");
+    group.bench_with_input(BenchmarkId::new("ladder", "random"),
+    &(generator, scalar), |b, (generator, scalar)|
+    b.iter(|| generator.mul_int(&scalar.as_int())));
+    group.bench_with_input(BenchmarkId::new("glv", "random"),
+    &(generator, scalar), |b, (generator, scalar)|
+    b.iter(|| *generator * scalar));
+    group.finish();
+}
+
+criterion_group!(benches, bench_scalar_mul_secp256k1);
+criterion_main!(benches);
\ No newline at end of file
@@ -1,7 +1,15 @@
 //! Implementations of particulat elliptic curve groups
 //!
 //!
-//! Currently we have an implementation of the [EdSDA Edwards cuve](https://www.rfc-editor.org/rfc/rfc8032#page-16) over the prime field modulus `2^255 - 19`.
+//! Currently we have an implementation of the [EdSDA Edwards cuve](https://www.rfc-editor.org/rfc/rfc8032#page-16) over the prime field modulus `2^255 - 19`
+//! and of the NIST curve P-256 and the curve secp256k1 in the `p256` and `secp256k1` modules.
+//! The Baby Jubjub curve of EIP-2494, a twisted Edwards curve with `a != -1`, is in the
+//! `baby_jubjub` module. The pairing friendly curve BLS12-381 with its groups G1, G2, GT and
+//! the optimal ate pairing is in the `bls12_381` module. The Montgomery form Curve25519 of the
+//! Ed25519 curve, with the X25519 function of RFC 7748, is in the `curve25519` module, and the
+//! prime order group ristretto255 of RFC 9496 built on Ed25519 is in the `ristretto255` module.
+//! The curve Ed448-Goldilocks and the prime order group decaf448 of RFC 9496 are in the `ed448`
+//! module.
 //!    ```rust
 //!    use cryp_ec::curves::edwards25519::*;
 //!    use cryp_std::rand::thread_rng;
@@ -70,11 +78,75 @@
 //!
 //!    ```
 
-mod bls12_318;
+mod babyjubjub;
+mod bls12;
 mod ed25519;
+mod goldilocks;
+mod k256;
+mod ristretto;
+mod secp256r1;
+mod x25519;
 
 pub mod edwards25519 {
     use super::*;
     pub use crate::edwards::*;
-    pub use ed25519::{Ed25519Parameters, Fp25519, GroupEd25519, ScalarEd25519};
+    pub use ed25519::{
+        AffineEd25519, Ed25519Parameters, Fp25519, GroupEd25519, ScalarEd25519, EIGHT_TORSION,
+    };
+}
+
+pub mod ristretto255 {
+    use super::*;
+    pub use crate::edwards::*;
+    pub use ed25519::{Ed25519Parameters, Fp25519, ScalarEd25519};
+    pub use ristretto::{GroupRistretto255, PublicRistretto255, Ristretto255, RistrettoPoint};
+}
+
+pub mod ed448 {
+    use super::*;
+    pub use crate::edwards::*;
+    pub use goldilocks::{
+        AffineEd448, Decaf448, DecafPoint, Ed448Parameters, Fp448, Fp448Params, GroupDecaf448,
+        GroupEd448, PublicDecaf448, ScalarEd448, ScalarEd448Parameters,
+    };
+}
+
+pub mod curve25519 {
+    use super::*;
+    pub use crate::montgomery::*;
+    pub use ed25519::Fp25519;
+    pub use x25519::{x25519, Curve25519Parameters, Wei25519Parameters, X25519_BASEPOINT};
+}
+
+pub mod baby_jubjub {
+    use super::*;
+    pub use crate::edwards::*;
+    pub use babyjubjub::{
+        AffineBabyJubjub, BabyJubjubParameters, FpBabyJubjub, GroupBabyJubjub, ScalarBabyJubjub,
+    };
+}
+
+pub mod p256 {
+    use super::*;
+    pub use crate::weierstrass::*;
+    pub use secp256r1::{AffineP256, Fp256, GroupP256, P256Parameters, ScalarP256};
+}
+
+pub mod secp256k1 {
+    use super::*;
+    pub use crate::weierstrass::*;
+    pub use k256::{
+        AffineSecp256k1, FpSecp256k1, GroupSecp256k1, ScalarSecp256k1, Secp256k1Parameters,
+    };
+}
+
+pub mod bls12_381 {
+    use super::*;
+    pub use crate::weierstrass::*;
+    pub use bls12::{
+        AffineG1, AffineG2, Bls12381, Fp, Fp12, Fp12Parameters, Fp2, Fp2Parameters, Fp6,
+        Fp6Parameters, FpParameters, Fr, FrParameters, G1Parameters, G2Parameters, GroupG1,
+        GroupG2, GroupGT,
+    };
+    pub use cryp_alg::Pairing;
 }
@@ -0,0 +1,332 @@
+use crate::edwards::*;
+use crate::models::{field_from_bytes_be, generators_from_label};
+use cryp_alg::ff::*;
+use cryp_std::rand::Rng;
+use cryp_std::vec::Vec;
+
+pub type FpBabyJubjub = F<MontgomeryOperations<4, FpBabyJubjubParams>>;
+pub type ScalarBabyJubjub = F<MontgomeryOperations<4, ScalarBabyJubjubParameters>>;
+pub type GroupBabyJubjub = GroupEC<EdwardsGeneralUnifiedOperations<BabyJubjubParameters>>;
+pub type AffineBabyJubjub = PublicEC<EdwardsGeneralUnifiedOperations<BabyJubjubParameters>>;
+
+/// Parameters of the Baby Jubjub curve of EIP-2494, the twisted Edwards curve
+/// `168700x^2 + y^2 = 1 + 168696x^2y^2` over the scalar field of BN254.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BabyJubjubParameters;
+
+/// Parameters for the prime field FpBabyJubjub
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FpBabyJubjubParams;
+
+impl BabyJubjubParameters {
+    // The point Base8 of EIP-2494, which generates the subgroup of prime order
+    // 2736030358979909402780800718157159386076813972158567259200215660948447373041
+
+    // 5299619240641551281634865583518297030282874472190772894086521144482721001553
+    const X: [u64; 4] = [
+        2923948824128221265,
+        3078447844201652406,
+        5669102708735506369,
+        844278054434796443,
+    ];
+
+    // 16950150798460657717958625567821834550301663161624707787222815936182638968203
+    const Y: [u64; 4] = [
+        5421249259631377803,
+        18221569726161695607,
+        2690670003684637165,
+        2700314812950295113,
+    ];
+}
+
+impl MontParameters<4usize> for FpBabyJubjubParams {
+    type Limb = u64;
+
+    // 21888242871839275222246405745257275088548364400416034343698204186575808495617
+    const MODULUS: [Self::Limb; 4] = [
+        4891460686036598785,
+        2896914383306846353,
+        13281191951274694749,
+        3486998266802970665,
+    ];
+
+    const R: [Self::Limb; 4] = [
+        12436184717236109307,
+        3962172157175319849,
+        7381016538464732718,
+        1011752739694698287,
+    ];
+
+    const R2: [Self::Limb; 4] = [
+        1997599621687373223,
+        6052339484930628067,
+        10108755138030829701,
+        150537098327114917,
+    ];
+
+    const MP: Self::Limb = 14042775128853446655;
+}
+
+impl TwistedEdwardsGeneral for BabyJubjubParameters {
+    type Field = FpBabyJubjub;
+
+    // The element a = 168700 in Montgomery form
+    const A: Self::Field =
+        FpBabyJubjub::from_RAW_limbs(<FpBabyJubjub as PrimeField>::BigInteger::from_limbs([
+            10785223227458347488,
+            2627865112663806840,
+            16189334210225400552,
+            1096023023792938739,
+        ]));
+
+    // The element d = 168696 in Montgomery form
+    const D: Self::Field =
+        FpBabyJubjub::from_RAW_limbs(<FpBabyJubjub as PrimeField>::BigInteger::from_limbs([
+            2825433191969612277,
+            8122834940978925411,
+            18393204081350716044,
+            536010331817116255,
+        ]));
+}
+
+// The scalar Field
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ScalarBabyJubjubParameters;
+
+impl MontParameters<4usize> for ScalarBabyJubjubParameters {
+    type Limb = u64;
+
+    // 2736030358979909402780800718157159386076813972158567259200215660948447373041
+    const MODULUS: [Self::Limb; 4] = [
+        7454187305358665457,
+        12339561404529962506,
+        3965992003123030795,
+        435874783350371333,
+    ];
+
+    const R: [Self::Limb; 4] = [
+        518782427998428278,
+        16693999147318571595,
+        17895776605928222741,
+        140003172993955620,
+    ];
+
+    const R2: [Self::Limb; 4] = [
+        3883310962495500830,
+        8386947896187571908,
+        16461464917592642959,
+        307851319823716520,
+    ];
+
+    const MP: Self::Limb = 5993417742769255919;
+}
+
+impl PrimeSubGroupConfig for EdwardsGeneralUnifiedOperations<BabyJubjubParameters> {
+    type ScalarField = ScalarBabyJubjub;
+
+    const NAME: &'static str = "BabyJubjub";
+
+    type Cofactor = [u64; 1];
+
+    const COFACTOR: Self::Cofactor = [8];
+
+    fn generator<R: Rng>(rng: Option<&mut R>) -> Self::Affine {
+        let x = FpBabyJubjub::from_int(&BabyJubjubParameters::X.into());
+        let y = FpBabyJubjub::from_int(&BabyJubjubParameters::Y.into());
+        let affine_point = Self::Affine::new(x, y);
+
+        let mut point = Self::Point::from(affine_point);
+        if let Some(rng) = rng {
+            let scalar = ScalarBabyJubjub::rand(rng);
+            point = <Self as PrimeSubGroupConfig>::scalar_mul(&point, &scalar);
+        }
+        point.into_affine().unwrap()
+    }
+
+    /// The 32-byte encoding of circomlib: the little endian encoding of `y` with the
+    /// most significant bit set when `x > (p - 1) / 2`.
+    fn compress(input: &Self::Affine) -> Vec<u8> {
+        let x = input.x.as_int();
+        let minus_x = (-input.x).as_int();
+        let mut bytes = input
+            .y
+            .as_int()
+            .limbs
+            .iter()
+            .flat_map(|l| l.to_le_bytes())
+            .collect::<Vec<_>>();
+        if x.limbs.iter().rev().cmp(minus_x.limbs.iter().rev()) == cryp_std::cmp::Ordering::Greater
+        {
+            bytes[31] |= 0x80;
+        }
+        bytes
+    }
+
+    /// Uses the first 32 bytes of the digest, reduced to 254 bits, as `y` and the next bit
+    /// to choose the sign of `x`, and multiplies the point by the cofactor.
+    fn batch_generators_from_label(label: &[u8], n: usize) -> Vec<Self::Affine> {
+        generators_from_label::<Self>(label, n, |digest| {
+            let mut bytes = [0u8; 32];
+            bytes.copy_from_slice(&digest[..32]);
+            bytes[0] &= 0x3f;
+            let y = field_from_bytes_be::<FpBabyJubjub>(&bytes)?;
+
+            // ax^2 + y^2 = 1 + dx^2y^2, so x^2 = (1 - y^2) / (a - dy^2)
+            let y2 = y.square();
+            let denominator = BabyJubjubParameters::A - BabyJubjubParameters::D * y2;
+            let mut x = ((FpBabyJubjub::one() - y2) * denominator.inverse()?).sqrt()?;
+            if digest[32] & 1 == 1 {
+                x = -x;
+            }
+            Some(Self::mul_by_cofactor(&Affine::new(x, y).into()))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::check_generators;
+    use crate::models::CurveOperations;
+    use cryp_std::rand::rngs::ThreadRng;
+    use cryp_std::rand::thread_rng;
+    use cryp_std::string::ToString;
+
+    type Operations = EdwardsGeneralUnifiedOperations<BabyJubjubParameters>;
+
+    fn from_dec(digits: &str) -> FpBabyJubjub {
+        let ten = FpBabyJubjub::from_int(&[10, 0, 0, 0].into());
+        digits.chars().fold(FpBabyJubjub::zero(), |acc, c| {
+            let digit = c.to_digit(10).unwrap() as u64;
+            acc * ten + FpBabyJubjub::from_int(&[digit, 0, 0, 0].into())
+        })
+    }
+
+    fn is_on_curve(point: &Affine<FpBabyJubjub>) -> bool {
+        let (x2, y2) = (point.x.square(), point.y.square());
+        BabyJubjubParameters::A * x2 + y2 == FpBabyJubjub::one() + BabyJubjubParameters::D * x2 * y2
+    }
+
+    #[test]
+    fn test_parameters() {
+        assert_eq!(
+            BabyJubjubParameters::A,
+            FpBabyJubjub::from_int(&[168700, 0, 0, 0].into())
+        );
+        assert_eq!(
+            BabyJubjubParameters::D,
+            FpBabyJubjub::from_int(&[168696, 0, 0, 0].into())
+        );
+
+        let mut rng = thread_rng();
+        let x = FpBabyJubjub::rand(&mut rng);
+        assert_eq!(x * x.inverse().unwrap(), FpBabyJubjub::one());
+        let s = ScalarBabyJubjub::rand(&mut rng);
+        assert_eq!(s * s.inverse().unwrap(), ScalarBabyJubjub::one());
+    }
+
+    #[test]
+    fn test_group() {
+        let g = GroupBabyJubjub::generator::<ThreadRng>(None);
+        assert!(is_on_curve(&g.point));
+        assert!(GroupBabyJubjub::is_valid(&g));
+
+        let point = GroupBabyJubjub::from(g);
+        let identity = GroupBabyJubjub::identity();
+        let order = ScalarBabyJubjub::MODULUS;
+
+        assert_eq!(point + point, point.double());
+        assert_eq!(identity + point, point);
+        assert_eq!(point + (-point), identity);
+        assert_eq!(point.mul_int(&order), identity);
+        assert_eq!(point.mul_int_non_ct(&order), identity);
+
+        let mut rng = thread_rng();
+        for _ in 0..5 {
+            let a = ScalarBabyJubjub::rand(&mut rng);
+            let b = ScalarBabyJubjub::rand(&mut rng);
+            assert_eq!(point * &a + point * &b, point * &(a + b));
+            assert_eq!(point.mul_int_non_ct(&a.as_int()), point * &a);
+
+            let p = (point * &a).as_public().unwrap();
+            assert!(is_on_curve(&p.point));
+        }
+    }
+
+    #[test]
+    fn test_formulas() {
+        // mixed and projective additions agree, and are unified
+        let mut rng = thread_rng();
+        let g = GroupBabyJubjub::generator(Some(&mut rng)).point;
+        let h = GroupBabyJubjub::generator(Some(&mut rng)).point;
+
+        let mut sum = ExtendedPoint::from(g);
+        Operations::add_in_place(&mut sum, &ExtendedPoint::from(h));
+        let mut mixed = ExtendedPoint::from(g);
+        Operations::add_affine_in_place(&mut mixed, &h);
+        assert_eq!(sum, mixed);
+
+        let mut double = ExtendedPoint::from(g);
+        Operations::double_in_place(&mut double);
+        let mut mixed = ExtendedPoint::from(g);
+        Operations::add_affine_in_place(&mut mixed, &g);
+        assert_eq!(double, mixed);
+
+        let mut zero = ExtendedPoint::from(g);
+        let mut minus_g = ExtendedPoint::from(g);
+        Operations::neg_in_place(&mut minus_g);
+        Operations::add_in_place(&mut zero, &minus_g);
+        assert_eq!(zero, Operations::identity());
+    }
+
+    /// Test vectors from EIP-2494.
+    #[test]
+    fn test_vectors() {
+        // The generator G of the full group has order 8 * l, and Base8 = 8 * G
+        let g = AffineBabyJubjub::new(Affine::new(
+            from_dec("995203441582195749578291179787384436505546430278305826713579947235728471134"),
+            from_dec(
+                "5472060717959818805561601436314318772137091100104008585924551046643952123905",
+            ),
+        ));
+        assert!(is_on_curve(&g.point));
+        assert!(!GroupBabyJubjub::is_valid(&g));
+
+        let base8 = GroupBabyJubjub::generator::<ThreadRng>(None);
+        let g = GroupBabyJubjub::from(g);
+        assert_eq!(g.mul_int(&GroupBabyJubjub::cofactor()), base8.into());
+
+        let double = GroupBabyJubjub::from(base8).double().as_public().unwrap();
+        assert_eq!(
+            double.point,
+            Affine::new(
+                from_dec(
+                    "10031262171927540148667355526369034398030886437092045105752248699557385197826"
+                ),
+                from_dec(
+                    "633281375905621697187330766174974863687049529291089048651929454608812697683"
+                ),
+            )
+        );
+    }
+
+    #[test]
+    fn test_generators() {
+        check_generators::<GroupBabyJubjub>(
+            "2e0f17e76f6b52619ae9b904da3d34d9eb1285d276eead8ffe13cec32cb4338b",
+        );
+    }
+
+    #[test]
+    fn test_introspection() {
+        assert_eq!(GroupBabyJubjub::name(), "BabyJubjub");
+        assert_eq!(GroupBabyJubjub::cofactor(), [8]);
+        assert_eq!(GroupBabyJubjub::order(), ScalarBabyJubjub::MODULUS);
+
+        let g = GroupBabyJubjub::generator::<ThreadRng>(None);
+        assert_eq!(
+            g.to_string(),
+            "8b7d2d877a253c4b7733e1b91f05e0fcedf96bd11c2e572549b2a0f703727925"
+        );
+    }
+}
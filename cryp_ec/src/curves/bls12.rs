@@ -0,0 +1,54 @@
+//! The pairing friendly curve BLS12-381.
+//!
+//! The curve `E: y^2 = x^3 + 4` is defined over the 381-bit prime field `Fp`, and
+//! `G1` is its subgroup of prime order `r`. The group `G2` is the subgroup of order `r`
+//! of the sextic twist `E': y^2 = x^3 + 4(u + 1)` over `Fp2 = Fp[u] / (u^2 + 1)`.
+//! The towers `Fp6 = Fp2[v] / (v^3 - (u + 1))` and `Fp12 = Fp6[w] / (w^2 - v)` are
+//! also provided, together with the optimal ate pairing `e: G1 x G2 -> GT`.
+//!
+//! Parameters follow
+//! [draft-irtf-cfrg-pairing-friendly-curves](https://datatracker.ietf.org/doc/draft-irtf-cfrg-pairing-friendly-curves/)
+//! and points are compressed in the format of the Zcash implementation.
+
+use crate::models::field_from_bytes_be;
+use cryp_alg::ff::*;
+use cryp_std::cmp::Ordering;
+
+mod fields;
+mod g1;
+mod g2;
+mod isogeny;
+mod pairing;
+
+pub use fields::{
+    Fp, Fp12, Fp12Parameters, Fp2, Fp2Parameters, Fp6, Fp6Parameters, FpParameters, Fr,
+    FrParameters,
+};
+pub use g1::{AffineG1, G1Parameters, GroupG1};
+pub use g2::{AffineG2, G2Parameters, GroupG2};
+pub use pairing::{Bls12381, GroupGT};
+
+/// Whether `y` is larger than `-y` when both are seen as integers in `[0, p)`.
+fn is_lexicographically_largest(y: &Fp) -> bool {
+    Bytes::into_iter_be(&y.as_int()).cmp(Bytes::into_iter_be(&(-*y).as_int())) == Ordering::Greater
+}
+
+/// The element of Fp given by the first 48 bytes of a digest with the top 3 bits cleared,
+/// or `None` if it is not smaller than `p`.
+fn fp_from_digest(digest: &[u8; 64]) -> Option<Fp> {
+    let mut bytes = [0u8; 48];
+    bytes.copy_from_slice(&digest[..48]);
+    bytes[0] &= 0x1f;
+    field_from_bytes_be::<Fp>(&bytes)
+}
+
+/// Parses a big endian hexadecimal string of 96 characters into an element of Fp.
+#[cfg(test)]
+fn fp_from_hex(hex: &str) -> Fp {
+    let mut limbs = [0u64; 6];
+    for (i, limb) in limbs.iter_mut().enumerate() {
+        let start = 80 - 16 * i;
+        *limb = u64::from_str_radix(&hex[start..start + 16], 16).unwrap();
+    }
+    Fp::from_int(&limbs.into())
+}
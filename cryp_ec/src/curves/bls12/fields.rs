@@ -0,0 +1,567 @@
+use cryp_alg::ff::*;
+use cryp_alg::LimbInt;
+
+pub type Fp = F<MontgomeryOperations<6, FpParameters>>;
+pub type Fr = F<MontgomeryOperations<4, FrParameters>>;
+pub type Fp2 = QuadExtField<Fp2Parameters>;
+pub type Fp6 = CubicExtField<Fp6Parameters>;
+pub type Fp12 = QuadExtField<Fp12Parameters>;
+
+/// An element of Fp given in Montgomery form.
+pub(super) const fn fp(limbs: [u64; 6]) -> Fp {
+    Fp::from_RAW_limbs(LimbInt::from_limbs(limbs))
+}
+
+/// An element of Fp2 with coefficients given in Montgomery form.
+pub(super) const fn fp2(c0: [u64; 6], c1: [u64; 6]) -> Fp2 {
+    Fp2::new(fp(c0), fp(c1))
+}
+
+// 1 in Montgomery form
+const ONE: [u64; 6] = [
+    8505329371266088957,
+    17002214543764226050,
+    6865905132761471162,
+    8632934651105793861,
+    6631298214892334189,
+    1582556514881692819,
+];
+
+// -1 in Montgomery form
+const MINUS_ONE: [u64; 6] = [
+    4897101644811774638,
+    3654671041462534141,
+    569769440802610537,
+    17053147383018470266,
+    17227549637287919721,
+    291242102765847046,
+];
+
+/// Parameters for the base prime field Fp
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FpParameters;
+
+impl MontParameters<6usize> for FpParameters {
+    type Limb = u64;
+
+    // 4002409555221667393417789825735904156556882819939007885332058136124031650490837864442687629129015664037894272559787
+    const MODULUS: [Self::Limb; 6] = [
+        13402431016077863595,
+        2210141511517208575,
+        7435674573564081700,
+        7239337960414712511,
+        5412103778470702295,
+        1873798617647539866,
+    ];
+
+    const R: [Self::Limb; 6] = ONE;
+
+    const R2: [Self::Limb; 6] = [
+        17644856173732828998,
+        754043588434789617,
+        10224657059481499349,
+        7488229067341005760,
+        11130996698012816685,
+        1267921511277847466,
+    ];
+
+    const MP: Self::Limb = 9940570264628428797;
+}
+
+/// Parameters for the scalar field Fr
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FrParameters;
+
+impl MontParameters<4usize> for FrParameters {
+    type Limb = u64;
+
+    // 52435875175126190479447740508185965837690552500527637822603658699938581184513
+    const MODULUS: [Self::Limb; 4] = [
+        18446744069414584321,
+        6034159408538082302,
+        3691218898639771653,
+        8353516859464449352,
+    ];
+
+    const R: [Self::Limb; 4] = [
+        8589934590,
+        6378425256633387010,
+        11064306276430008309,
+        1739710354780652911,
+    ];
+
+    const R2: [Self::Limb; 4] = [
+        14526898881837571181,
+        3129137299524312099,
+        419701826671360399,
+        524908885293268753,
+    ];
+
+    const MP: Self::Limb = 18446744069414584319;
+}
+
+impl FftParameters<4usize> for FrParameters {
+    const TWO_ADICITY: u32 = 32;
+
+    // 7^((r - 1) / 2^32)
+    const TWO_ADIC_ROOT_OF_UNITY: [Self::Limb; 4] = [
+        4046931900703378731,
+        13129826145616953529,
+        15031722638446171060,
+        1631043718794977056,
+    ];
+}
+
+/// Parameters of Fp2 = Fp[u] / (u^2 + 1)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Fp2Parameters;
+
+impl QuadExtParameters for Fp2Parameters {
+    type BaseField = Fp;
+    type FrobCoeff = Fp;
+
+    const NONRESIDUE: Fp = fp(MINUS_ONE);
+
+    const FROBENIUS_COEFF_C1: &'static [Fp] = &[fp(ONE), fp(MINUS_ONE)];
+
+    fn mul_base_by_nonresidue(element: &Fp) -> Fp {
+        -*element
+    }
+
+    fn mul_base_by_frob_coeff(c1: &mut Fp, power: usize) {
+        *c1 *= Self::FROBENIUS_COEFF_C1[power % 2];
+    }
+}
+
+/// Parameters of Fp6 = Fp2[v] / (v^3 - (u + 1))
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Fp6Parameters;
+
+impl CubicExtParameters for Fp6Parameters {
+    type BaseField = Fp2;
+    type FrobCoeff = Fp2;
+
+    const NONRESIDUE: Fp2 = fp2(ONE, ONE);
+
+    // (u + 1)^((p^i - 1) / 3) for i = 0, ..., 5
+    const FROBENIUS_COEFF_C1: &'static [Fp2] = &[
+        fp2(
+            [
+                8505329371266088957,
+                17002214543764226050,
+                6865905132761471162,
+                8632934651105793861,
+                6631298214892334189,
+                1582556514881692819,
+            ],
+            [0; 6],
+        ),
+        fp2(
+            [0; 6],
+            [
+                14772873186050699377,
+                6749526151121446354,
+                6372666795664677781,
+                10283423008382700446,
+                286397964926079186,
+                1796971870900422465,
+            ],
+        ),
+        fp2(
+            [
+                3526659474838938856,
+                17562030475567847978,
+                1632777218702014455,
+                14009062335050482331,
+                3906511377122991214,
+                368068849512964448,
+            ],
+            [0; 6],
+        ),
+        fp2(
+            [0; 6],
+            [
+                8505329371266088957,
+                17002214543764226050,
+                6865905132761471162,
+                8632934651105793861,
+                6631298214892334189,
+                1582556514881692819,
+            ],
+        ),
+        fp2(
+            [
+                14772873186050699377,
+                6749526151121446354,
+                6372666795664677781,
+                10283423008382700446,
+                286397964926079186,
+                1796971870900422465,
+            ],
+            [0; 6],
+        ),
+        fp2(
+            [0; 6],
+            [
+                3526659474838938856,
+                17562030475567847978,
+                1632777218702014455,
+                14009062335050482331,
+                3906511377122991214,
+                368068849512964448,
+            ],
+        ),
+    ];
+
+    // (u + 1)^((2 * p^i - 2) / 3) for i = 0, ..., 5
+    const FROBENIUS_COEFF_C2: &'static [Fp2] = &[
+        fp2(
+            [
+                8505329371266088957,
+                17002214543764226050,
+                6865905132761471162,
+                8632934651105793861,
+                6631298214892334189,
+                1582556514881692819,
+            ],
+            [0; 6],
+        ),
+        fp2(
+            [
+                9875771541238924739,
+                3094855109658912213,
+                5802897354862067244,
+                11677019699073781796,
+                1505592401347711080,
+                1505729768134575418,
+            ],
+            [0; 6],
+        ),
+        fp2(
+            [
+                14772873186050699377,
+                6749526151121446354,
+                6372666795664677781,
+                10283423008382700446,
+                286397964926079186,
+                1796971870900422465,
+            ],
+            [0; 6],
+        ),
+        fp2(
+            [
+                4897101644811774638,
+                3654671041462534141,
+                569769440802610537,
+                17053147383018470266,
+                17227549637287919721,
+                291242102765847046,
+            ],
+            [0; 6],
+        ),
+        fp2(
+            [
+                3526659474838938856,
+                17562030475567847978,
+                1632777218702014455,
+                14009062335050482331,
+                3906511377122991214,
+                368068849512964448,
+            ],
+            [0; 6],
+        ),
+        fp2(
+            [
+                17076301903736715834,
+                13907359434105313836,
+                1063007777899403918,
+                15402659025741563681,
+                5125705813544623108,
+                76826746747117401,
+            ],
+            [0; 6],
+        ),
+    ];
+
+    fn mul_base_by_nonresidue(element: &Fp2) -> Fp2 {
+        // (c0 + c1 * u) * (1 + u) = (c0 - c1) + (c0 + c1) * u
+        Fp2::new(element.c0 - element.c1, element.c0 + element.c1)
+    }
+
+    fn mul_base_by_frob_coeff(c1: &mut Fp2, c2: &mut Fp2, power: usize) {
+        *c1 *= Self::FROBENIUS_COEFF_C1[power % 6];
+        *c2 *= Self::FROBENIUS_COEFF_C2[power % 6];
+    }
+}
+
+/// Parameters of Fp12 = Fp6[w] / (w^2 - v)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Fp12Parameters;
+
+impl QuadExtParameters for Fp12Parameters {
+    type BaseField = Fp6;
+    type FrobCoeff = Fp2;
+
+    const NONRESIDUE: Fp6 = Fp6::new(fp2([0; 6], [0; 6]), fp2(ONE, [0; 6]), fp2([0; 6], [0; 6]));
+
+    // (u + 1)^((p^i - 1) / 6) for i = 0, ..., 11
+    const FROBENIUS_COEFF_C1: &'static [Fp2] = &[
+        fp2(
+            [
+                8505329371266088957,
+                17002214543764226050,
+                6865905132761471162,
+                8632934651105793861,
+                6631298214892334189,
+                1582556514881692819,
+            ],
+            [0; 6],
+        ),
+        fp2(
+            [
+                506819140503852133,
+                14297063575771579155,
+                10946065744702939791,
+                11771194236670323182,
+                2081670087578406477,
+                644615147456521963,
+            ],
+            [
+                12895611875574011462,
+                6359822009455181036,
+                14936352902570693524,
+                13914887797453940944,
+                3330433690892295817,
+                1229183470191017903,
+            ],
+        ),
+        fp2(
+            [
+                17076301903736715834,
+                13907359434105313836,
+                1063007777899403918,
+                15402659025741563681,
+                5125705813544623108,
+                76826746747117401,
+            ],
+            [0; 6],
+        ),
+        fp2(
+            [
+                4480897313486445265,
+                4797496051193971075,
+                4046559893315008306,
+                10569151167044009496,
+                2123814803385151673,
+                852749317591686856,
+            ],
+            [
+                8921533702591418330,
+                15859389534032789116,
+                3389114680249073393,
+                15116930867080254631,
+                3288288975085550621,
+                1021049300055853010,
+            ],
+        ),
+        fp2(
+            [
+                3526659474838938856,
+                17562030475567847978,
+                1632777218702014455,
+                14009062335050482331,
+                3906511377122991214,
+                368068849512964448,
+            ],
+            [0; 6],
+        ),
+        fp2(
+            [
+                3974078172982593132,
+                8947176549131943536,
+                11547238222321620130,
+                17244701004083237929,
+                42144715806745195,
+                208134170135164893,
+            ],
+            [
+                9428352843095270463,
+                11709709036094816655,
+                14335180424952013185,
+                8441381030041026197,
+                5369959062663957099,
+                1665664447512374973,
+            ],
+        ),
+        fp2(
+            [
+                4897101644811774638,
+                3654671041462534141,
+                569769440802610537,
+                17053147383018470266,
+                17227549637287919721,
+                291242102765847046,
+            ],
+            [0; 6],
+        ),
+        fp2(
+            [
+                12895611875574011462,
+                6359822009455181036,
+                14936352902570693524,
+                13914887797453940944,
+                3330433690892295817,
+                1229183470191017903,
+            ],
+            [
+                506819140503852133,
+                14297063575771579155,
+                10946065744702939791,
+                11771194236670323182,
+                2081670087578406477,
+                644615147456521963,
+            ],
+        ),
+        fp2(
+            [
+                14772873186050699377,
+                6749526151121446354,
+                6372666795664677781,
+                10283423008382700446,
+                286397964926079186,
+                1796971870900422465,
+            ],
+            [0; 6],
+        ),
+        fp2(
+            [
+                8921533702591418330,
+                15859389534032789116,
+                3389114680249073393,
+                15116930867080254631,
+                3288288975085550621,
+                1021049300055853010,
+            ],
+            [
+                4480897313486445265,
+                4797496051193971075,
+                4046559893315008306,
+                10569151167044009496,
+                2123814803385151673,
+                852749317591686856,
+            ],
+        ),
+        fp2(
+            [
+                9875771541238924739,
+                3094855109658912213,
+                5802897354862067244,
+                11677019699073781796,
+                1505592401347711080,
+                1505729768134575418,
+            ],
+            [0; 6],
+        ),
+        fp2(
+            [
+                9428352843095270463,
+                11709709036094816655,
+                14335180424952013185,
+                8441381030041026197,
+                5369959062663957099,
+                1665664447512374973,
+            ],
+            [
+                3974078172982593132,
+                8947176549131943536,
+                11547238222321620130,
+                17244701004083237929,
+                42144715806745195,
+                208134170135164893,
+            ],
+        ),
+    ];
+
+    fn mul_base_by_nonresidue(element: &Fp6) -> Fp6 {
+        // (c0 + c1 * v + c2 * v^2) * v = (u + 1) * c2 + c0 * v + c1 * v^2
+        Fp6::new(
+            Fp6Parameters::mul_base_by_nonresidue(&element.c2),
+            element.c0,
+            element.c1,
+        )
+    }
+
+    fn mul_base_by_frob_coeff(c1: &mut Fp6, power: usize) {
+        *c1 = c1.mul_by_base(&Self::FROBENIUS_COEFF_C1[power % 12]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cryp_std::rand::thread_rng;
+
+    #[test]
+    fn test_prime_fields() {
+        let mut rng = thread_rng();
+
+        let x = Fp::rand(&mut rng);
+        assert_eq!(x * x.inverse().unwrap(), Fp::one());
+        assert_eq!(fp(ONE), Fp::one());
+        assert_eq!(fp(MINUS_ONE), -Fp::one());
+        assert_eq!((-Fp::one()).as_int().limbs[0], FpParameters::MODULUS[0] - 1);
+
+        let s = Fr::rand(&mut rng);
+        assert_eq!(s * s.inverse().unwrap(), Fr::one());
+
+        let root = Fr::two_adic_root_of_unity();
+        assert_eq!(root.exp(&[1u64 << 32]), Fr::one());
+        assert_ne!(root.exp(&[1u64 << 31]), Fr::one());
+    }
+
+    #[test]
+    fn test_tower() {
+        let mut rng = thread_rng();
+
+        // u^2 = -1, v^3 = u + 1, w^2 = v
+        let u = Fp2::new(Fp::zero(), Fp::one());
+        assert_eq!(u.square(), -Fp2::one());
+        let v = Fp6::new(Fp2::zero(), Fp2::one(), Fp2::zero());
+        assert_eq!(
+            v * v * v,
+            Fp6::new(u + Fp2::one(), Fp2::zero(), Fp2::zero())
+        );
+        let w = Fp12::new(Fp6::zero(), Fp6::one());
+        assert_eq!(w.square(), Fp12::new(v, Fp6::zero()));
+
+        for _ in 0..5 {
+            let a = Fp12::rand(&mut rng);
+            let b = Fp12::rand(&mut rng);
+            assert_eq!(a * a.inverse().unwrap(), Fp12::one());
+            assert_eq!(a.square(), a * a);
+            assert_eq!((a * b) * a.inverse().unwrap(), b);
+
+            let c = Fp6::rand(&mut rng);
+            assert_eq!(c * c.inverse().unwrap(), Fp6::one());
+        }
+    }
+
+    #[test]
+    fn test_frobenius() {
+        let mut rng = thread_rng();
+
+        let a = Fp2::rand(&mut rng);
+        assert_eq!(a.frobenius_map(1), a.exp(&FpParameters::MODULUS));
+        let b = Fp6::rand(&mut rng);
+        assert_eq!(b.frobenius_map(1), b.exp(&FpParameters::MODULUS));
+        let c = Fp12::rand(&mut rng);
+        let c_p = c.exp(&FpParameters::MODULUS);
+        assert_eq!(c.frobenius_map(1), c_p);
+        assert_eq!(c.frobenius_map(2), c_p.exp(&FpParameters::MODULUS));
+
+        for i in 0..12 {
+            assert_eq!(c.frobenius_map(i).frobenius_map(12 - i), c);
+            assert_eq!(b.frobenius_map(i).frobenius_map(1), b.frobenius_map(i + 1));
+        }
+    }
+}
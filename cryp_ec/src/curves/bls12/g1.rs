@@ -0,0 +1,372 @@
+use super::fields::{Fp, Fr};
+use super::isogeny::*;
+use super::{fp_from_digest, is_lexicographically_largest};
+use crate::hash_to_curve::{
+    encode_to_curve, expand_message_xmd, hash_to_curve, isogeny_map, map_to_curve_sswu,
+    HashToCurveConfig, SSWUMap, Sha256,
+};
+use crate::models::scalar_mul::ScalarMul;
+use crate::models::CurveOperations;
+use crate::models::generators_from_label;
+use crate::weierstrass::*;
+use cryp_alg::ff::*;
+use cryp_std::rand::Rng;
+use cryp_std::vec::Vec;
+
+pub type GroupG1 = GroupEC<ShortWeierstrassCompleteOperations<G1Parameters>>;
+pub type AffineG1 = PublicEC<ShortWeierstrassCompleteOperations<G1Parameters>>;
+
+/// Parameters of the curve `y^2 = x^3 + 4` over Fp.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct G1Parameters;
+
+impl G1Parameters {
+    // 3685416753713387016781088315183077757961620795782546409894578378688607592378376318836054947676345821548104185464507
+    const X: [u64; 6] = [
+        18103045581585958587,
+        7806400890582735599,
+        11623291730934869080,
+        14080658508445169925,
+        2780237799254240271,
+        1725392847304644500,
+    ];
+
+    // 1339506544944476473020471379941921221584933875938349620426543736416511423956333506472724655353366534992391756441569
+    const Y: [u64; 6] = [
+        912580534683953121,
+        15005087156090211044,
+        61670280795567085,
+        18227722000993880822,
+        11573741888802228964,
+        627113611842199793,
+    ];
+
+    // A primitive cube root of unity in Montgomery form, such that the endomorphism acts
+    // as the scalar -x^2 on G1
+    // 793479390729215512621379701633421447060886740281060493010456487427281649075476305620758731620350
+    const BETA: Fp = Fp::from_RAW_limbs(<Fp as PrimeField>::BigInteger::from_limbs([
+        3526659474838938856,
+        17562030475567847978,
+        1632777218702014455,
+        14009062335050482331,
+        3906511377122991214,
+        368068849512964448,
+    ]));
+
+    // x^2 for the curve parameter x = -0xd201000000010000
+    const X_SQUARED: [u64; 2] = [4294967296, 12413508272118670338];
+
+    // The effective cofactor 1 - x of RFC 9380, section 8.8.1
+    const H_EFF: [u64; 1] = [15132376222941642753];
+
+    /// The endomorphism `(x, y) -> (beta * x, y)` in projective coordinates.
+    pub fn endomorphism(point: &Projective<Fp>) -> Projective<Fp> {
+        Projective {
+            X: point.X * Self::BETA,
+            Y: point.Y,
+            Z: point.Z,
+        }
+    }
+}
+
+impl ShortWeierstrass for G1Parameters {
+    type Field = Fp;
+
+    const A: Self::Field = Fp::from_RAW_limbs(<Fp as PrimeField>::BigInteger::from_limbs([0; 6]));
+
+    // The element b = 4 in Montgomery form
+    const B: Self::Field = Fp::from_RAW_limbs(<Fp as PrimeField>::BigInteger::from_limbs([
+        12260768510540316659,
+        6038201419376623626,
+        5156596810353639551,
+        12813724723179037911,
+        10288881524157229871,
+        708830206584151678,
+    ]));
+
+    const A_KIND: CoefficientKind = CoefficientKind::Zero;
+}
+
+impl PrimeSubGroupConfig for ShortWeierstrassCompleteOperations<G1Parameters> {
+    type ScalarField = Fr;
+
+    const NAME: &'static str = "BLS12-381 G1";
+
+    type Cofactor = [u64; 2];
+
+    // 0x396c8c005555e1568c00aaab0000aaab
+    const COFACTOR: Self::Cofactor = [10088250816726084267, 4137836090706223446];
+
+    fn generator<R: Rng>(rng: Option<&mut R>) -> Self::Affine {
+        let x = Fp::from_int(&G1Parameters::X.into());
+        let y = Fp::from_int(&G1Parameters::Y.into());
+        let affine_point = Self::Affine::new(x, y);
+
+        let mut point = Self::Point::from(affine_point);
+        if let Some(rng) = rng {
+            let scalar = Fr::rand(rng);
+            point = <Self as PrimeSubGroupConfig>::scalar_mul(&point, &scalar);
+        }
+        point.into_affine().unwrap()
+    }
+
+    /// Checks that the endomorphism acts as `-x^2`, see section 6 of
+    /// "Co-factor clearing and subgroup membership testing on pairing-friendly curves"
+    /// by Scott, https://eprint.iacr.org/2021/1130, and https://eprint.iacr.org/2022/352.
+    fn is_in_subgroup(point: &Self::Affine) -> bool {
+        let point = Self::Point::from(*point);
+        let mut sum = ScalarMul::wnaf::<Self>(&point, &G1Parameters::X_SQUARED);
+        Self::add_in_place(&mut sum, &G1Parameters::endomorphism(&point));
+        sum == Self::identity()
+    }
+
+    /// Multiplication by the effective cofactor `1 - x` of RFC 9380, section 8.8.1.
+    fn clear_cofactor(point: &Self::Point) -> Self::Point {
+        ScalarMul::wnaf::<Self>(point, &G1Parameters::H_EFF)
+    }
+
+    /// The 48 byte big endian encoding of `x`, with the top bit set to mark the
+    /// compressed form and the third bit set when `y` is lexicographically largest.
+    fn compress(input: &Self::Affine) -> Vec<u8> {
+        let mut bytes = Bytes::into_iter_be(&input.x.as_int()).collect::<Vec<_>>();
+        bytes[0] |= 0x80;
+        if is_lexicographically_largest(&input.y) {
+            bytes[0] |= 0x20;
+        }
+        bytes
+    }
+
+    /// Uses the first 48 bytes of the digest, reduced to 381 bits, as `x` and the next bit
+    /// to choose the sign of `y`, and clears the cofactor.
+    fn batch_generators_from_label(label: &[u8], n: usize) -> Vec<Self::Affine> {
+        generators_from_label::<Self>(label, n, |digest| {
+            let x = fp_from_digest(digest)?;
+            let mut y = (x.square() * x + G1Parameters::B).sqrt()?;
+            if digest[48] & 1 == 1 {
+                y = -y;
+            }
+            Some(Self::clear_cofactor(&Affine::new(x, y).into()))
+        })
+    }
+
+    /// The suite `BLS12381G1_XMD:SHA-256_SSWU_RO_` of RFC 9380.
+    fn hash_to_curve(msg: &[u8], dst: &[u8]) -> Option<Self::Point> {
+        Some(hash_to_curve::<Self>(msg, dst))
+    }
+
+    /// The suite `BLS12381G1_XMD:SHA-256_SSWU_NU_` of RFC 9380.
+    fn encode_to_curve(msg: &[u8], dst: &[u8]) -> Option<Self::Point> {
+        Some(encode_to_curve::<Self>(msg, dst))
+    }
+}
+
+impl SSWUMap for G1Parameters {
+    const ISO_A: Self::Field = G1_ISO_A;
+    const ISO_B: Self::Field = G1_ISO_B;
+    const Z: Self::Field = G1_Z;
+
+    /// The 11-isogeny from the curve `y^2 = x^3 + A'x + B'`.
+    fn isogeny_map(point: Affine<Fp>) -> Option<Affine<Fp>> {
+        isogeny_map(
+            &point,
+            &G1_ISO_X_NUM,
+            &G1_ISO_X_DEN,
+            &G1_ISO_Y_NUM,
+            &G1_ISO_Y_DEN,
+        )
+    }
+}
+
+impl HashToCurveConfig for ShortWeierstrassCompleteOperations<G1Parameters> {
+    const L: usize = 64;
+
+    fn expand_message(msg: &[u8], dst: &[u8], len_in_bytes: usize) -> Vec<u8> {
+        expand_message_xmd::<Sha256>(msg, dst, len_in_bytes)
+    }
+
+    fn map_to_curve(u: &Fp) -> Self::Point {
+        map_to_curve_sswu::<G1Parameters>(u).map_or_else(Self::identity, Into::into)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::fp_from_hex;
+    use super::*;
+    use crate::models::check_generators;
+    use cryp_std::rand::rngs::ThreadRng;
+    use cryp_std::rand::thread_rng;
+    use cryp_std::string::ToString;
+
+    #[test]
+    fn test_group() {
+        let g = GroupG1::generator::<ThreadRng>(None);
+        let (x, y) = (g.point.x, g.point.y);
+
+        // check y^2 = x^3 + 4
+        assert_eq!(y.square(), x.square() * x + G1Parameters::B);
+        assert_eq!(G1Parameters::B, Fp::from_int(&[4, 0, 0, 0, 0, 0].into()));
+        assert!(GroupG1::is_valid(&g));
+
+        let point = GroupG1::from(g);
+        let identity = GroupG1::identity();
+        let order = Fr::MODULUS;
+
+        assert_eq!(point + point, point.double());
+        assert_eq!(identity + point, point);
+        assert_eq!(point + (-point), identity);
+        assert_eq!(point.mul_int(&order), identity);
+        assert_eq!(point.mul_int_non_ct(&order), identity);
+
+        let mut rng = thread_rng();
+        for _ in 0..5 {
+            let a = Fr::rand(&mut rng);
+            let b = Fr::rand(&mut rng);
+            assert_eq!(point * &a + point * &b, point * &(a + b));
+            assert_eq!(point.mul_int_non_ct(&a.as_int()), point * &a);
+        }
+    }
+
+    #[test]
+    fn test_vectors() {
+        let g = GroupG1::generator::<ThreadRng>(None);
+        assert_eq!(
+            g.to_string(),
+            "97f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb"
+        );
+
+        let double = GroupG1::from(g).double().as_public().unwrap();
+        assert_eq!(
+            double.point,
+            Affine::new(
+                fp_from_hex("0572cbea904d67468808c8eb50a9450c9721db309128012543902d0ac358a62ae28f75bb8f1c7c42c39a8c5529bf0f4e"),
+                fp_from_hex("166a9d8cabc673a322fda673779d8e3822ba3ecb8670e461f73bb9021d5fd76a4c56d9d4cd16bd1bba86881979749d28"),
+            )
+        );
+    }
+
+    #[test]
+    fn test_subgroup() {
+        // (0, 2) is on the curve and has order 3
+        let t = AffineG1::new(Affine::new(Fp::zero(), Fp::one().double()));
+        assert_eq!(t.point.y.square(), G1Parameters::B);
+        assert!(!GroupG1::is_valid(&t));
+        let t = GroupG1::from(t);
+        assert_eq!(t + t + t, GroupG1::identity());
+
+        // g + t is on the curve but not in the subgroup of order r
+        let g = GroupG1::from(GroupG1::generator::<ThreadRng>(None));
+        let p = g + t;
+        assert!(!GroupG1::is_valid(&p.as_public().unwrap()));
+
+        let cleared = p.mul_int(&GroupG1::cofactor());
+        assert_eq!(cleared, g.mul_int(&GroupG1::cofactor()));
+        assert!(GroupG1::is_valid(&cleared.as_public().unwrap()));
+
+        // the endomorphism acts as -x^2 on the subgroup
+        let phi = G1Parameters::endomorphism(&g.as_public().unwrap().point.into());
+        assert_eq!(GroupG1::new(phi), -g.mul_int(&G1Parameters::X_SQUARED));
+
+        // the endomorphism check agrees with multiplication by the order
+        let mut rng = thread_rng();
+        for _ in 0..5 {
+            let q = GroupG1::from(GroupG1::generator(Some(&mut rng)));
+            for r in [q, q + t, q + t + t] {
+                let in_subgroup = r.mul_int(&Fr::MODULUS) == GroupG1::identity();
+                assert_eq!(GroupG1::is_valid(&r.as_public().unwrap()), in_subgroup);
+            }
+        }
+
+        // the point of order 3 has small order, clearing the cofactor removes it
+        assert!(t.is_small_order());
+        assert!(!g.is_small_order());
+        assert!(g.is_torsion_free());
+        assert!(!p.is_torsion_free());
+        assert!(GroupG1::identity().is_torsion_free());
+        assert_eq!(p.mul_by_cofactor(), cleared);
+        assert_eq!(p.clear_cofactor(), g.mul_int(&G1Parameters::H_EFF));
+        assert!(p.clear_cofactor().is_torsion_free());
+
+        // points that are not on the curve are rejected
+        let mut off_curve = GroupG1::generator::<ThreadRng>(None);
+        off_curve.point.y += Fp::one();
+        assert!(!GroupG1::is_valid(&off_curve));
+    }
+
+    #[test]
+    fn test_generators() {
+        check_generators::<GroupG1>(
+            "a9358d8e786370c98cac9ad1fc48d9b3668310ba0c9f37c6078901c79fe12ad9\
+             b1ff7e038a7b428a6f809e4dd5a9ac58",
+        );
+    }
+
+    /// The test vectors of RFC 9380, appendix J.9, for the suites
+    /// BLS12381G1_XMD:SHA-256_SSWU_RO_ and BLS12381G1_XMD:SHA-256_SSWU_NU_
+    #[test]
+    fn test_hash_to_curve() {
+        let q128 = [b"q128_".as_slice(), &[b'q'; 128]].concat();
+        let a512 = [b"a512_".as_slice(), &[b'a'; 512]].concat();
+        let msgs: [&[u8]; 5] = [b"", b"abc", b"abcdef0123456789", &q128, &a512];
+
+        let dst = b"QUUX-V01-CS02-with-BLS12381G1_XMD:SHA-256_SSWU_RO_";
+        let expected = [
+            (
+                "052926add2207b76ca4fa57a8734416c8dc95e24501772c814278700eed6d1e4e8cf62d9c09db0fac349612b759e79a1",
+                "08ba738453bfed09cb546dbb0783dbb3a5f1f566ed67bb6be0e8c67e2e81a4cc68ee29813bb7994998f3eae0c9c6a265",
+            ),
+            (
+                "03567bc5ef9c690c2ab2ecdf6a96ef1c139cc0b2f284dca0a9a7943388a49a3aee664ba5379a7655d3c68900be2f6903",
+                "0b9c15f3fe6e5cf4211f346271d7b01c8f3b28be689c8429c85b67af215533311f0b8dfaaa154fa6b88176c229f2885d",
+            ),
+            (
+                "11e0b079dea29a68f0383ee94fed1b940995272407e3bb916bbf268c263ddd57a6a27200a784cbc248e84f357ce82d98",
+                "03a87ae2caf14e8ee52e51fa2ed8eefe80f02457004ba4d486d6aa1f517c0889501dc7413753f9599b099ebcbbd2d709",
+            ),
+            (
+                "15f68eaa693b95ccb85215dc65fa81038d69629f70aeee0d0f677cf22285e7bf58d7cb86eefe8f2e9bc3f8cb84fac488",
+                "1807a1d50c29f430b8cafc4f8638dfeeadf51211e1602a5f184443076715f91bb90a48ba1e370edce6ae1062f5e6dd38",
+            ),
+            (
+                "082aabae8b7dedb0e78aeb619ad3bfd9277a2f77ba7fad20ef6aabdc6c31d19ba5a6d12283553294c1825c4b3ca2dcfe",
+                "05b84ae5a942248eea39e1d91030458c40153f3b654ab7872d779ad1e942856a20c438e8d99bc8abfbf74729ce1f7ac8",
+            ),
+        ];
+        for (msg, (x, y)) in msgs.iter().zip(expected) {
+            let point = GroupG1::hash_to_curve(msg, dst).unwrap();
+            assert_eq!(
+                point.as_public().unwrap().point,
+                Affine::new(fp_from_hex(x), fp_from_hex(y))
+            );
+        }
+
+        let dst = b"QUUX-V01-CS02-with-BLS12381G1_XMD:SHA-256_SSWU_NU_";
+        let expected = [
+            (
+                "184bb665c37ff561a89ec2122dd343f20e0f4cbcaec84e3c3052ea81d1834e192c426074b02ed3dca4e7676ce4ce48ba",
+                "04407b8d35af4dacc809927071fc0405218f1401a6d15af775810e4e460064bcc9468beeba82fdc751be70476c888bf3",
+            ),
+            (
+                "009769f3ab59bfd551d53a5f846b9984c59b97d6842b20a2c565baa167945e3d026a3755b6345df8ec7e6acb6868ae6d",
+                "1532c00cf61aa3d0ce3e5aa20c3b531a2abd2c770a790a2613818303c6b830ffc0ecf6c357af3317b9575c567f11cd2c",
+            ),
+        ];
+        for (msg, (x, y)) in msgs.iter().zip(expected) {
+            let point = GroupG1::encode_to_curve(msg, dst).unwrap();
+            assert_eq!(
+                point.as_public().unwrap().point,
+                Affine::new(fp_from_hex(x), fp_from_hex(y))
+            );
+        }
+    }
+
+    #[test]
+    fn test_introspection() {
+        assert_eq!(GroupG1::name(), "BLS12-381 G1");
+        assert_eq!(
+            GroupG1::cofactor(),
+            [10088250816726084267, 4137836090706223446]
+        );
+        assert_eq!(GroupG1::order(), Fr::MODULUS);
+    }
+}
@@ -0,0 +1,509 @@
+use super::fields::{Fp, Fp2, Fr};
+use super::isogeny::*;
+use super::{fp_from_digest, is_lexicographically_largest};
+use crate::hash_to_curve::{
+    encode_to_curve, expand_message_xmd, hash_to_curve, isogeny_map, map_to_curve_sswu,
+    HashToCurveConfig, SSWUMap, Sha256,
+};
+use crate::models::scalar_mul::ScalarMul;
+use crate::models::CurveOperations;
+use crate::models::generators_from_label;
+use crate::weierstrass::*;
+use cryp_alg::ff::*;
+use cryp_hash::{Digest, Sha512};
+use cryp_std::rand::Rng;
+use cryp_std::vec::Vec;
+
+pub type GroupG2 = GroupEC<ShortWeierstrassCompleteOperations<G2Parameters>>;
+pub type AffineG2 = PublicEC<ShortWeierstrassCompleteOperations<G2Parameters>>;
+
+/// Parameters of the sextic twist `y^2 = x^3 + 4(u + 1)` over Fp2.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct G2Parameters;
+
+impl G2Parameters {
+    // 352701069587466618187139116011060144890029952792775240219908644239793785735715026873347600343865175952761926303160
+    const X_C0: [u64; 6] = [
+        15312334153293348280,
+        841050694974028783,
+        12993178926126977399,
+        14331714969349929730,
+        2740446039084699729,
+        165123225776229009,
+    ];
+
+    // 3059144344244213709971259814753781636986470325476647558659373206291635324768958432433509563104347017837885763365758
+    const X_C1: [u64; 6] = [
+        16549740192668593022,
+        3696594454104530263,
+        13103893525273989193,
+        6443473286224459290,
+        9055845637167730533,
+        1432192374203850592,
+    ];
+
+    // 1985150602287291935568054521177171638300868978215655730859378665066344726373823718423869104263333984641494340347905
+    const Y_C0: [u64; 6] = [
+        16254428414758889473,
+        10536956157198377609,
+        7873024875724591404,
+        12537348094477325223,
+        10144865889576432922,
+        929383263523139089,
+    ];
+
+    // 927553665492332455747201965776037880757740193453592970025027978793976877002675564980949289727957565575433344219582
+    const Y_C1: [u64; 6] = [
+        12297368366147926462,
+        4555124010822409633,
+        2771000935339432363,
+        14645187562128761775,
+        3651525051980876697,
+        434250606344352972,
+    ];
+
+    // The coefficients 1 / (u + 1)^((p - 1) / 3) and 1 / (u + 1)^((p - 1) / 2) of the
+    // untwist-Frobenius-twist endomorphism psi, in Montgomery form
+    const PSI_COEFF_X: Fp2 = Fp2::new(
+        Fp::from_RAW_limbs(<Fp as PrimeField>::BigInteger::from_limbs([0; 6])),
+        Fp::from_RAW_limbs(<Fp as PrimeField>::BigInteger::from_limbs([
+            9875771541238924739,
+            3094855109658912213,
+            5802897354862067244,
+            11677019699073781796,
+            1505592401347711080,
+            1505729768134575418,
+        ])),
+    );
+
+    const PSI_COEFF_Y: Fp2 = Fp2::new(
+        Fp::from_RAW_limbs(<Fp as PrimeField>::BigInteger::from_limbs([
+            4480897313486445265,
+            4797496051193971075,
+            4046559893315008306,
+            10569151167044009496,
+            2123814803385151673,
+            852749317591686856,
+        ])),
+        Fp::from_RAW_limbs(<Fp as PrimeField>::BigInteger::from_limbs([
+            8921533702591418330,
+            15859389534032789116,
+            3389114680249073393,
+            15116930867080254631,
+            3288288975085550621,
+            1021049300055853010,
+        ])),
+    );
+
+    // -x for the curve parameter x = -0xd201000000010000
+    const MINUS_X: [u64; 1] = [15132376222941642752];
+
+    /// The endomorphism `psi(x, y) = (conj(x) * c_x, conj(y) * c_y)` in projective
+    /// coordinates, which acts as the scalar `x` on G2.
+    pub fn psi(point: &Projective<Fp2>) -> Projective<Fp2> {
+        Projective {
+            X: point.X.conjugate() * Self::PSI_COEFF_X,
+            Y: point.Y.conjugate() * Self::PSI_COEFF_Y,
+            Z: point.Z.conjugate(),
+        }
+    }
+
+    /// The fixed generator of G2, the point returned by `generator` without an rng.
+    pub fn generator() -> Affine<Fp2> {
+        let x = Fp2::new(
+            Fp::from_int(&Self::X_C0.into()),
+            Fp::from_int(&Self::X_C1.into()),
+        );
+        let y = Fp2::new(
+            Fp::from_int(&Self::Y_C0.into()),
+            Fp::from_int(&Self::Y_C1.into()),
+        );
+        Affine::new(x, y)
+    }
+}
+
+impl ShortWeierstrass for G2Parameters {
+    type Field = Fp2;
+
+    const A: Self::Field = Fp2::new(
+        Fp::from_RAW_limbs(<Fp as PrimeField>::BigInteger::from_limbs([0; 6])),
+        Fp::from_RAW_limbs(<Fp as PrimeField>::BigInteger::from_limbs([0; 6])),
+    );
+
+    // The element b = 4 + 4u in Montgomery form
+    const B: Self::Field = Fp2::new(
+        Fp::from_RAW_limbs(<Fp as PrimeField>::BigInteger::from_limbs([
+            12260768510540316659,
+            6038201419376623626,
+            5156596810353639551,
+            12813724723179037911,
+            10288881524157229871,
+            708830206584151678,
+        ])),
+        Fp::from_RAW_limbs(<Fp as PrimeField>::BigInteger::from_limbs([
+            12260768510540316659,
+            6038201419376623626,
+            5156596810353639551,
+            12813724723179037911,
+            10288881524157229871,
+            708830206584151678,
+        ])),
+    );
+
+    const A_KIND: CoefficientKind = CoefficientKind::Zero;
+}
+
+impl PrimeSubGroupConfig for ShortWeierstrassCompleteOperations<G2Parameters> {
+    type ScalarField = Fr;
+
+    const NAME: &'static str = "BLS12-381 G2";
+
+    type Cofactor = [u64; 8];
+
+    // 0x5d543a95414e7f1091d50792876a202cd91de4547085abaa68a205b2e5a7ddfa628f1cb4d9e82ef21537e293a6691ae1616ec6e786f0c70cf1c38e31c7238e5
+    const COFACTOR: Self::Cofactor = [
+        14923865813284960485,
+        1591719477533150320,
+        2401401741857165742,
+        11973085464847352559,
+        12000439733073903071,
+        14812865038524111546,
+        656769601966088706,
+        420316534768199665,
+    ];
+
+    fn generator<R: Rng>(rng: Option<&mut R>) -> Self::Affine {
+        let mut point = Self::Point::from(G2Parameters::generator());
+        if let Some(rng) = rng {
+            let scalar = Fr::rand(rng);
+            point = <Self as PrimeSubGroupConfig>::scalar_mul(&point, &scalar);
+        }
+        point.into_affine().unwrap()
+    }
+
+    /// Checks that `psi` acts as `x`, see section 4 of
+    /// "Co-factor clearing and subgroup membership testing on pairing-friendly curves"
+    /// by Scott, https://eprint.iacr.org/2021/1130.
+    fn is_in_subgroup(point: &Self::Affine) -> bool {
+        let point = Self::Point::from(*point);
+        let mut sum = ScalarMul::wnaf::<Self>(&point, &G2Parameters::MINUS_X);
+        Self::add_in_place(&mut sum, &G2Parameters::psi(&point));
+        sum == Self::identity()
+    }
+
+    /// Multiplication by the effective cofactor of RFC 9380, section 8.8.2, computed as
+    /// `[x^2 - x - 1]P + [x - 1]psi(P) + psi^2(2P)` following appendix G.3.
+    fn clear_cofactor(point: &Self::Point) -> Self::Point {
+        // multiplication by the curve parameter x, which is negative
+        let mul_by_x = |p: &Self::Point| {
+            let mut res = ScalarMul::wnaf::<Self>(p, &G2Parameters::MINUS_X);
+            Self::neg_in_place(&mut res);
+            res
+        };
+
+        let t1 = mul_by_x(point);
+        let mut t2 = G2Parameters::psi(point);
+        let mut t3 = *point;
+        Self::double_in_place(&mut t3);
+        t3 = G2Parameters::psi(&G2Parameters::psi(&t3));
+
+        let mut neg_t2 = t2;
+        Self::neg_in_place(&mut neg_t2);
+        Self::add_in_place(&mut t3, &neg_t2);
+        Self::add_in_place(&mut t2, &t1);
+        Self::add_in_place(&mut t3, &mul_by_x(&t2));
+
+        let mut neg = t1;
+        Self::add_in_place(&mut neg, point);
+        Self::neg_in_place(&mut neg);
+        Self::add_in_place(&mut t3, &neg);
+        t3
+    }
+
+    /// The 96 byte big endian encoding of `x.c1` followed by `x.c0`, with the flags of
+    /// the G1 encoding. The sign of `y` is taken from `y.c1`, or from `y.c0` if `y.c1` is zero.
+    fn compress(input: &Self::Affine) -> Vec<u8> {
+        let mut bytes = Bytes::into_iter_be(&input.x.c1.as_int())
+            .chain(Bytes::into_iter_be(&input.x.c0.as_int()))
+            .collect::<Vec<_>>();
+        bytes[0] |= 0x80;
+        let largest = if input.y.c1 == Fp::zero() {
+            is_lexicographically_largest(&input.y.c0)
+        } else {
+            is_lexicographically_largest(&input.y.c1)
+        };
+        if largest {
+            bytes[0] |= 0x20;
+        }
+        bytes
+    }
+
+    /// Uses the first 48 bytes of the digest and of its SHA-512 hash, reduced to 381 bits, as
+    /// the coefficients of `x` and the next bit to choose the sign of `y`, and clears the
+    /// cofactor.
+    fn batch_generators_from_label(label: &[u8], n: usize) -> Vec<Self::Affine> {
+        generators_from_label::<Self>(label, n, |digest| {
+            let c0 = fp_from_digest(digest)?;
+            let c1 = fp_from_digest(&Sha512::digest(digest))?;
+            let x = Fp2::new(c0, c1);
+            let mut y = (x.square() * x + G2Parameters::B).sqrt()?;
+            if digest[48] & 1 == 1 {
+                y = -y;
+            }
+            Some(Self::clear_cofactor(&Affine::new(x, y).into()))
+        })
+    }
+
+    /// The suite `BLS12381G2_XMD:SHA-256_SSWU_RO_` of RFC 9380.
+    fn hash_to_curve(msg: &[u8], dst: &[u8]) -> Option<Self::Point> {
+        Some(hash_to_curve::<Self>(msg, dst))
+    }
+
+    /// The suite `BLS12381G2_XMD:SHA-256_SSWU_NU_` of RFC 9380.
+    fn encode_to_curve(msg: &[u8], dst: &[u8]) -> Option<Self::Point> {
+        Some(encode_to_curve::<Self>(msg, dst))
+    }
+}
+
+impl SSWUMap for G2Parameters {
+    const ISO_A: Self::Field = G2_ISO_A;
+    const ISO_B: Self::Field = G2_ISO_B;
+    const Z: Self::Field = G2_Z;
+
+    /// The 3-isogeny from the curve `y^2 = x^3 + A'x + B'`.
+    fn isogeny_map(point: Affine<Fp2>) -> Option<Affine<Fp2>> {
+        isogeny_map(
+            &point,
+            &G2_ISO_X_NUM,
+            &G2_ISO_X_DEN,
+            &G2_ISO_Y_NUM,
+            &G2_ISO_Y_DEN,
+        )
+    }
+}
+
+impl HashToCurveConfig for ShortWeierstrassCompleteOperations<G2Parameters> {
+    const L: usize = 64;
+
+    fn expand_message(msg: &[u8], dst: &[u8], len_in_bytes: usize) -> Vec<u8> {
+        expand_message_xmd::<Sha256>(msg, dst, len_in_bytes)
+    }
+
+    fn map_to_curve(u: &Fp2) -> Self::Point {
+        map_to_curve_sswu::<G2Parameters>(u).map_or_else(Self::identity, Into::into)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::fp_from_hex;
+    use super::*;
+    use crate::models::check_generators;
+    use cryp_std::rand::rngs::ThreadRng;
+    use cryp_std::rand::thread_rng;
+    use cryp_std::string::ToString;
+
+    fn fp2_from_hex(c0: &str, c1: &str) -> Fp2 {
+        Fp2::new(fp_from_hex(c0), fp_from_hex(c1))
+    }
+
+    #[test]
+    fn test_group() {
+        let g = GroupG2::generator::<ThreadRng>(None);
+        let (x, y) = (g.point.x, g.point.y);
+
+        // check y^2 = x^3 + 4(u + 1)
+        assert_eq!(y.square(), x.square() * x + G2Parameters::B);
+        let four = Fp::from_int(&[4, 0, 0, 0, 0, 0].into());
+        assert_eq!(G2Parameters::B, Fp2::new(four, four));
+        assert!(GroupG2::is_valid(&g));
+
+        let point = GroupG2::from(g);
+        let identity = GroupG2::identity();
+        let order = Fr::MODULUS;
+
+        assert_eq!(point + point, point.double());
+        assert_eq!(identity + point, point);
+        assert_eq!(point + (-point), identity);
+        assert_eq!(point.mul_int(&order), identity);
+        assert_eq!(point.mul_int_non_ct(&order), identity);
+
+        let mut rng = thread_rng();
+        for _ in 0..3 {
+            let a = Fr::rand(&mut rng);
+            let b = Fr::rand(&mut rng);
+            assert_eq!(point * &a + point * &b, point * &(a + b));
+            assert_eq!(point.mul_int_non_ct(&a.as_int()), point * &a);
+        }
+    }
+
+    #[test]
+    fn test_vectors() {
+        let g = GroupG2::generator::<ThreadRng>(None);
+        assert_eq!(
+            g.to_string(),
+            "93e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8"
+        );
+
+        let double = GroupG2::from(g).double().as_public().unwrap();
+        assert_eq!(
+            double.point,
+            Affine::new(
+                fp2_from_hex(
+                    "1638533957d540a9d2370f17cc7ed5863bc0b995b8825e0ee1ea1e1e4d00dbae81f14b0bf3611b78c952aacab827a053",
+                    "0a4edef9c1ed7f729f520e47730a124fd70662a904ba1074728114d1031e1572c6c886f6b57ec72a6178288c47c33577",
+                ),
+                fp2_from_hex(
+                    "0468fb440d82b0630aeb8dca2b5256789a66da69bf91009cbfe6bd221e47aa8ae88dece9764bf3bd999d95d71e4c9899",
+                    "0f6d4552fa65dd2638b361543f887136a43253d9c66c411697003f7a13c308f5422e1aa0a59c8967acdefd8b6e36ccf3",
+                ),
+            )
+        );
+    }
+
+    #[test]
+    fn test_subgroup() {
+        // A point of the twist with x = 2 which is not in the subgroup of order r
+        let x = Fp2::new(Fp::one().double(), Fp::zero());
+        let y = fp2_from_hex(
+            "013a59858b6809fca4d9a3b6539246a70051a3c88899964a42bc9a69cf9acdd9dd387cfa9086b894185b9a46a402be73",
+            "02d27e0ec3356299a346a09ad7dc4ef68a483c3aed53f9139d2f929a3eecebf72082e5e58c6da24ee32e03040c406d4f",
+        );
+        assert_eq!(y.square(), x.square() * x + G2Parameters::B);
+
+        let p = AffineG2::new(Affine::new(x, y));
+        assert!(!GroupG2::is_valid(&p));
+
+        let cleared = GroupG2::from(p).mul_int(&GroupG2::cofactor());
+        assert!(GroupG2::is_valid(&cleared.as_public().unwrap()));
+
+        // psi acts as x on the subgroup
+        let g = GroupG2::from(GroupG2::generator::<ThreadRng>(None));
+        let psi = GroupG2::new(G2Parameters::psi(&g.as_public().unwrap().point.into()));
+        assert_eq!(psi, -g.mul_int(&G2Parameters::MINUS_X));
+
+        // the endomorphism check agrees with multiplication by the order
+        let mut rng = thread_rng();
+        for _ in 0..3 {
+            let q = GroupG2::from(GroupG2::generator(Some(&mut rng)));
+            for r in [q, q + p] {
+                let in_subgroup = r.mul_int(&Fr::MODULUS) == GroupG2::identity();
+                assert_eq!(GroupG2::is_valid(&r.as_public().unwrap()), in_subgroup);
+            }
+        }
+
+        // the effective cofactor of RFC 9380, section 8.8.2
+        let h_eff: [u64; 10] = [
+            16717924791090763089,
+            6451771550755190452,
+            16813287336095381155,
+            3368952460600638490,
+            7891079509683868336,
+            3646841576362204053,
+            11062809923385098209,
+            9863631852917151592,
+            6362576984766887208,
+            848540440590185907,
+        ];
+        let p = GroupG2::from(p);
+        assert!(!p.is_torsion_free());
+        assert!(!p.is_small_order());
+        assert!(g.is_torsion_free());
+        assert_eq!(p.mul_by_cofactor(), cleared);
+        assert_eq!(p.clear_cofactor(), p.mul_int(&h_eff));
+        assert!(p.clear_cofactor().is_torsion_free());
+
+        // points that are not on the curve are rejected
+        let mut off_curve = GroupG2::generator::<ThreadRng>(None);
+        off_curve.point.y += Fp2::one();
+        assert!(!GroupG2::is_valid(&off_curve));
+    }
+
+    #[test]
+    fn test_generators() {
+        check_generators::<GroupG2>(
+            "822bfdbce2e2bef63d2e185741ae07ea03c0913e47b2f97c13b486d3f6ab8a9c\
+             27ec1cd33418518ea5d19e2feb1f3e7f058609506f0430f50825d99bef35c952\
+             3d0796708f14e4b9c842bd9e6e47e9f5243836d47c7346e963afa1635eea3b14",
+        );
+    }
+
+    /// The test vectors of RFC 9380, appendix J.10, for the suites
+    /// BLS12381G2_XMD:SHA-256_SSWU_RO_ and BLS12381G2_XMD:SHA-256_SSWU_NU_
+    #[test]
+    fn test_hash_to_curve() {
+        let q128 = [b"q128_".as_slice(), &[b'q'; 128]].concat();
+        let a512 = [b"a512_".as_slice(), &[b'a'; 512]].concat();
+        let msgs: [&[u8]; 5] = [b"", b"abc", b"abcdef0123456789", &q128, &a512];
+
+        let dst = b"QUUX-V01-CS02-with-BLS12381G2_XMD:SHA-256_SSWU_RO_";
+        let expected = [
+            (
+                "0141ebfbdca40eb85b87142e130ab689c673cf60f1a3e98d69335266f30d9b8d4ac44c1038e9dcdd5393faf5c41fb78a",
+                "05cb8437535e20ecffaef7752baddf98034139c38452458baeefab379ba13dff5bf5dd71b72418717047f5b0f37da03d",
+                "0503921d7f6a12805e72940b963c0cf3471c7b2a524950ca195d11062ee75ec076daf2d4bc358c4b190c0c98064fdd92",
+                "12424ac32561493f3fe3c260708a12b7c620e7be00099a974e259ddc7d1f6395c3c811cdd19f1e8dbf3e9ecfdcbab8d6",
+            ),
+            (
+                "02c2d18e033b960562aae3cab37a27ce00d80ccd5ba4b7fe0e7a210245129dbec7780ccc7954725f4168aff2787776e6",
+                "139cddbccdc5e91b9623efd38c49f81a6f83f175e80b06fc374de9eb4b41dfe4ca3a230ed250fbe3a2acf73a41177fd8",
+                "1787327b68159716a37440985269cf584bcb1e621d3a7202be6ea05c4cfe244aeb197642555a0645fb87bf7466b2ba48",
+                "00aa65dae3c8d732d10ecd2c50f8a1baf3001578f71c694e03866e9f3d49ac1e1ce70dd94a733534f106d4cec0eddd16",
+            ),
+            (
+                "121982811d2491fde9ba7ed31ef9ca474f0e1501297f68c298e9f4c0028add35aea8bb83d53c08cfc007c1e005723cd0",
+                "190d119345b94fbd15497bcba94ecf7db2cbfd1e1fe7da034d26cbba169fb3968288b3fafb265f9ebd380512a71c3f2c",
+                "05571a0f8d3c08d094576981f4a3b8eda0a8e771fcdcc8ecceaf1356a6acf17574518acb506e435b639353c2e14827c8",
+                "0bb5e7572275c567462d91807de765611490205a941a5a6af3b1691bfe596c31225d3aabdf15faff860cb4ef17c7c3be",
+            ),
+            (
+                "19a84dd7248a1066f737cc34502ee5555bd3c19f2ecdb3c7d9e24dc65d4e25e50d83f0f77105e955d78f4762d33c17da",
+                "0934aba516a52d8ae479939a91998299c76d39cc0c035cd18813bec433f587e2d7a4fef038260eef0cef4d02aae3eb91",
+                "14f81cd421617428bc3b9fe25afbb751d934a00493524bc4e065635b0555084dd54679df1536101b2c979c0152d09192",
+                "09bcccfa036b4847c9950780733633f13619994394c23ff0b32fa6b795844f4a0673e20282d07bc69641cee04f5e5662",
+            ),
+            (
+                "01a6ba2f9a11fa5598b2d8ace0fbe0a0eacb65deceb476fbbcb64fd24557c2f4b18ecfc5663e54ae16a84f5ab7f62534",
+                "11fca2ff525572795a801eed17eb12785887c7b63fb77a42be46ce4a34131d71f7a73e95fee3f812aea3de78b4d01569",
+                "0b6798718c8aed24bc19cb27f866f1c9effcdbf92397ad6448b5c9db90d2b9da6cbabf48adc1adf59a1a28344e79d57e",
+                "03a47f8e6d1763ba0cad63d6114c0accbef65707825a511b251a660a9b3994249ae4e63fac38b23da0c398689ee2ab52",
+            ),
+        ];
+        for (msg, (x0, x1, y0, y1)) in msgs.iter().zip(expected) {
+            let point = GroupG2::hash_to_curve(msg, dst).unwrap();
+            assert_eq!(
+                point.as_public().unwrap().point,
+                Affine::new(fp2_from_hex(x0, x1), fp2_from_hex(y0, y1))
+            );
+        }
+
+        let dst = b"QUUX-V01-CS02-with-BLS12381G2_XMD:SHA-256_SSWU_NU_";
+        let expected = [
+            (
+                "00e7f4568a82b4b7dc1f14c6aaa055edf51502319c723c4dc2688c7fe5944c213f510328082396515734b6612c4e7bb7",
+                "126b855e9e69b1f691f816e48ac6977664d24d99f8724868a184186469ddfd4617367e94527d4b74fc86413483afb35b",
+                "0caead0fd7b6176c01436833c79d305c78be307da5f6af6c133c47311def6ff1e0babf57a0fb5539fce7ee12407b0a42",
+                "1498aadcf7ae2b345243e281ae076df6de84455d766ab6fcdaad71fab60abb2e8b980a440043cd305db09d283c895e3d",
+            ),
+            (
+                "108ed59fd9fae381abfd1d6bce2fd2fa220990f0f837fa30e0f27914ed6e1454db0d1ee957b219f61da6ff8be0d6441f",
+                "0296238ea82c6d4adb3c838ee3cb2346049c90b96d602d7bb1b469b905c9228be25c627bffee872def773d5b2a2eb57d",
+                "033f90f6057aadacae7963b0a0b379dd46750c1c94a6357c99b65f63b79e321ff50fe3053330911c56b6ceea08fee656",
+                "153606c417e59fb331b7ae6bce4fbf7c5190c33ce9402b5ebe2b70e44fca614f3f1382a3625ed5493843d0b0a652fc3f",
+            ),
+        ];
+        for (msg, (x0, x1, y0, y1)) in msgs.iter().zip(expected) {
+            let point = GroupG2::encode_to_curve(msg, dst).unwrap();
+            assert_eq!(
+                point.as_public().unwrap().point,
+                Affine::new(fp2_from_hex(x0, x1), fp2_from_hex(y0, y1))
+            );
+        }
+    }
+
+    #[test]
+    fn test_introspection() {
+        assert_eq!(GroupG2::name(), "BLS12-381 G2");
+        assert_eq!(GroupG2::order(), Fr::MODULUS);
+    }
+}
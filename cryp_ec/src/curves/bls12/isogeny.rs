@@ -0,0 +1,839 @@
+//! The constants of the simplified SWU maps of RFC 9380 for BLS12-381, computed on curves
+//! isogenous to `E` and `E'`.
+
+use super::fields::{fp, fp2, Fp, Fp2};
+
+// A' = 0x144698a3b8e9433d693a02c96d4982b0ea985383ee66a8d8e8981aefd881ac98936f8da0e0f97f5cf428082d584c1d
+pub(super) const G1_ISO_A: Fp = fp([
+    3415322872136444497,
+    9675504606121301699,
+    13284745414851768802,
+    2873609449387478652,
+    2897906769629812789,
+    1536947672689614213,
+]);
+
+// B' = 0x12e2908d11688030018b12e8753eee3b2016c1f0f24f4070a0b9c14fcef35ef55a23215a316ceaa5d1cc48e98e172be0
+pub(super) const G1_ISO_B: Fp = fp([
+    18129637713272545760,
+    11144507692959411567,
+    10108153527111632324,
+    9745270364868568433,
+    14587922135379007624,
+    469008097655535723,
+]);
+
+// Z = 11
+pub(super) const G1_Z: Fp = fp([
+    9830232086645309404,
+    1112389714365644829,
+    8603885298299447491,
+    11361495444721768256,
+    5788602283869803809,
+    543934104870762216,
+]);
+
+// The coefficients of the 11-isogeny of RFC 9380, appendix E.2, in order of increasing degree
+
+pub(super) const G1_ISO_X_NUM: [Fp; 12] = [
+    // 0x11a05f2b1e833340b809101dd99815856b303e88a2d7005ff2627b56cdb4e2c85610c2d5f2e62d6eaeac1662734649b7
+    fp([
+        5555391298090832668,
+        1871845530032595596,
+        4551034694774233518,
+        2584197799339864836,
+        15085749040064757844,
+        654075415717002996,
+    ]),
+    // 0x17294ed3e943ab2f0588bab22147a81c7c17e75b2f6a8417f565e33c70d1e86b4838f2a6f318c356e834eef1b3cb83bb
+    fp([
+        9910598932128054667,
+        4357765064159749802,
+        1555960221863322426,
+        9671461638228026285,
+        1275132148248838779,
+        507072521670460589,
+    ]),
+    // 0xd54005db97678ec1d1048c5d10a9a1bce032473295983e56878e501ec68e25c958c3e3d2a09729fe0179f9dac9edcb0
+    fp([
+        11908177372061066827,
+        18190436643933350086,
+        6603102998733829542,
+        6581045210674032871,
+        16099974426311393401,
+        541581077397919012,
+    ]),
+    // 0x1778e7166fcc6db74e0609d307e55412d7f5e4656a8dbf25f1b33289f1b330835336e25ce3107193c5b388641d9b6861
+    fp([
+        5282195870529824577,
+        12365729195083706401,
+        2807246122435955773,
+        332702220601507168,
+        7339422050895811209,
+        1050416448951884523,
+    ]),
+    // 0xe99726a3199f4436642b4b3e4118e5499db995a1257fb3f086eeb65982fac18985a286f301e77c451154ce9ac8895d9
+    fp([
+        10443415753526299973,
+        8852419397684277637,
+        1088333252544296036,
+        1174353327457337436,
+        1626144519293139599,
+        716651429285276662,
+    ]),
+    // 0x1630c3250d7313ff01d1201bf7a74ab5db3cb17dd952799b9ed3ab9097e68f90a0870d2dcae73d19cd13c1c66f652983
+    fp([
+        7916646322956281527,
+        11909818257232418749,
+        1455301921509471421,
+        3317627683558310107,
+        12693445337245173919,
+        1798273032850409769,
+    ]),
+    // 0xd6ed6553fe44d296a3726c38ae652bfb11586264f0f8ce19008e218f9c86b2a8da25128c1052ecaddd7f225a139ed84
+    fp([
+        2577731109215284733,
+        8810166123993386985,
+        3186592767751348067,
+        15050850291391518479,
+        18435652654155870871,
+        1330813445865859326,
+    ]),
+    // 0x17b81e7701abdbe2e8743884d1117e53356de5ab275b4db1a682c62ef0f2753339b7c8f8c8f475af9ccb5618e3f0c88e
+    fp([
+        8787912969482053798,
+        9653629252694769025,
+        1358451377919714320,
+        16331599695590198629,
+        13519934665722691825,
+        628078949001449512,
+    ]),
+    // 0x80d3cf1f9a78fc47b90b33563be990dc43b756ce79f5574a2c596c928c5d1de4fa295f296b74e956d71986a8497e317
+    fp([
+        16605411443261943819,
+        9536014432113026165,
+        8685402948537367476,
+        16291074259433785035,
+        407185289045737198,
+        713426768049972652,
+    ]),
+    // 0x169b1f8e1bcfa7c42e0c37515d138f22dd2ecb803a0c5c99676314baf4bb1b7fa3190b2edc0327797f241067be390c9e
+    fp([
+        1001421273809907975,
+        724433776290697394,
+        16309429154639760781,
+        10003715605277815375,
+        307249038158020985,
+        688008371043525493,
+    ]),
+    // 0x10321da079ce07e272d8ec09d2565b0dfa7dccdde6787f96d50af36003b14866f69b771f8c285decca67df3f1605fb7b
+    fp([
+        16622893420529658311,
+        18333652517857227637,
+        2139173376235292830,
+        16496634502105693419,
+        5355299366650241487,
+        382770009771704860,
+    ]),
+    // 0x6e08c248e260e70bd1e962381edee3d31d79d7e22c837bc23c0bf1bc24c6b68c24b1b80b64d391fa9c8ba2e8ba2d229
+    fp([
+        8276255265012938363,
+        9997870203437298645,
+        16819210142450232135,
+        5062450688048499179,
+        12776432501206859311,
+        1778476024187613533,
+    ]),
+];
+
+pub(super) const G1_ISO_X_DEN: [Fp; 11] = [
+    // 0x8ca8d548cff19ae18b2e62f4bd3fa6f01d5ef4ba35b48ba9c9588617fc8ac62b558d681be343df8993cf9fa40d21b1c
+    fp([
+        13358415881952098629,
+        12009257493157516192,
+        13928884382876484932,
+        12988314785833227070,
+        11244145530317148182,
+        100673949996487007,
+    ]),
+    // 0x12561a5deb559c4348b4711298e536367041e8ca0cf0800c0126c2588c48bf5713daa8846cb026e9e5c8276ec82b3bff
+    fp([
+        2533162896381624793,
+        10578896196504721258,
+        4263020647280931071,
+        1255899686249737875,
+        17097124965295857733,
+        590960935246623182,
+    ]),
+    // 0xb2962fe57a3225e8137e629bff2991f6f89416f5a718cd1fca64e00b11aceacd6a3d0967c94fedcfcc239ba5cb83e19
+    fp([
+        10990404485039254780,
+        5344458621503091696,
+        1718862119039451458,
+        11600049052019063549,
+        18389973225607751698,
+        1092616849767867362,
+    ]),
+    // 0x3425581a58ae2fec83aafef7c40eb545b08243f16b1655154cca8abc28d6fd04976d5243eecf5c4130de8938dc62cd8
+    fp([
+        16377845895484993601,
+        15314247056264135931,
+        14543008873173635408,
+        4875476272346940127,
+        2030129768648768484,
+        1297689274107773964,
+    ]),
+    // 0x13a8e162022914a80a6f1d5f43e7a07dffdfc759a12062bb8d6b44e833b306da9bd29ba81f35781d539d395b3532a21e
+    fp([
+        6376927397170316667,
+        1460555178565443615,
+        18156708192400235081,
+        14761117739963869762,
+        8361091377443400626,
+        1421233557303902229,
+    ]),
+    // 0xe7355f8e4e667b955390f7f0506c6e9395735e9ce9cad4d0a43bcef24b8982f7400d24bc4228f11c02df9a29f6304a5
+    fp([
+        18127417459170613536,
+        5353764292720778676,
+        858818813615405862,
+        3528937506143354306,
+        12604964186779349896,
+        489837025077541867,
+    ]),
+    // 0x772caacf16936190f3e0c63e0596721570f5799af53a1894e2e073062aede9cea73b3538f0de06cec2574496ee84a3a
+    fp([
+        15285065477075910543,
+        3650488990300576179,
+        7274499670465195193,
+        16100555180954076900,
+        7580582425312971905,
+        896074979407586822,
+    ]),
+    // 0x14a7ac2a9d64a8b230b3f5b074cf01996e7f63c21bca68a81996e1cdf9822c580fa5b9489d11e2d311f7d99bbdcc5a5e
+    fp([
+        7582945168915351799,
+        2506680954090651888,
+        10272835934257987876,
+        9924916350558121763,
+        13577194922650729507,
+        1698254565890367778,
+    ]),
+    // 0xa10ecf6ada54f825e920b3dafc7a3cce07f8d1d7161366b74100da67f39883503826692abba43704776ec3a79a1d641
+    fp([
+        2009730524583761661,
+        11053280693947850663,
+        14409256190409559425,
+        3658799329773368860,
+        13529638021208614900,
+        869243908766415668,
+    ]),
+    // 0x95fc13ab9e92ad4476d6e3eb3a56680f682b4ee96f7d03776df533978f31c1593174e4b4b7865002d6384d168ecdd0a
+    fp([
+        11058048790650732295,
+        7059501760293999296,
+        6596812464094265283,
+        14567744481299745071,
+        1591898617514919697,
+        1344004358835331304,
+    ]),
+    // 0x1
+    fp([
+        8505329371266088957,
+        17002214543764226050,
+        6865905132761471162,
+        8632934651105793861,
+        6631298214892334189,
+        1582556514881692819,
+    ]),
+];
+
+pub(super) const G1_ISO_Y_NUM: [Fp; 16] = [
+    // 0x90d97c81ba24ee0259d1f094980dcfa11ad138e48a869522b52af6c956543d3cd0c7aee9b3ba3c2be9845719707bb33
+    fp([
+        3122824077082063463,
+        2111517899915568999,
+        14844585557031220083,
+        14713720721132803039,
+        9041847780307969683,
+        950267513573868304,
+    ]),
+    // 0x134996a104ee5811d51036d776fb46831223e96c254f383d0f906343eb67ad34d6c56711962fa8bfe097e75a2e41c696
+    fp([
+        11079511902567680319,
+        18338468344530008184,
+        6769016392463638666,
+        1504264063027988936,
+        8098359051856762276,
+        760455062874047829,
+    ]),
+    // 0xcc786baa966e66f4a384c86a3b49942552e2d658a31ce2c344be4b91400da7d26d521628b00523b8dfe240c72de1f6
+    fp([
+        1430247552210236986,
+        3854575382974307965,
+        14917507996414511245,
+        207936139448560,
+        9498310774218301406,
+        1438631746617682181,
+    ]),
+    // 0x1f86376e8981c217898751ad8746757d42aa7b90eeb791c09e4a3ec03251cf9de405aba9ec61deca6355c77b0e5f4cb
+    fp([
+        6654065794071117243,
+        2928282753802966791,
+        4144383358731160429,
+        12673586709493869907,
+        12918170109018188791,
+        844088361957958231,
+    ]),
+    // 0x8cc03fdefe0ff135caf4fe2a21529c4195536fbe3ce50b879833fd221351adc2ee7f8dc099040a841b6daecf2e8fedb
+    fp([
+        6416330705244672319,
+        3552017270878949117,
+        7777490944331917312,
+        7917192495177481567,
+        7271851377118683537,
+        253926972271069325,
+    ]),
+    // 0x16603fca40634b6a2211e11db8f0a6a074a7d0d4afadb7bd76505c3d3ad5544e203f6326c95a807299b23ab13633a5f0
+    fp([
+        11903306495973637341,
+        11622313950541285762,
+        17991208474928993001,
+        12280964980743791783,
+        14941570282955772167,
+        143516344770893715,
+    ]),
+    // 0x4ab0b9bcfac1bbcb2c977d027796b3ce75bb8ca2be184cb5231413c4d634f3747a87ac2460f415ec961f8855fe9d6f2
+    fp([
+        7324386472845891920,
+        16310961984705608217,
+        14050364318273732029,
+        410622978843904432,
+        13407944087243235067,
+        570579643952782879,
+    ]),
+    // 0x987c8d5333ab86fde9926bd2ca6c674170a05bfe3bdd81ffd038da6c26c842642f64550fedfe935a15e4ca31870fb29
+    fp([
+        10655681039374273828,
+        3913226275392147601,
+        9613292388335178165,
+        11852815148890010639,
+        17652581670569921892,
+        780578093363976825,
+    ]),
+    // 0x9fc4018bd96684be88c9e221e4da1bb8f3abd16679dc26c1e8b6e6a1f20cabe69d65201c78607a360370e577bdba587
+    fp([
+        10454026283255684948,
+        15005802245309313587,
+        4420421943175638630,
+        18052347756729021570,
+        12181908985148691767,
+        1485233717472293779,
+    ]),
+    // 0xe1bba7a1186bdb5223abde7ada14a23c42a0ca7915af6fe06985e7ed1e4d43b9b3f7055dd4eba6f2bafaaebca731c30
+    fp([
+        5056344670784885274,
+        15896288289018563095,
+        11120951801157184493,
+        7250506164525313606,
+        9295677455526059106,
+        1757175036496698059,
+    ]),
+    // 0x19713e47937cd1be0dfd0b8f1d43fb93cd2fcbcb6caf493fd1183e416389e61031bf3a5cce3fbafce813711ad011c132
+    fp([
+        417067620545670182,
+        113740147118943311,
+        7666319924200602156,
+        1469963335415292317,
+        13482947512490784447,
+        1353298443678343909,
+    ]),
+    // 0x18b46a908f36f6deb918c143fed2edcc523559b8aaf0c2462e6bfe7f911f643249d9cdf41b44d606ce07c8a4d0074d8e
+    fp([
+        13069093794065563159,
+        18364685236451803588,
+        2235996605706292724,
+        1007629142299662669,
+        4077244143222018961,
+        162586537120788900,
+    ]),
+    // 0xb182cac101b9399d155096004f53f447aa7b12a3426b08ec02710e807b4633f06c851c1919211f20d4c04f00b971ef8
+    fp([
+        12976751790971550752,
+        10256454045927919861,
+        8968423978443605586,
+        91636529236982767,
+        9459527627289574163,
+        949550897353139410,
+    ]),
+    // 0x245a394ad1eca9b72fc00ae7be315dc757b3b080d4c158013e6632d3c40659cc6cf90ad1c232a6442d9d3f5db980133
+    fp([
+        10595118024452621845,
+        8010256778549625402,
+        10333144214150401956,
+        17682229685967587631,
+        8235697699445463546,
+        317883997785997129,
+    ]),
+    // 0x5c129645e44cf1102a159f748c4a3fc5e673d81d7e86568d9ab0f5d396a7ce46ba1049b6579afb7866b1e715475224b
+    fp([
+        16894283457285346118,
+        10513943172407809423,
+        4685513162956315481,
+        11558261883362075118,
+        574375951146893083,
+        1159440548124233311,
+    ]),
+    // 0x15e6be4e990f03ce4ea50b3b42df2eb5cb181d8f84965a3957add4fa95af01b2b665027efec01c7704b456be69c8b604
+    fp([
+        9739780494108151959,
+        17207219630538774058,
+        553911396609642498,
+        6085929320386029624,
+        14175410874026216616,
+        1183751611824804793,
+    ]),
+];
+
+pub(super) const G1_ISO_Y_DEN: [Fp; 16] = [
+    // 0x16112c4c3a9c98b252181140fad0eae9601a6de578980be6eec3232b5be72e7a07f3688ef60c206d01479253b03663c1
+    fp([
+        16963992846030154524,
+        1796759822929186144,
+        15995221960860457854,
+        8232142361908220707,
+        5977498266010213481,
+        759868220591477233,
+    ]),
+    // 0x1962d75c2381201e1a0cbd6c43c348b885c84ff731c4d59ca4a10356f453e01f78a4260763529e3532f6102c2e49a03d
+    fp([
+        7019489280640006651,
+        8025136855967848721,
+        17464762292772824538,
+        4490335113250743896,
+        7652702793653159798,
+        1129822927746498110,
+    ]),
+    // 0x58df3306640da276faaae7d6e8eb15778c4855551ae7f310c35a5dd279cd2eca6757cd636f96f891e2538b53dbf67f2
+    fp([
+        3164260796573156764,
+        2639884922337322818,
+        1251365706181388855,
+        13142429936036186189,
+        359878619957828340,
+        126848055205862465,
+    ]),
+    // 0x16b7d288798e5395f20d23bf89edb4d1d115c5dbddbcd30e123da489e726af41727364f2c28297ada8d26d98445f5416
+    fp([
+        17472832885692408710,
+        9911075278795900735,
+        2614390623136861791,
+        14474775734428698630,
+        6462878218464609418,
+        1225960780180864957,
+    ]),
+    // 0xbe0e079545f43e4b00cc912f8228ddcc6d19c9f0f69bbb0542eda0fc9dec916a20b15dc0fd2ededda39142311a5001d
+    fp([
+        3586995257703132870,
+        2143554115308730112,
+        15207899356205612465,
+        4372523065560113828,
+        12811868595146042778,
+        307251632623424763,
+    ]),
+    // 0x8d9e5297186db2d9fb266eaac783182b70152c65550d881c5ecd87b6f0f5a6449f38db9dfa9cce202c6477faaf9b7ac
+    fp([
+        14298637377310410728,
+        10963101290308221781,
+        8192510423058716701,
+        1175370967867267532,
+        1029599188863854120,
+        678981456155013844,
+    ]),
+    // 0x166007c08a99db2fc3ba8734ace9824b5eecfdfa8d0cf8ef5dd365bc400a0051d5fa9c01a58b1fb93d1a1399126a775c
+    fp([
+        11149806480082726900,
+        3664985661428410608,
+        18095361538178773836,
+        14174906593575241395,
+        15305104369759711886,
+        901234928011491053,
+    ]),
+    // 0x16a3ef08be3ea7ea03bcddfabba6ff6ee5a4375efa1f4fd7feb34fd206357132b920f5b00801dee460ee415a15812ed9
+    fp([
+        4727074327869776987,
+        15736954329525418288,
+        14642679026711520511,
+        11429849039208981702,
+        17333567062758618213,
+        951235897335772166,
+    ]),
+    // 0x1866c8ed336c61231a1be54fd1d74cc4f9fb0ce4c6af5920abc5750c4bf39b4852cfe2f7bb9248836b233d9d55535d4a
+    fp([
+        9130114290642375589,
+        14069725355798443159,
+        6621984191700563591,
+        270173975669947883,
+        6218390495944243859,
+        1077419361593130421,
+    ]),
+    // 0x167a55cda70a6e1cea820597d94a84903216f763e13d87bb5308592e7ea7d4fbc7385ea3d529b35e346ef48bb8913f55
+    fp([
+        9144875514986933294,
+        16561351410666797616,
+        8591333879886582656,
+        15059370240386191395,
+        7834396448114781869,
+        946553772269403391,
+    ]),
+    // 0x4d2f259eea405bd48f010a01ad2911d9c6dd039bb61a6290e591b36e636a5c871a5c29f4f83060400f8b49cba8f6aa8
+    fp([
+        17809450171377747225,
+        15896956440537434491,
+        8451524482089653422,
+        1694507265233574136,
+        18224201536921880842,
+        317503425606567070,
+    ]),
+    // 0xaccbb67481d033ff5852c1e48c50c477f94ff8aefce42d28c0f9a88cea7913516f968986f7ebbea9684b529e2561092
+    fp([
+        13940503876759740187,
+        8772047862193200131,
+        6080360161890657205,
+        7935486160089058373,
+        9407473295146243021,
+        1255078947940629503,
+    ]),
+    // 0xad6b9514c767fe3c3613144b45f1496543346d98adf02267d5ceef9a00d9b8693000763e3b90ac11e99b138573345cc
+    fp([
+        1160821217138360586,
+        13542760608074182996,
+        11595911004531652098,
+        18158686636947034451,
+        13330657138280564947,
+        1773960737279760188,
+    ]),
+    // 0x2660400eb2e4f3b628bdd0d53cd76f2bf565b94e72927c1cb748df27942480e420517bd8714cc80d1fadc1326ed06f7
+    fp([
+        9132548444917292754,
+        16464415422105000789,
+        6319313500251671073,
+        12727658548847517900,
+        10985275115076354035,
+        1431541893474124246,
+    ]),
+    // 0xe0fa1d816ddc03e6b24255e0d7819c171c40f65e273b853324efcd6356caa205ca2f570f13497804415473a1d634b8f
+    fp([
+        662485641082390837,
+        260809847827618849,
+        6177381409359357075,
+        18231947741742261351,
+        18128540110746580014,
+        1079107229429227022,
+    ]),
+    // 0x1
+    fp([
+        8505329371266088957,
+        17002214543764226050,
+        6865905132761471162,
+        8632934651105793861,
+        6631298214892334189,
+        1582556514881692819,
+    ]),
+];
+
+// A' = 240u
+pub(super) const G2_ISO_A: Fp2 = fp2(
+    [0, 0, 0, 0, 0, 0],
+    [
+        16517514583386313282,
+        74322656156451461,
+        16683759486841714365,
+        815493829203396097,
+        204518332920448171,
+        1306242806803223655,
+    ],
+);
+
+// B' = 1012(1 + u)
+pub(super) const G2_ISO_B: Fp2 = fp2(
+    [
+        2515823342057463218,
+        7982686274772798116,
+        7934098172177393262,
+        8484566552980779962,
+        4455086327883106868,
+        1323173589274087377,
+    ],
+    [
+        2515823342057463218,
+        7982686274772798116,
+        7934098172177393262,
+        8484566552980779962,
+        4455086327883106868,
+        1323173589274087377,
+    ],
+);
+
+// Z = -(2 + u)
+pub(super) const G2_Z: Fp2 = fp2(
+    [
+        9794203289623549276,
+        7309342082925068282,
+        1139538881605221074,
+        15659550692327388916,
+        16008355200866287827,
+        582484205531694093,
+    ],
+    [
+        4897101644811774638,
+        3654671041462534141,
+        569769440802610537,
+        17053147383018470266,
+        17227549637287919721,
+        291242102765847046,
+    ],
+);
+
+// The coefficients of the 3-isogeny of RFC 9380, appendix E.3, in order of increasing degree
+
+pub(super) const G2_ISO_X_NUM: [Fp2; 4] = [
+    // 0x5c759507e8e333ebb5b7a9a47d7ed8532c52d39fd3a042a88b58423c50ae15d5c2638e343d9c71c6238aaaaaaaa97d6 + 0x5c759507e8e333ebb5b7a9a47d7ed8532c52d39fd3a042a88b58423c50ae15d5c2638e343d9c71c6238aaaaaaaa97d6 * u
+    fp2(
+        [
+            5185457120960601698,
+            494647221959407934,
+            8971396042087821730,
+            324544954362548322,
+            14214792730224113654,
+            1405280679127738945,
+        ],
+        [
+            5185457120960601698,
+            494647221959407934,
+            8971396042087821730,
+            324544954362548322,
+            14214792730224113654,
+            1405280679127738945,
+        ],
+    ),
+    // 0x0 + 0x11560bf17baa99bc32126fced787c88f984f87adf7ae0c7f9a208c6b4f20a4181472aaa9cb8d555526a9ffffffffc71a * u
+    fp2(
+        [0, 0, 0, 0, 0, 0],
+        [
+            6910023028261548496,
+            9745789443900091043,
+            7668299866710145304,
+            2432656849393633605,
+            2897729527445498821,
+            776645607375592125,
+        ],
+    ),
+    // 0x11560bf17baa99bc32126fced787c88f984f87adf7ae0c7f9a208c6b4f20a4181472aaa9cb8d555526a9ffffffffc71e + 0x8ab05f8bdd54cde190937e76bc3e447cc27c3d6fbd7063fcd104635a790520c0a395554e5c6aaaa9354ffffffffe38d * u
+    fp2(
+        [
+            724047465092313539,
+            15783990863276714670,
+            12824896677063784855,
+            15246381572572671516,
+            13186611051602728692,
+            1485475813959743803,
+        ],
+        [
+            12678383550985550056,
+            4872894721950045521,
+            13057521970209848460,
+            10439700461551592610,
+            10672236800577525218,
+            388322803687796062,
+        ],
+    ),
+    // 0x171d6541fa38ccfaed6dea691f5fb614cb14b4e7f4e810aa22d6108f142b85757098e38d0f671c7188e2aaaaaaaa5ed1 + 0x0 * u
+    fp2(
+        [
+            4659755689450087917,
+            1804066951354704782,
+            15570919779568036803,
+            15592734958806855601,
+            7597208057374167129,
+            1841438384006890194,
+        ],
+        [0, 0, 0, 0, 0, 0],
+    ),
+];
+
+pub(super) const G2_ISO_X_DEN: [Fp2; 3] = [
+    // 0x0 + 0x1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaa63 * u
+    fp2(
+        [0, 0, 0, 0, 0, 0],
+        [
+            2250392438786206615,
+            17463829474098544446,
+            14571211649711714824,
+            4495761442775821336,
+            258811604141191305,
+            357646605018048850,
+        ],
+    ),
+    // 0xc + 0x1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaa9f * u
+    fp2(
+        [
+            4933130441833534766,
+            15904462746612662304,
+            8034115857496836953,
+            12755092135412849606,
+            7007796720291435703,
+            252692002104915169,
+        ],
+        [
+            8469300574244328829,
+            4752422838614097887,
+            17848302789776796362,
+            12930989898711414520,
+            16851051131888818207,
+            1621106615542624696,
+        ],
+    ),
+    // 0x1 + 0x0 * u
+    fp2(
+        [
+            8505329371266088957,
+            17002214543764226050,
+            6865905132761471162,
+            8632934651105793861,
+            6631298214892334189,
+            1582556514881692819,
+        ],
+        [0, 0, 0, 0, 0, 0],
+    ),
+];
+
+pub(super) const G2_ISO_Y_NUM: [Fp2; 4] = [
+    // 0x1530477c7ab4113b59a4c18b076d11930f7da5d4a07f649bf54439d87d27e500fc8c25ebf8c92f6812cfc71c71c6d706 + 0x1530477c7ab4113b59a4c18b076d11930f7da5d4a07f649bf54439d87d27e500fc8c25ebf8c92f6812cfc71c71c6d706 * u
+    fp2(
+        [
+            10869708750642247614,
+            13056187057366814946,
+            1750362034917495549,
+            6326189602300757217,
+            1140223926335695785,
+            632761649765668291,
+        ],
+        [
+            10869708750642247614,
+            13056187057366814946,
+            1750362034917495549,
+            6326189602300757217,
+            1140223926335695785,
+            632761649765668291,
+        ],
+    ),
+    // 0x0 + 0x5c759507e8e333ebb5b7a9a47d7ed8532c52d39fd3a042a88b58423c50ae15d5c2638e343d9c71c6238aaaaaaaa97be * u
+    fp2(
+        [0, 0, 0, 0, 0, 0],
+        [
+            13765940311003083782,
+            5579209876153186557,
+            11349908400803699438,
+            11707848830955952341,
+            199199289641242246,
+            899896674917908607,
+        ],
+    ),
+    // 0x11560bf17baa99bc32126fced787c88f984f87adf7ae0c7f9a208c6b4f20a4181472aaa9cb8d555526a9ffffffffc71c + 0x8ab05f8bdd54cde190937e76bc3e447cc27c3d6fbd7063fcd104635a790520c0a395554e5c6aaaa9354ffffffffe38f * u
+    fp2(
+        [
+            15562563812347550836,
+            2436447360975022760,
+            6528760985104924230,
+            5219850230775796305,
+            5336118400288762609,
+            194161401843898031,
+        ],
+        [
+            16286611277439864375,
+            18220438224251737430,
+            906913588459157469,
+            2019487729638916206,
+            75985378181939686,
+            1679637215803641835,
+        ],
+    ),
+    // 0x124c9ad43b6cf79bfbf7043de3811ad0761b0f37a1e26286b0e977c69aa274524e79097a56dc4bd9e1b371c71c718b10 + 0x0 * u
+    fp2(
+        [
+            11849179119594500956,
+            13906615243538674725,
+            14543197362847770509,
+            2041759640812427310,
+            2879701092679313252,
+            1259985822978576468,
+        ],
+        [0, 0, 0, 0, 0, 0],
+    ),
+];
+
+pub(super) const G2_ISO_Y_DEN: [Fp2; 4] = [
+    // 0x1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffa8fb + 0x1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffa8fb * u
+    fp2(
+        [
+            99923616639376095,
+            10339114964526300021,
+            6204619029868000785,
+            1288486622530663893,
+            14587509920085997152,
+            272081012460753233,
+        ],
+        [
+            99923616639376095,
+            10339114964526300021,
+            6204619029868000785,
+            1288486622530663893,
+            14587509920085997152,
+            272081012460753233,
+        ],
+    ),
+    // 0x0 + 0x1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffa9d3 * u
+    fp2(
+        [0, 0, 0, 0, 0, 0],
+        [
+            6751177316358619845,
+            15498000274876530106,
+            6820146801716041242,
+            13487284328327464010,
+            776434812423573915,
+            1072939815054146550,
+        ],
+    ),
+    // 0x12 + 0x1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaa99 * u
+    fp2(
+        [
+            7399695662750302149,
+            14633322083064217648,
+            12051173786245255430,
+            9909266166264498601,
+            1288323043582377747,
+            379038003157372754,
+        ],
+        [
+            6002735353327561446,
+            6023563502162542543,
+            13831244861028377885,
+            15776815867859765525,
+            4123780734888324547,
+            1494760614490167112,
+        ],
+    ),
+    // 0x1 + 0x0 * u
+    fp2(
+        [
+            8505329371266088957,
+            17002214543764226050,
+            6865905132761471162,
+            8632934651105793861,
+            6631298214892334189,
+            1582556514881692819,
+        ],
+        [0, 0, 0, 0, 0, 0],
+    ),
+];
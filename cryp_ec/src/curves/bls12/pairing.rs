@@ -0,0 +1,588 @@
+//! The optimal ate pairing on BLS12-381.
+//!
+//! The Miller loop runs over the curve parameter `x = -0xd201000000010000` with the point of
+//! `G2` in homogeneous projective coordinates on the twist, following "Faster Explicit Formulas
+//! for Computing Pairings over Ordinary Curves" by Aranha, Karabina, Longa, Gebotys and López.
+//! The hard part of the final exponentiation follows "Efficient Final Exponentiation via
+//! Cyclotomic Structure for Pairings over Families of Elliptic Curves" by Hayashida, Hayasaka
+//! and Teruya, which raises to `3 (p^12 - 1) / r` instead of `(p^12 - 1) / r`. This is the
+//! convention of the common implementations of BLS12-381.
+
+use super::fields::{Fp, Fp12, Fp12Parameters, Fp2, Fp6, Fr};
+use super::g1::{AffineG1, GroupG1};
+use super::g2::{AffineG2, G2Parameters, GroupG2};
+use crate::weierstrass::*;
+use core::borrow::Borrow;
+use cryp_alg::ff::*;
+use cryp_alg::Pairing;
+use cryp_std::{
+    fmt::{Debug, Display},
+    hash::Hash,
+    iter,
+    ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+    rand::Rng,
+    vec::Vec,
+};
+
+/// The absolute value of the curve parameter `x`, which is negative.
+const X: u64 = 0xd201000000010000;
+
+/// The optimal ate pairing `e: G1 x G2 -> GT` of BLS12-381.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Bls12381;
+
+/// The target group of the pairing, the subgroup of order `r` of the multiplicative group of Fp12.
+///
+/// The group operation is multiplication in Fp12, so that in the additive notation of the
+/// `Group` trait "addition" is field multiplication and "scalar multiplication" is exponentiation.
+#[derive(Debug)]
+pub struct GroupGT {
+    element: Fp12,
+}
+
+impl GroupGT {
+    /// Wraps a field element without checking that it lies in the subgroup.
+    pub fn new(element: Fp12) -> Self {
+        Self { element }
+    }
+
+    /// The underlying field element.
+    pub fn element(&self) -> Fp12 {
+        self.element
+    }
+
+    /// Raises an element of the cyclotomic subgroup to the power `x`.
+    fn exp_by_x(element: &Fp12) -> Fp12 {
+        let mut result = Fp12::one();
+        for bit in Bits::into_iter_be(&[X]).skip_while(|b| !b) {
+            result.square_in_place();
+            if bit {
+                result *= element;
+            }
+        }
+        // x is negative, and inversion in the cyclotomic subgroup is conjugation
+        result.conjugate()
+    }
+}
+
+// -----------------------------------------
+
+// The Miller loop
+
+// -----------------------------------------
+
+/// The coefficients `(c0, c1, c4)` of a line evaluated at a point of G1, standing for the
+/// sparse element `(c0 + c1 * v) + c4 * v * w` of Fp12.
+type LineCoeffs = (Fp2, Fp2, Fp2);
+
+/// Doubles `r` and returns the tangent line at `r`.
+fn doubling_step(r: &mut Projective<Fp2>, two_inv: &Fp) -> LineCoeffs {
+    let a = (r.X * r.Y).mul_by_base(two_inv);
+    let b = r.Y.square();
+    let c = r.Z.square();
+    let e = G2Parameters::B * (c.double() + c);
+    let f = e.double() + e;
+    let g = (b + f).mul_by_base(two_inv);
+    let h = (r.Y + r.Z).square() - (b + c);
+    let i = e - b;
+    let j = r.X.square();
+    let e_square = e.square();
+
+    r.X = a * (b - f);
+    r.Y = g.square() - (e_square.double() + e_square);
+    r.Z = b * h;
+
+    (i, j.double() + j, -h)
+}
+
+/// Adds the affine point `q` to `r` and returns the line through `r` and `q`.
+fn addition_step(r: &mut Projective<Fp2>, q: &Affine<Fp2>) -> LineCoeffs {
+    let theta = r.Y - q.y * r.Z;
+    let lambda = r.X - q.x * r.Z;
+    let c = theta.square();
+    let d = lambda.square();
+    let e = lambda * d;
+    let f = r.Z * c;
+    let g = r.X * d;
+    let h = e + f - g.double();
+
+    r.X = lambda * h;
+    r.Y = theta * (g - h) - e * r.Y;
+    r.Z *= e;
+
+    let j = theta * q.x - lambda * q.y;
+    (j, -theta, lambda)
+}
+
+/// Multiplies `f` by the line with coefficients `coeffs` evaluated at `p`.
+fn ell(f: &mut Fp12, coeffs: &LineCoeffs, p: &Affine<Fp>) {
+    let (c0, c1, c4) = coeffs;
+    let c1 = c1.mul_by_base(&p.x);
+    let c4 = c4.mul_by_base(&p.y);
+
+    // (a0 + a1 * w) * (b0 + b1 * w) with b0 = c0 + c1 * v and b1 = c4 * v
+    let a0 = f.c0 * Fp6::new(*c0, c1, Fp2::zero());
+    let a1 = f.c1 * Fp6::new(Fp2::zero(), c4, Fp2::zero());
+    let c1 = (f.c0 + f.c1) * Fp6::new(*c0, c1 + c4, Fp2::zero()) - a0 - a1;
+    let c0 = a0 + Fp12Parameters::mul_base_by_nonresidue(&a1);
+    *f = Fp12::new(c0, c1);
+}
+
+impl Pairing for Bls12381 {
+    type ScalarField = Fr;
+    type G1 = GroupG1;
+    type G2 = GroupG2;
+    type GT = GroupGT;
+    type TargetField = Fp12;
+
+    fn multi_miller_loop<I, J>(a: I, b: J) -> Fp12
+    where
+        I: IntoIterator,
+        I::Item: Borrow<AffineG1>,
+        J: IntoIterator,
+        J::Item: Borrow<AffineG2>,
+    {
+        let mut pairs = a
+            .into_iter()
+            .zip(b)
+            .map(|(p, q)| {
+                let (p, q) = (p.borrow().point, q.borrow().point);
+                let r = Projective {
+                    X: q.x,
+                    Y: q.y,
+                    Z: Fp2::one(),
+                };
+                (p, q, r)
+            })
+            .collect::<Vec<_>>();
+
+        let two_inv = Fp::one().double().inverse().unwrap();
+        let mut f = Fp12::one();
+        for bit in Bits::into_iter_be(&[X]).skip_while(|b| !b).skip(1) {
+            f.square_in_place();
+            for (p, _, r) in pairs.iter_mut() {
+                let coeffs = doubling_step(r, &two_inv);
+                ell(&mut f, &coeffs, p);
+            }
+            if bit {
+                for (p, q, r) in pairs.iter_mut() {
+                    let coeffs = addition_step(r, q);
+                    ell(&mut f, &coeffs, p);
+                }
+            }
+        }
+        // x is negative
+        f.conjugate()
+    }
+
+    fn final_exponentiation(f: &Fp12) -> Option<GroupGT> {
+        // Easy part: f^((p^6 - 1)(p^2 + 1))
+        let f_inv = f.inverse()?;
+        let mut r = f.conjugate() * f_inv;
+        r = r.frobenius_map(2) * r;
+
+        // Hard part: r^(3 (p^4 - p^2 + 1) / r), where
+        // 3 (p^4 - p^2 + 1) / r = (x - 1)^2 (x + p) (x^2 + p^2 - 1) + 3
+        let y0 = r.square();
+        let mut y1 = GroupGT::exp_by_x(&r);
+        let mut y2 = r.conjugate();
+        y1 *= y2;
+        y2 = GroupGT::exp_by_x(&y1);
+        y1 = y1.conjugate();
+        y1 *= y2;
+        y2 = GroupGT::exp_by_x(&y1);
+        y1 = y1.frobenius_map(1);
+        y1 *= y2;
+        r *= y0;
+        let y0 = GroupGT::exp_by_x(&y1);
+        y2 = GroupGT::exp_by_x(&y0);
+        let y0 = y1.frobenius_map(2);
+        y1 = y1.conjugate();
+        y1 *= y2;
+        y1 *= y0;
+        r *= y1;
+
+        Some(GroupGT::new(r))
+    }
+}
+
+// -----------------------------------------
+
+// Group and PrimeGroup traits for GroupGT
+
+// -----------------------------------------
+
+impl Group for GroupGT {
+    fn identity() -> Self {
+        Self::new(Fp12::one())
+    }
+
+    fn double_in_place(&mut self) {
+        self.element.square_in_place();
+    }
+
+    fn mul_int(&self, scalar: &impl Integer) -> Self {
+        Self::new(self.element.exp(scalar))
+    }
+
+    /// Exponentiation is cheaper than the windowed method, which needs field inversions.
+    fn mul_int_non_ct(&self, scalar: &impl Integer) -> Self {
+        self.mul_int(scalar)
+    }
+}
+
+impl PrimeGroup for GroupGT {
+    type ScalarField = Fr;
+    type Public = GroupGT;
+    type Cofactor = [u64; 20];
+
+    fn name() -> &'static str {
+        "BLS12-381 GT"
+    }
+
+    /// The index `(p^4 - p^2 + 1) / r` of the group in the cyclotomic subgroup of Fp12.
+    fn cofactor() -> Self::Cofactor {
+        [
+            16507596937867016825,
+            18057484706064682225,
+            10402445662613232488,
+            14346821523445031478,
+            9473120882997548232,
+            2436835176888788889,
+            3373392400589487540,
+            10474413905108998177,
+            7489908168881354597,
+            2555996213494600119,
+            8757522661579182556,
+            2339225625408862113,
+            9403175223376818523,
+            16548243521931561887,
+            17766550329644248970,
+            3396558972642661655,
+            10687154282069584538,
+            15262110412764179619,
+            13888319236475750814,
+            4336934453280001,
+        ]
+    }
+
+    /// The generator is the pairing of the generators of G1 and G2.
+    fn generator<R: Rng>(rng: Option<&mut R>) -> Self::Public {
+        let g1 = GroupG1::generator::<R>(None);
+        let g2 = GroupG2::generator::<R>(None);
+        let g = Bls12381::pairing(&g1, &g2);
+        match rng {
+            Some(rng) => g * &Fr::rand(rng),
+            None => g,
+        }
+    }
+
+    /// Checks that the element is non-zero and has order dividing `r`.
+    fn is_valid(input: &Self::Public) -> bool {
+        input.element != Fp12::zero() && input.element.exp(&Fr::MODULUS) == Fp12::one()
+    }
+
+    fn as_public(&self) -> Option<Self::Public> {
+        Some(*self)
+    }
+
+    /// Pairs generators of G1 derived from a random label with the generator of G2.
+    fn batch_generators<R: Rng>(n: usize, rng: &mut R) -> Vec<Self::Public> {
+        let g2 = GroupG2::generator::<R>(None);
+        GroupG1::batch_generators(n, rng)
+            .iter()
+            .map(|g1| Bls12381::pairing(g1, &g2))
+            .collect()
+    }
+
+    /// Pairs the generators of G1 derived from the label with the generator of G2, so that
+    /// their discrete logarithms are as unknown as those of the G1 generators.
+    fn batch_generators_from_label(label: &[u8], n: usize) -> Vec<Self::Public> {
+        let g2 = AffineG2::new(G2Parameters::generator());
+        GroupG1::batch_generators_from_label(label, n)
+            .iter()
+            .map(|g1| Bls12381::pairing(g1, &g2))
+            .collect()
+    }
+
+    fn msm<I, J>(bases: I, scalars: J) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Self::Public>,
+        J: IntoIterator,
+        J::Item: Borrow<Fr>,
+    {
+        bases
+            .into_iter()
+            .zip(scalars)
+            .map(|(b, s)| *b.borrow() * s.borrow())
+            .sum()
+    }
+}
+
+// -----------------------------------------
+
+// Basic traits (Clone, Copy, Hash, Display) for GroupGT
+
+//------------------------------------------
+
+impl Clone for GroupGT {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl Copy for GroupGT {}
+
+impl PartialEq for GroupGT {
+    fn eq(&self, other: &Self) -> bool {
+        self.element == other.element
+    }
+}
+
+impl Eq for GroupGT {}
+
+impl Hash for GroupGT {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.element.hash(state);
+    }
+}
+
+/// The twelve coefficients over Fp in big endian order, starting with `c0.c0.c0`.
+impl Display for GroupGT {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for c in [self.element.c0, self.element.c1] {
+            for d in [c.c0, c.c1, c.c2] {
+                for e in [d.c0, d.c1] {
+                    Bytes::fmt_hex(Bytes::into_iter_be(&e.as_int()), f)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+// -----------------------------------------
+
+// Implementing the operations as ops traits
+
+// -----------------------------------------
+
+impl AddAssign<&GroupGT> for GroupGT {
+    #[allow(clippy::suspicious_op_assign_impl)]
+    fn add_assign(&mut self, other: &GroupGT) {
+        self.element *= other.element;
+    }
+}
+
+impl AddAssign for GroupGT {
+    fn add_assign(&mut self, other: GroupGT) {
+        *self += &other;
+    }
+}
+
+impl Add<&GroupGT> for GroupGT {
+    type Output = GroupGT;
+
+    fn add(mut self, other: &GroupGT) -> GroupGT {
+        self += other;
+        self
+    }
+}
+
+impl Add for GroupGT {
+    type Output = GroupGT;
+
+    fn add(mut self, other: GroupGT) -> GroupGT {
+        self += other;
+        self
+    }
+}
+
+impl Neg for GroupGT {
+    type Output = GroupGT;
+
+    fn neg(self) -> GroupGT {
+        // elements of the group are in the cyclotomic subgroup, where the inverse is the conjugate
+        Self::new(self.element.conjugate())
+    }
+}
+
+impl SubAssign<&GroupGT> for GroupGT {
+    fn sub_assign(&mut self, other: &GroupGT) {
+        *self += -*other;
+    }
+}
+
+impl SubAssign for GroupGT {
+    fn sub_assign(&mut self, other: GroupGT) {
+        *self -= &other;
+    }
+}
+
+impl Sub<&GroupGT> for GroupGT {
+    type Output = GroupGT;
+
+    fn sub(mut self, other: &GroupGT) -> GroupGT {
+        self -= other;
+        self
+    }
+}
+
+impl Sub for GroupGT {
+    type Output = GroupGT;
+
+    fn sub(mut self, other: GroupGT) -> GroupGT {
+        self -= other;
+        self
+    }
+}
+
+impl iter::Sum for GroupGT {
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = Self>,
+    {
+        iter.fold(Self::identity(), |acc, x| acc + x)
+    }
+}
+
+impl<'a> iter::Sum<&'a GroupGT> for GroupGT {
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = &'a GroupGT>,
+    {
+        iter.fold(Self::identity(), |acc, x| acc + x)
+    }
+}
+
+impl Mul<&Fr> for GroupGT {
+    type Output = GroupGT;
+
+    fn mul(self, other: &Fr) -> GroupGT {
+        self.mul_int(&other.as_int())
+    }
+}
+
+impl MulAssign<&Fr> for GroupGT {
+    fn mul_assign(&mut self, other: &Fr) {
+        *self = *self * other;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::fp_from_hex;
+    use super::*;
+    use cryp_std::rand::rngs::ThreadRng;
+    use cryp_std::rand::thread_rng;
+    use cryp_std::string::ToString;
+
+    #[test]
+    fn test_known_value() {
+        let g1 = GroupG1::generator::<ThreadRng>(None);
+        let g2 = GroupG2::generator::<ThreadRng>(None);
+        let e = Bls12381::pairing(&g1, &g2);
+
+        let coefficients = [
+            "1250ebd871fc0a92a7b2d83168d0d727272d441befa15c503dd8e90ce98db3e7b6d194f60839c508a84305aaca1789b6",
+            "089a1c5b46e5110b86750ec6a532348868a84045483c92b7af5af689452eafabf1a8943e50439f1d59882a98eaa0170f",
+            "1368bb445c7c2d209703f239689ce34c0378a68e72a6b3b216da0e22a5031b54ddff57309396b38c881c4c849ec23e87",
+            "193502b86edb8857c273fa075a50512937e0794e1e65a7617c90d8bd66065b1fffe51d7a579973b1315021ec3c19934f",
+            "01b2f522473d171391125ba84dc4007cfbf2f8da752f7c74185203fcca589ac719c34dffbbaad8431dad1c1fb597aaa5",
+            "018107154f25a764bd3c79937a45b84546da634b8f6be14a8061e55cceba478b23f7dacaa35c8ca78beae9624045b4b6",
+            "19f26337d205fb469cd6bd15c3d5a04dc88784fbb3d0b2dbdea54d43b2b73f2cbb12d58386a8703e0f948226e47ee89d",
+            "06fba23eb7c5af0d9f80940ca771b6ffd5857baaf222eb95a7d2809d61bfe02e1bfd1b68ff02f0b8102ae1c2d5d5ab1a",
+            "11b8b424cd48bf38fcef68083b0b0ec5c81a93b330ee1a677d0d15ff7b984e8978ef48881e32fac91b93b47333e2ba57",
+            "03350f55a7aefcd3c31b4fcb6ce5771cc6a0e9786ab5973320c806ad360829107ba810c5a09ffdd9be2291a0c25a99a2",
+            "04c581234d086a9902249b64728ffd21a189e87935a954051c7cdba7b3872629a4fafc05066245cb9108f0242d0fe3ef",
+            "0f41e58663bf08cf068672cbd01a7ec73baca4d72ca93544deff686bfd6df543d48eaa24afe47e1efde449383b676631",
+        ];
+        let c = coefficients.map(fp_from_hex);
+        let expected = Fp12::new(
+            Fp6::new(
+                Fp2::new(c[0], c[1]),
+                Fp2::new(c[2], c[3]),
+                Fp2::new(c[4], c[5]),
+            ),
+            Fp6::new(
+                Fp2::new(c[6], c[7]),
+                Fp2::new(c[8], c[9]),
+                Fp2::new(c[10], c[11]),
+            ),
+        );
+        assert_eq!(e.element(), expected);
+        assert_eq!(e.to_string(), coefficients.concat());
+
+        assert_eq!(GroupGT::generator::<ThreadRng>(None), e);
+        assert!(GroupGT::is_valid(&e));
+        assert_ne!(e, GroupGT::identity());
+    }
+
+    #[test]
+    fn test_bilinearity() {
+        let mut rng = thread_rng();
+        let g1 = GroupG1::generator::<ThreadRng>(None);
+        let g2 = GroupG2::generator::<ThreadRng>(None);
+        let e = Bls12381::pairing(&g1, &g2);
+
+        let a = Fr::rand(&mut rng);
+        let b = Fr::rand(&mut rng);
+        let p = (g1 * &a).as_public().unwrap();
+        let q = (g2 * &b).as_public().unwrap();
+
+        let e_ab = Bls12381::pairing(&p, &q);
+        assert_eq!(e_ab, e * &(a * b));
+        assert_eq!(Bls12381::pairing(&p, &g2), e * &a);
+        assert_eq!(Bls12381::pairing(&g1, &q), e * &b);
+
+        // e(-P, Q) = e(P, -Q) = -e(P, Q)
+        let minus_p = (-GroupG1::from(p)).as_public().unwrap();
+        let minus_q = (-GroupG2::from(q)).as_public().unwrap();
+        assert_eq!(Bls12381::pairing(&minus_p, &q), -e_ab);
+        assert_eq!(Bls12381::pairing(&p, &minus_q), -e_ab);
+        assert_eq!(
+            Bls12381::multi_pairing([p, minus_p], [q, q]),
+            GroupGT::identity()
+        );
+
+        // e(P, Q) + e(P, g2) = e(P, Q + g2)
+        let q_plus_g2 = (q + g2).as_public().unwrap();
+        assert_eq!(
+            Bls12381::multi_pairing([p, p], [q, g2]),
+            Bls12381::pairing(&p, &q_plus_g2)
+        );
+    }
+
+    #[test]
+    fn test_target_group() {
+        let mut rng = thread_rng();
+        let e = GroupGT::generator::<ThreadRng>(None);
+        let identity = GroupGT::identity();
+
+        assert_eq!(e.mul_int(&Fr::MODULUS), identity);
+        assert_eq!(e + (-e), identity);
+        assert_eq!(e - e, identity);
+        assert_eq!(e.double(), e + e);
+
+        let a = Fr::rand(&mut rng);
+        let b = Fr::rand(&mut rng);
+        assert_eq!(e * &a + e * &b, e * &(a + b));
+        assert_eq!(GroupGT::msm([e, e], [a, b]), e * &(a + b));
+
+        assert_eq!(Bls12381::final_exponentiation(&Fp12::zero()), None);
+        assert!(!GroupGT::is_valid(&GroupGT::new(Fp12::zero())));
+        assert!(!GroupGT::is_valid(&GroupGT::new(Fp12::one().double())));
+        assert_eq!(GroupGT::name(), "BLS12-381 GT");
+
+        let generators = GroupGT::batch_generators_from_label(b"test", 2);
+        assert!(GroupGT::is_valid(&generators[0]));
+        assert_ne!(generators[0], generators[1]);
+        assert_ne!(generators[0], identity);
+
+        let generators = GroupGT::batch_generators(2, &mut rng);
+        assert!(GroupGT::is_valid(&generators[0]));
+        assert_ne!(generators[0], generators[1]);
+        assert_ne!(generators[0], identity);
+    }
+}
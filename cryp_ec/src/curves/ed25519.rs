@@ -1,4 +1,10 @@
+use super::x25519::Curve25519Parameters;
 use crate::edwards::*;
+use crate::hash_to_curve::{
+    encode_to_curve, expand_message_xmd, hash_to_curve, map_to_curve_elligator2, HashToCurveConfig,
+    Sha512,
+};
+use crate::models::{field_from_bytes_be, generators_from_label};
 use cryp_alg::ff::*;
 use cryp_std::rand::Rng;
 use cryp_std::vec::Vec;
@@ -33,6 +39,102 @@ impl Ed25519Parameters {
     ];
 }
 
+/// The eight points of order dividing 8 on Ed25519, `EIGHT_TORSION[k] = [k]T` for the point `T`
+/// of order 8 with encoding `26e8958fc2b227b045c3f489f2ef98f0d5dfac05d3c63339b13802886d53fc85`.
+///
+/// Strict signature verification rejects public keys and commitments that are one of these
+/// points, which are exactly the points for which `GroupEd25519::is_small_order` holds.
+pub const EIGHT_TORSION: [Affine<Fp25519>; 8] = [
+    Affine::new(fe([0, 0, 0, 0]), fe([1, 0, 0, 0])),
+    Affine::new(
+        fe([
+            2404563456267792035,
+            11810342095734578631,
+            1642800998078592324,
+            6929428354689316311,
+        ]),
+        fe([
+            12693310623469398054,
+            17336870590538367813,
+            4121856693609750485,
+            431311394219112625,
+        ]),
+    ),
+    Affine::new(
+        fe([
+            4256434742258392893,
+            15041151913532857223,
+            15326593298702018648,
+            6087982137762259188,
+        ]),
+        fe([0, 0, 0, 0]),
+    ),
+    Affine::new(
+        fe([
+            2404563456267792035,
+            11810342095734578631,
+            1642800998078592324,
+            6929428354689316311,
+        ]),
+        fe([
+            5753433450240153543,
+            1109873483171183802,
+            14324887380099801130,
+            8792060642635663182,
+        ]),
+    ),
+    Affine::new(
+        fe([0, 0, 0, 0]),
+        fe([
+            18446744073709551596,
+            18446744073709551615,
+            18446744073709551615,
+            9223372036854775807,
+        ]),
+    ),
+    Affine::new(
+        fe([
+            16042180617441759562,
+            6636401977974972984,
+            16803943075630959291,
+            2293943682165459496,
+        ]),
+        fe([
+            5753433450240153543,
+            1109873483171183802,
+            14324887380099801130,
+            8792060642635663182,
+        ]),
+    ),
+    Affine::new(
+        fe([
+            14190309331451158704,
+            3405592160176694392,
+            3120150775007532967,
+            3135389899092516619,
+        ]),
+        fe([0, 0, 0, 0]),
+    ),
+    Affine::new(
+        fe([
+            16042180617441759562,
+            6636401977974972984,
+            16803943075630959291,
+            2293943682165459496,
+        ]),
+        fe([
+            12693310623469398054,
+            17336870590538367813,
+            4121856693609750485,
+            431311394219112625,
+        ]),
+    ),
+];
+
+const fn fe(limbs: [u64; 4]) -> Fp25519 {
+    Fp25519::from_RAW_limbs(<Fp25519 as PrimeField>::BigInteger::from_limbs(limbs))
+}
+
 impl MontParameters<4usize> for Fp25519Params {
     type Limb = u64;
 
@@ -124,7 +226,11 @@ impl MontParameters<4usize> for ScalarEd25519Parameters {
 impl PrimeSubGroupConfig for EdwardsAM1UnifiedOperations<Ed25519Parameters> {
     type ScalarField = ScalarEd25519;
 
-    const COFACTOR: u32 = 8;
+    const NAME: &'static str = "Ed25519";
+
+    type Cofactor = [u64; 1];
+
+    const COFACTOR: Self::Cofactor = [8];
 
     fn generator<R: Rng>(rng: Option<&mut R>) -> Self::Affine {
         let x = Fp25519::from_int(&Ed25519Parameters::X.into());
@@ -139,20 +245,86 @@ impl PrimeSubGroupConfig for EdwardsAM1UnifiedOperations<Ed25519Parameters> {
         point.into_affine().unwrap()
     }
 
-    fn batch_generators<R: Rng>(n: usize, rng: &mut R) -> cryp_std::vec::Vec<Self::Affine> {
-        let mut generators = Vec::with_capacity(n + 1);
+    /// The 32-byte encoding of RFC 8032: the little endian encoding of `y` with the
+    /// least significant bit of `x` in the most significant bit.
+    fn compress(input: &Self::Affine) -> Vec<u8> {
+        let x = input.x.as_int();
+        let mut bytes = input
+            .y
+            .as_int()
+            .limbs
+            .iter()
+            .flat_map(|l| l.to_le_bytes())
+            .collect::<Vec<_>>();
+        bytes[31] |= ((x.limbs[0] & 1) as u8) << 7;
+        bytes
+    }
 
-        for _ in 0..n {
-            generators.push(<Self as PrimeSubGroupConfig>::generator(Some(rng)));
-        }
+    /// Decodes the first 32 bytes of the digest as an encoded point and multiplies it by
+    /// the cofactor.
+    fn batch_generators_from_label(label: &[u8], n: usize) -> Vec<Self::Affine> {
+        generators_from_label::<Self>(label, n, |digest| {
+            let point = <Self as CurveEncoding>::decompress(&digest[..32])?;
+            Some(Self::mul_by_cofactor(&point.into()))
+        })
+    }
+
+    /// The suite `edwards25519_XMD:SHA-512_ELL2_RO_` of RFC 9380.
+    fn hash_to_curve(msg: &[u8], dst: &[u8]) -> Option<Self::Point> {
+        Some(hash_to_curve::<Self>(msg, dst))
+    }
+
+    /// The suite `edwards25519_XMD:SHA-512_ELL2_NU_` of RFC 9380.
+    fn encode_to_curve(msg: &[u8], dst: &[u8]) -> Option<Self::Point> {
+        Some(encode_to_curve::<Self>(msg, dst))
+    }
+}
+
+impl HashToCurveConfig for EdwardsAM1UnifiedOperations<Ed25519Parameters> {
+    const L: usize = 48;
+
+    fn expand_message(msg: &[u8], dst: &[u8], len_in_bytes: usize) -> Vec<u8> {
+        expand_message_xmd::<Sha512>(msg, dst, len_in_bytes)
+    }
+
+    /// Elligator 2 on Curve25519 followed by the map to the Edwards curve.
+    fn map_to_curve(u: &Fp25519) -> Self::Point {
+        map_to_curve_elligator2::<Curve25519Parameters>(u).into()
+    }
+}
+
+impl CurveEncoding for EdwardsAM1UnifiedOperations<Ed25519Parameters> {
+    /// Decodes the 32-byte encoding of RFC 8032, section 5.1.3.
+    ///
+    /// The encoding of `y` must be canonical and the sign bit must be clear if `x = 0`.
+    fn decompress(bytes: &[u8]) -> Option<Self::Affine> {
+        let bytes: &[u8; 32] = bytes.try_into().ok()?;
+        let sign = bytes[31] >> 7;
+
+        let mut y_bytes = *bytes;
+        y_bytes[31] &= 0x7f;
+        y_bytes.reverse();
+        let y = field_from_bytes_be::<Fp25519>(&y_bytes)?;
+
+        // -x^2 + y^2 = 1 + d x^2 y^2, so x^2 = (y^2 - 1) / (d y^2 + 1)
+        let one = Fp25519::one();
+        let y2 = y.square();
+        let mut x = ((y2 - one) * (Ed25519Parameters::D * y2 + one).inverse()?).sqrt()?;
 
-        generators
+        if x == Fp25519::zero() && sign == 1 {
+            return None;
+        }
+        if (x.as_int().limbs[0] & 1) as u8 != sign {
+            x = -x;
+        }
+        Some(Affine::new(x, y))
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::models::check_generators;
     use cryp_std::rand::thread_rng;
     use cryp_std::rand::UniformRand;
 
@@ -212,7 +384,7 @@ mod tests {
         let x = ScalarEd25519::from_int(&[3293829, 232323, 4473653, 2323].into());
 
         let mut rng = thread_rng();
-        let y = ScalarEd25519::from_int(
+        let _y = ScalarEd25519::from_int(
             &[
                 u64::rand(&mut rng),
                 u64::rand(&mut rng),
@@ -268,7 +440,7 @@ mod tests {
         assert_eq!(identity + point.double(), point.double());
         assert_eq!(point.mul_int(&[2u32]), point.double());
         assert_eq!(x.square(), x.exp(&[2u32]));
-        assert_eq!(x * &(one + one), x.double());
+        assert_eq!(x * (one + one), x.double());
 
         let order: [u64; 4] = [
             6346243789798364141,
@@ -285,6 +457,12 @@ mod tests {
         ];
 
         assert_eq!(point.mul_int(&order), identity);
+        assert_eq!(point.mul_int_non_ct(&order), identity);
+        assert_eq!(point.mul_int_non_ct(&order_minus_one), -point);
+        for _ in 0..10 {
+            let scalar = ScalarEd25519::rand(&mut rng).as_int();
+            assert_eq!(point.mul_int_non_ct(&scalar), point.mul_int(&scalar));
+        }
         assert_eq!(point.mul_int(&order_minus_one), -point);
         assert_eq!(point - point, identity);
 
@@ -292,4 +470,310 @@ mod tests {
         assert_ne!(point * &mod_minus_one, point);
         assert_eq!(point * &mod_minus_one, -point);
     }
+
+    #[test]
+    fn test_doubling() {
+        use crate::models::CurveOperations;
+        type Operations = EdwardsAM1UnifiedOperations<Ed25519Parameters>;
+
+        let mut rng = thread_rng();
+        let g = GroupEd25519::generator(Some(&mut rng)).point;
+        let point = ExtendedPoint::from(g);
+
+        // the dedicated doubling agrees with the unified addition and keeps T = XY/Z
+        let mut double = point;
+        Operations::double_in_place(&mut double);
+        let mut sum = point;
+        Operations::add_in_place(&mut sum, &point);
+        assert_eq!(double, sum);
+        assert_eq!(double.T * double.Z, double.X * double.Y);
+
+        let mut identity = Operations::identity();
+        Operations::double_in_place(&mut identity);
+        assert_eq!(identity, Operations::identity());
+
+        for n in 0..6 {
+            let mut chain = point;
+            Operations::double_n_in_place(&mut chain, n);
+            let mut expected = point;
+            for _ in 0..n {
+                let copy = expected;
+                Operations::add_in_place(&mut expected, &copy);
+            }
+            assert_eq!(chain, expected);
+            assert_eq!(chain.T * chain.Z, chain.X * chain.Y);
+        }
+    }
+
+    #[test]
+    fn test_cached() {
+        use crate::models::CurveOperations;
+        type Operations = EdwardsAM1UnifiedOperations<Ed25519Parameters>;
+
+        let mut rng = thread_rng();
+        let p = ExtendedPoint::from(GroupEd25519::generator(Some(&mut rng)).point);
+        let mut q = ExtendedPoint::from(GroupEd25519::generator(Some(&mut rng)).point);
+        Operations::double_in_place(&mut q);
+
+        for rhs in [p, q, Operations::identity()] {
+            let mut sum = q;
+            Operations::add_in_place(&mut sum, &rhs);
+            let mut cached_sum = q;
+            Operations::add_cached_in_place(&mut cached_sum, &Operations::to_cached(&rhs));
+            assert_eq!(sum, cached_sum);
+            assert_eq!(cached_sum.T * cached_sum.Z, cached_sum.X * cached_sum.Y);
+        }
+    }
+
+    #[test]
+    fn test_msm() {
+        let mut rng = thread_rng();
+        for n in [0, 1, 5, 40] {
+            let bases = GroupEd25519::batch_generators(n, &mut rng);
+            let scalars = (0..n)
+                .map(|_| ScalarEd25519::rand(&mut rng))
+                .collect::<Vec<_>>();
+            let expected = bases
+                .iter()
+                .zip(scalars.iter())
+                .map(|(b, s)| *b * s)
+                .sum::<GroupEd25519>();
+            assert_eq!(GroupEd25519::msm_non_ct(&bases, &scalars), expected);
+            assert_eq!(GroupEd25519::msm(&bases, &scalars), expected);
+        }
+    }
+
+    /// Parses a big endian hexadecimal string of 64 characters.
+    fn from_hex(hex: &str) -> [u64; 4] {
+        let mut limbs = [0u64; 4];
+        for (i, limb) in limbs.iter_mut().enumerate() {
+            let start = 48 - 16 * i;
+            *limb = u64::from_str_radix(&hex[start..start + 16], 16).unwrap();
+        }
+        limbs
+    }
+
+    fn affine(x: &str, y: &str) -> Affine<Fp25519> {
+        Affine::new(
+            Fp25519::from_int(&from_hex(x).into()),
+            Fp25519::from_int(&from_hex(y).into()),
+        )
+    }
+
+    /// The test vectors of RFC 9380, appendix J.5, for the suites
+    /// edwards25519_XMD:SHA-512_ELL2_RO_ and edwards25519_XMD:SHA-512_ELL2_NU_
+    #[test]
+    fn test_hash_to_curve() {
+        let q128 = [b"q128_".as_slice(), &[b'q'; 128]].concat();
+        let a512 = [b"a512_".as_slice(), &[b'a'; 512]].concat();
+        let msgs: [&[u8]; 5] = [b"", b"abc", b"abcdef0123456789", &q128, &a512];
+
+        let dst = b"QUUX-V01-CS02-with-edwards25519_XMD:SHA-512_ELL2_RO_";
+        let expected = [
+            (
+                "3c3da6925a3c3c268448dcabb47ccde5439559d9599646a8260e47b1e4822fc6",
+                "09a6c8561a0b22bef63124c588ce4c62ea83a3c899763af26d795302e115dc21",
+            ),
+            (
+                "608040b42285cc0d72cbb3985c6b04c935370c7361f4b7fbdb1ae7f8c1a8ecad",
+                "1a8395b88338f22e435bbd301183e7f20a5f9de643f11882fb237f88268a5531",
+            ),
+            (
+                "6d7fabf47a2dc03fe7d47f7dddd21082c5fb8f86743cd020f3fb147d57161472",
+                "53060a3d140e7fbcda641ed3cf42c88a75411e648a1add71217f70ea8ec561a6",
+            ),
+            (
+                "5fb0b92acedd16f3bcb0ef83f5c7b7a9466b5f1e0d8d217421878ea3686f8524",
+                "2eca15e355fcfa39d2982f67ddb0eea138e2994f5956ed37b7f72eea5e89d2f7",
+            ),
+            (
+                "0efcfde5898a839b00997fbe40d2ebe950bc81181afbd5cd6b9618aa336c1e8c",
+                "6dc2fc04f266c5c27f236a80b14f92ccd051ef1ff027f26a07f8c0f327d8f995",
+            ),
+        ];
+        for (msg, (x, y)) in msgs.iter().zip(expected) {
+            let point = GroupEd25519::hash_to_curve(msg, dst).unwrap();
+            assert_eq!(point.as_public().unwrap().point, affine(x, y));
+        }
+
+        let dst = b"QUUX-V01-CS02-with-edwards25519_XMD:SHA-512_ELL2_NU_";
+        let expected = [
+            (
+                "1ff2b70ecf862799e11b7ae744e3489aa058ce805dd323a936375a84695e76da",
+                "222e314d04a4d5725e9f2aff9fb2a6b69ef375a1214eb19021ceab2d687f0f9b",
+            ),
+            (
+                "5f13cc69c891d86927eb37bd4afc6672360007c63f68a33ab423a3aa040fd2a8",
+                "67732d50f9a26f73111dd1ed5dba225614e538599db58ba30aaea1f5c827fa42",
+            ),
+        ];
+        for (msg, (x, y)) in msgs.iter().zip(expected) {
+            let point = GroupEd25519::encode_to_curve(msg, dst).unwrap();
+            assert_eq!(point.as_public().unwrap().point, affine(x, y));
+        }
+    }
+
+    #[test]
+    fn test_batch_as_public() {
+        let mut rng = thread_rng();
+        let g = GroupEd25519::from(GroupEd25519::generator(Some(&mut rng)));
+        let points = [g, g.double(), GroupEd25519::identity(), g.double() + g];
+        let expected = points
+            .iter()
+            .map(GroupEd25519::as_public)
+            .collect::<Vec<_>>();
+        assert_eq!(GroupEd25519::batch_as_public(&points), expected);
+    }
+
+    #[test]
+    fn test_generators() {
+        use cryp_std::string::ToString;
+
+        check_generators::<GroupEd25519>(
+            "6111851adb7d7dc99ce304124e81fab2977473610c1a9f28b9f7fb617296a026",
+        );
+        let generators = GroupEd25519::batch_generators_from_label(b"test", 3);
+        assert_eq!(
+            generators[1].to_string(),
+            "6fc501f7ad4cbba78e041695c167e691e97e4015d208fd6bd79a114c482cf510"
+        );
+        assert_eq!(
+            generators[2].to_string(),
+            "d4d79fb215743e8679050bc93146617e512b5c9ad947e62d6d4311b5f2bf5e70"
+        );
+    }
+
+    #[test]
+    fn test_introspection() {
+        use cryp_std::rand::rngs::ThreadRng;
+        use cryp_std::string::ToString;
+
+        assert_eq!(GroupEd25519::name(), "Ed25519");
+        assert_eq!(GroupEd25519::cofactor(), [8]);
+        assert_eq!(GroupEd25519::order(), ScalarEd25519::MODULUS);
+
+        // The encoding of the base point from RFC 8032
+        let g = GroupEd25519::generator::<ThreadRng>(None);
+        let expected = "5866666666666666666666666666666666666666666666666666666666666666";
+        assert_eq!(g.to_string(), expected);
+        assert_eq!(GroupEd25519::from(g).to_string(), expected);
+        assert_eq!(
+            (-GroupEd25519::from(g)).to_string(),
+            "58666666666666666666666666666666666666666666666666666666666666e6"
+        );
+        assert_eq!(
+            GroupEd25519::identity().to_string(),
+            "0100000000000000000000000000000000000000000000000000000000000000"
+        );
+    }
+
+    #[test]
+    fn test_encoding() {
+        use cryp_std::rand::rngs::ThreadRng;
+        use cryp_std::string::ToString;
+
+        fn from_hex(hex: &str) -> Vec<u8> {
+            (0..hex.len())
+                .step_by(2)
+                .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+                .collect()
+        }
+
+        let g = GroupEd25519::generator::<ThreadRng>(None);
+        let bytes = g.compress();
+        assert_eq!(bytes, from_hex(&g.to_string()));
+        assert_eq!(AffineEd25519::decompress(&bytes), Some(g));
+        assert_eq!(
+            GroupEd25519::decompress(&bytes),
+            Some(GroupEd25519::from(g))
+        );
+
+        // round trip of random points, their negatives and the identity
+        let mut rng = thread_rng();
+        let identity = GroupEd25519::identity().as_public().unwrap();
+        let mut points = GroupEd25519::batch_generators(20, &mut rng);
+        points.extend(
+            points
+                .clone()
+                .into_iter()
+                .map(|p| (-GroupEd25519::from(p)).as_public().unwrap()),
+        );
+        points.push(identity);
+        for point in points {
+            let bytes = point.compress();
+            assert_eq!(bytes.len(), 32);
+            assert_eq!(AffineEd25519::decompress(&bytes), Some(point));
+        }
+
+        // the point of order 2 is on the curve but not in the subgroup
+        let order_two = AffineEd25519::decompress(&from_hex(
+            "ecffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f",
+        ))
+        .unwrap();
+        assert_eq!(
+            order_two.point,
+            Affine::new(Fp25519::zero(), -Fp25519::one())
+        );
+        assert!(!GroupEd25519::is_valid(&order_two));
+
+        let mut off_curve = order_two;
+        off_curve.point.x = Fp25519::one();
+        assert!(!GroupEd25519::is_valid(&off_curve));
+
+        let invalid = [
+            // wrong lengths
+            "",
+            "58666666666666666666666666666666666666666666666666666666666666",
+            "586666666666666666666666666666666666666666666666666666666666666600",
+            // y = p and y = p + 1 are non-canonical
+            "edffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f",
+            "eeffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f",
+            // x = 0 with the sign bit set
+            "0100000000000000000000000000000000000000000000000000000000000080",
+            // y = 2 and y = 7 are not the y-coordinates of points on the curve
+            "0200000000000000000000000000000000000000000000000000000000000000",
+            "0700000000000000000000000000000000000000000000000000000000000080",
+        ];
+        for hex in invalid {
+            assert_eq!(AffineEd25519::decompress(&from_hex(hex)), None);
+        }
+    }
+
+    #[test]
+    fn test_torsion() {
+        use cryp_std::rand::rngs::ThreadRng;
+        use cryp_std::string::ToString;
+
+        let identity = GroupEd25519::identity();
+        let t = GroupEd25519::new(EIGHT_TORSION[1].into());
+        let g = GroupEd25519::from(GroupEd25519::generator::<ThreadRng>(None));
+
+        let mut multiple = identity;
+        for (k, point) in EIGHT_TORSION.iter().enumerate() {
+            let public = AffineEd25519::new(*point);
+            assert_eq!(GroupEd25519::from(public), multiple);
+            assert_eq!(AffineEd25519::decompress(&public.compress()), Some(public));
+
+            let point = GroupEd25519::from(public);
+            assert!(point.is_small_order());
+            assert_eq!(point.mul_by_cofactor(), identity);
+            assert_eq!(point.is_torsion_free(), k == 0);
+            assert_eq!(GroupEd25519::is_valid(&public), k == 0);
+
+            // adding a small order component is removed by clearing the cofactor
+            let mixed = g + point;
+            assert!(!mixed.is_small_order());
+            assert_eq!(mixed.is_torsion_free(), k == 0);
+            assert_eq!(mixed.clear_cofactor(), g.mul_int(&[8u64]));
+
+            multiple += t;
+        }
+        assert_eq!(multiple, identity);
+        assert_eq!(
+            AffineEd25519::new(EIGHT_TORSION[1]).to_string(),
+            "26e8958fc2b227b045c3f489f2ef98f0d5dfac05d3c63339b13802886d53fc85"
+        );
+        assert!(g.is_torsion_free());
+        assert!(!g.is_small_order());
+    }
 }
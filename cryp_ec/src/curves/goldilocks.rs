@@ -0,0 +1,330 @@
+//! The Edwards curve Ed448-Goldilocks and the prime order group Decaf448.
+//!
+//! The curve `x^2 + y^2 = 1 - 39081x^2y^2` is defined over the Goldilocks prime field of
+//! modulus `p = 2^448 - 2^224 - 1`, see "Ed448-Goldilocks, a new elliptic curve" by M. Hamburg
+//! and RFC 8032 section 5.2. The group of points has order `4 * l` for the 446-bit prime `l`.
+//!
+//! Decaf448 of RFC 9496 section 5 is the group of order `l` obtained as the quotient of the
+//! even subgroup of the curve by its 2-torsion, with a canonical 56 byte encoding.
+
+use crate::edwards::*;
+use crate::models::{field_from_bytes_be, generators_from_label};
+use cryp_alg::ff::*;
+use cryp_std::rand::Rng;
+use cryp_std::vec::Vec;
+
+mod decaf;
+
+pub use decaf::{Decaf448, DecafPoint, GroupDecaf448, PublicDecaf448};
+
+pub type Fp448 = F<GeneralReductionOperations<7, GoldilocksReduction<7, Fp448Params>>>;
+pub type ScalarEd448 = F<MontgomeryOperations<7, ScalarEd448Parameters>>;
+pub type GroupEd448 = GroupEC<EdwardsGeneralUnifiedOperations<Ed448Parameters>>;
+pub type AffineEd448 = PublicEC<EdwardsGeneralUnifiedOperations<Ed448Parameters>>;
+
+/// Parameters of the Edwards curve Ed448-Goldilocks, `x^2 + y^2 = 1 - 39081x^2y^2`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Ed448Parameters;
+
+/// Parameters for the prime field Fp448
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Fp448Params;
+
+impl Ed448Parameters {
+    // The base point of RFC 8032, section 5.2
+    // 224580040295924300187604334099896036246789641632564134246125461686950415467406032909029192869357953282578032075146446173674602635247710
+    const X: [u64; 7] = [
+        2749069528532828254,
+        4844607530355168142,
+        1346042987506197777,
+        16892407523843482724,
+        11390840890429740903,
+        2458144774905870042,
+        5699710800930606573,
+    ];
+
+    // 298819210078481492676017930443930673437544040154080242095928241372331506189835876003536878655418784733982303233503462500531545062832660
+    const Y: [u64; 7] = [
+        10955139529391012372,
+        18283791143216335021,
+        4239012173876836804,
+        9761723845082923735,
+        5470311636975982656,
+        9833082258461411170,
+        7583857750517922852,
+    ];
+}
+
+impl GoldilocksParameters<7usize> for Fp448Params {
+    // 2^448 - 2^224 - 1
+    const MODULUS: [u64; 7] = [
+        18446744073709551615,
+        18446744073709551615,
+        18446744073709551615,
+        18446744069414584319,
+        18446744073709551615,
+        18446744073709551615,
+        18446744073709551615,
+    ];
+}
+
+impl TwistedEdwardsGeneral for Ed448Parameters {
+    type Field = Fp448;
+
+    // The element a = 1 in the regular representation
+    const A: Self::Field = Fp448::from_RAW_limbs(<Fp448 as PrimeField>::BigInteger::from_limbs([
+        1, 0, 0, 0, 0, 0, 0,
+    ]));
+
+    // The element d = -39081 in the regular representation
+    const D: Self::Field = Fp448::from_RAW_limbs(<Fp448 as PrimeField>::BigInteger::from_limbs([
+        18446744073709512534,
+        18446744073709551615,
+        18446744073709551615,
+        18446744069414584319,
+        18446744073709551615,
+        18446744073709551615,
+        18446744073709551615,
+    ]));
+}
+
+// The scalar Field
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ScalarEd448Parameters;
+
+impl MontParameters<7usize> for ScalarEd448Parameters {
+    type Limb = u64;
+
+    // 2^446 - 13818066809895115352007386748515426880336692474882178609894547503885
+    const MODULUS: [Self::Limb; 7] = [
+        2556006723728458995,
+        2408513697996967765,
+        14145484589129676432,
+        18446744071508206569,
+        18446744073709551615,
+        18446744073709551615,
+        4611686018427387903,
+    ];
+
+    const R: [Self::Limb; 7] = [
+        8222717178795715636,
+        8812689281721680555,
+        17205037938319500735,
+        8805380184,
+        0,
+        0,
+        0,
+    ];
+
+    const R2: [Self::Limb; 7] = [
+        16380597172113742688,
+        8859473595851707865,
+        965703414319814745,
+        12544723377189083192,
+        1917620071967259716,
+        2329131455307870383,
+        3747743906366994217,
+    ];
+
+    const MP: Self::Limb = 269446386856070085;
+}
+
+impl PrimeSubGroupConfig for EdwardsGeneralUnifiedOperations<Ed448Parameters> {
+    type ScalarField = ScalarEd448;
+
+    const NAME: &'static str = "Ed448";
+
+    type Cofactor = [u64; 1];
+
+    const COFACTOR: Self::Cofactor = [4];
+
+    fn generator<R: Rng>(rng: Option<&mut R>) -> Self::Affine {
+        let x = Fp448::from_int(&Ed448Parameters::X.into());
+        let y = Fp448::from_int(&Ed448Parameters::Y.into());
+        let affine_point = Self::Affine::new(x, y);
+
+        let mut point = Self::Point::from(affine_point);
+        if let Some(rng) = rng {
+            let scalar = ScalarEd448::rand(rng);
+            point = <Self as PrimeSubGroupConfig>::scalar_mul(&point, &scalar);
+        }
+        point.into_affine().unwrap()
+    }
+
+    /// The 57 byte encoding of RFC 8032, section 5.2.2: `y` in little endian followed by
+    /// a byte holding the least significant bit of `x` as its most significant bit.
+    fn compress(input: &Self::Affine) -> Vec<u8> {
+        let mut bytes = to_bytes(&input.y).to_vec();
+        bytes.push(((input.x.as_int().limbs[0] & 1) as u8) << 7);
+        bytes
+    }
+
+    /// Decodes the first 56 bytes of the digest as `y` and the next bit as the sign of `x`,
+    /// and multiplies the point by the cofactor.
+    fn batch_generators_from_label(label: &[u8], n: usize) -> Vec<Self::Affine> {
+        generators_from_label::<Self>(label, n, |digest| {
+            let mut bytes = digest[..57].to_vec();
+            bytes[56] &= 0x80;
+            let point = <Self as CurveEncoding>::decompress(&bytes)?;
+            Some(Self::mul_by_cofactor(&point.into()))
+        })
+    }
+}
+
+impl CurveEncoding for EdwardsGeneralUnifiedOperations<Ed448Parameters> {
+    /// Decodes the 57 byte encoding of RFC 8032, section 5.2.3.
+    ///
+    /// The encoding of `y` must be canonical, the last byte can only hold the sign bit and
+    /// the sign bit must be clear if `x = 0`.
+    fn decompress(bytes: &[u8]) -> Option<Self::Affine> {
+        let (last, y_bytes) = <&[u8; 57]>::try_from(bytes).ok()?.split_last()?;
+        if last & 0x7f != 0 {
+            return None;
+        }
+        let sign = last >> 7;
+
+        let mut y_bytes = y_bytes.to_vec();
+        y_bytes.reverse();
+        let y = field_from_bytes_be::<Fp448>(&y_bytes)?;
+
+        // x^2 + y^2 = 1 + d x^2 y^2, so x^2 = (y^2 - 1) / (d y^2 - 1)
+        let one = Fp448::one();
+        let y2 = y.square();
+        let mut x = ((y2 - one) * (Ed448Parameters::D * y2 - one).inverse()?).sqrt()?;
+
+        if x == Fp448::zero() && sign == 1 {
+            return None;
+        }
+        if (x.as_int().limbs[0] & 1) as u8 != sign {
+            x = -x;
+        }
+        Some(Affine::new(x, y))
+    }
+}
+
+/// The 56 byte little endian encoding of a field element.
+fn to_bytes(x: &Fp448) -> [u8; 56] {
+    let mut bytes = [0u8; 56];
+    for (chunk, limb) in bytes.chunks_mut(8).zip(x.as_int().limbs.iter()) {
+        chunk.copy_from_slice(&limb.to_le_bytes());
+    }
+    bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::check_generators;
+    use cryp_std::rand::rngs::ThreadRng;
+    use cryp_std::rand::thread_rng;
+
+    #[test]
+    fn test_parameters() {
+        let one = Fp448::one();
+        let d = -Fp448::from_int(&[39081, 0, 0, 0, 0, 0, 0].into());
+        assert_eq!(Ed448Parameters::A, one);
+        assert_eq!(Ed448Parameters::D, d);
+
+        let mut rng = thread_rng();
+        let modulus_minus_one = -one;
+        for _ in 0..10 {
+            let x = Fp448::rand(&mut rng);
+            let y = Fp448::rand(&mut rng);
+            assert_eq!(x * (y + one), x * y + x);
+            assert_eq!(x * x.inverse().unwrap(), one);
+            assert_eq!(x.exp(&modulus_minus_one.as_int()), one);
+
+            let s = ScalarEd448::rand(&mut rng);
+            assert_eq!(s * s.inverse().unwrap(), ScalarEd448::one());
+        }
+    }
+
+    #[test]
+    fn test_group() {
+        let g = GroupEd448::generator::<ThreadRng>(None);
+        let (x, y) = (g.point.x, g.point.y);
+
+        // check x^2 + y^2 = 1 + d*x^2*y^2
+        assert_eq!(
+            x.square() + y.square(),
+            Fp448::one() + Ed448Parameters::D * x.square() * y.square()
+        );
+        assert!(GroupEd448::is_valid(&g));
+
+        let point = GroupEd448::from(g);
+        let identity = GroupEd448::identity();
+        let order = ScalarEd448::MODULUS;
+
+        assert_eq!(point + point, point.double());
+        assert_eq!(identity + point, point);
+        assert_eq!(point + (-point), identity);
+        assert_eq!(point.mul_int(&order), identity);
+        assert_eq!(point.mul_int_non_ct(&order), identity);
+
+        let mut rng = thread_rng();
+        for _ in 0..5 {
+            let a = ScalarEd448::rand(&mut rng);
+            let b = ScalarEd448::rand(&mut rng);
+            assert_eq!(point * &a + point * &b, point * &(a + b));
+            assert_eq!(point.mul_int_non_ct(&a.as_int()), point * &a);
+        }
+    }
+
+    /// The encoding of the base point of RFC 8032, section 5.2
+    #[test]
+    fn test_compress() {
+        use cryp_std::string::ToString;
+
+        let g = GroupEd448::from(GroupEd448::generator::<ThreadRng>(None));
+        assert_eq!(
+            GroupEd448::generator::<ThreadRng>(None).to_string(),
+            "14fa30f25b790898adc8d74e2c13bdfdc4397ce61cffd33ad7c2a0051e9c78874098a36c7373ea4b\
+             62c7c9563720768824bcb66e71463f6900"
+        );
+
+        let mut rng = thread_rng();
+        let mut points = GroupEd448::batch_generators(10, &mut rng);
+        points.push(GroupEd448::identity().as_public().unwrap());
+        for point in points {
+            let bytes = point.compress();
+            assert_eq!(bytes.len(), 57);
+            assert_eq!(AffineEd448::decompress(&bytes), Some(point));
+
+            let negative = (-GroupEd448::from(point)).as_public().unwrap();
+            assert_eq!(
+                AffineEd448::decompress(&negative.compress()),
+                Some(negative)
+            );
+
+            // only the sign bit can be set in the last byte
+            let mut invalid = bytes.clone();
+            invalid[56] |= 1;
+            assert_eq!(AffineEd448::decompress(&invalid), None);
+            assert_eq!(AffineEd448::decompress(&bytes[..56]), None);
+        }
+
+        // The points of small order are not in the subgroup
+        let order_two = GroupEd448::new(Affine::new(Fp448::zero(), -Fp448::one()).into());
+        assert_ne!(order_two, GroupEd448::identity());
+        assert_eq!(order_two.double(), GroupEd448::identity());
+        assert_ne!(
+            (g + order_two).mul_int(&ScalarEd448::MODULUS),
+            GroupEd448::identity()
+        );
+    }
+
+    #[test]
+    fn test_generators() {
+        check_generators::<GroupEd448>(
+            "c48ee4d567b823cf51d65d5197084fb85c2fb834f1c912f4b6713e2cdd30250a\
+             bed8f7cd15d85d23dbb865d221dfc63f8e53271427939bdd00",
+        );
+    }
+
+    #[test]
+    fn test_introspection() {
+        assert_eq!(GroupEd448::name(), "Ed448");
+        assert_eq!(GroupEd448::cofactor(), [4]);
+        assert_eq!(GroupEd448::order(), ScalarEd448::MODULUS);
+    }
+}
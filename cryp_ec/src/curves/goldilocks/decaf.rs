@@ -0,0 +1,460 @@
+use super::{to_bytes, Ed448Parameters, Fp448, ScalarEd448};
+use crate::edwards::*;
+use crate::models::scalar_mul::{ScalarMul, VariableBaseMSM};
+use crate::models::{generators_from_label, CurveOperations};
+use core::borrow::Borrow;
+use cryp_alg::ff::*;
+use cryp_std::{
+    fmt::{Display, Formatter, Result},
+    hash::{Hash, Hasher},
+    rand::Rng,
+    vec::Vec,
+};
+
+pub type GroupDecaf448 = GroupEC<Decaf448>;
+pub type PublicDecaf448 = PublicEC<Decaf448>;
+
+type Ed448Operations = EdwardsGeneralUnifiedOperations<Ed448Parameters>;
+
+/// The prime order group decaf448 of RFC 9496.
+///
+/// The group is the quotient of the even subgroup of Ed448-Goldilocks by its 2-torsion, so
+/// that an element is represented by either of the two Edwards points in its coset. The curve
+/// arithmetic is the one of `EdwardsGeneralUnifiedOperations<Ed448Parameters>`, only the
+/// equality and the encoding differ.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Decaf448;
+
+/// An element of decaf448, represented by an Edwards point in extended coordinates.
+#[derive(Clone, Copy, Debug)]
+pub struct DecafPoint(ExtendedPoint<Fp448>);
+
+// The constants of RFC 9496, section 5.1, in the regular representation.
+
+// 1 - d = 39082
+const ONE_MINUS_D: Fp448 = Fp448::from_RAW_limbs(<Fp448 as PrimeField>::BigInteger::from_limbs([
+    39082, 0, 0, 0, 0, 0, 0,
+]));
+
+// sqrt(-d) = 98944233647732219769177004876929019128417576295529901074099889598043702116001257856802131563896515373927712232092845883226922417596214
+const SQRT_MINUS_D: Fp448 = Fp448::from_RAW_limbs(<Fp448 as PrimeField>::BigInteger::from_limbs([
+    10827479303018981174,
+    6932020550799773266,
+    9483005167791955666,
+    7251595297301468346,
+    11669292520989840715,
+    4320796414073531040,
+    2511147101357143912,
+]));
+
+// 1 / sqrt(-d) = 315019913931389607337177038330951043522456072897266928557328499619017160722351061360252776265186336876723201881398623946864393857820716
+const INVSQRT_MINUS_D: Fp448 =
+    Fp448::from_RAW_limbs(<Fp448 as PrimeField>::BigInteger::from_limbs([
+        6030244441568602156,
+        17274440901873014003,
+        13722607576982469141,
+        15472775063553856026,
+        6525879739811047335,
+        10388646941344581639,
+        7995022191540486231,
+    ]));
+
+// (p - 3) / 4
+const P_MINUS_3_DIV_4: [u64; 7] = [
+    18446744073709551615,
+    18446744073709551615,
+    18446744073709551615,
+    18446744072635809791,
+    18446744073709551615,
+    18446744073709551615,
+    4611686018427387903,
+];
+
+/// A field element is negative if its least significant bit is set.
+fn is_negative(x: &Fp448) -> bool {
+    x.as_int().limbs[0] & 1 == 1
+}
+
+/// The non-negative one of `x` and `-x`.
+fn abs(x: &Fp448) -> Fp448 {
+    if is_negative(x) {
+        -*x
+    } else {
+        *x
+    }
+}
+
+/// Computes the non-negative square root of `u / v` if it exists, or else of
+/// `-u / v`, see RFC 9496 section 5.2. The flag is set in the first case.
+fn sqrt_ratio_m1(u: &Fp448, v: &Fp448) -> (bool, Fp448) {
+    let r = *u * (*u * v).exp(&P_MINUS_3_DIV_4);
+    let check = *v * r.square();
+    (check == *u, abs(&r))
+}
+
+/// Decodes 56 little endian bytes as a field element, reduced modulo p.
+fn from_bytes(bytes: &[u8; 56]) -> Fp448 {
+    let mut limbs = [0u64; 7];
+    for (limb, chunk) in limbs.iter_mut().zip(bytes.chunks(8)) {
+        *limb = u64::from_le_bytes(chunk.try_into().unwrap());
+    }
+    Fp448::from_int(&limbs.into())
+}
+
+impl DecafPoint {
+    /// Decodes a canonical 56 byte encoding, see RFC 9496 section 5.3.1.
+    ///
+    /// Returns `None` if the bytes are not the encoding of a group element.
+    pub fn decode(bytes: &[u8; 56]) -> Option<Self> {
+        let s = from_bytes(bytes);
+        if to_bytes(&s) != *bytes || is_negative(&s) {
+            return None;
+        }
+
+        let one = Fp448::one();
+        let ss = s.square();
+        let u1 = one + ss;
+        let u2 = u1.square() - (Ed448Parameters::D * ss).double().double();
+        let (was_square, invsqrt) = sqrt_ratio_m1(&one, &(u2 * u1.square()));
+
+        let u3 = abs(&(s.double() * invsqrt * u1 * SQRT_MINUS_D));
+        let x = u3 * invsqrt * u2 * INVSQRT_MINUS_D;
+        let y = (one - ss) * invsqrt * u1;
+        let t = x * y;
+
+        if !was_square {
+            return None;
+        }
+        Some(Self(ExtendedPoint {
+            X: x,
+            Y: y,
+            T: t,
+            Z: one,
+        }))
+    }
+
+    /// The canonical 56 byte encoding, see RFC 9496 section 5.3.2.
+    pub fn encode(&self) -> [u8; 56] {
+        let ExtendedPoint {
+            X: x0,
+            T: t0,
+            Z: z0,
+            ..
+        } = self.0;
+
+        let u1 = (x0 + t0) * (x0 - t0);
+        let (_, invsqrt) = sqrt_ratio_m1(&Fp448::one(), &(u1 * ONE_MINUS_D * x0.square()));
+
+        let ratio = abs(&(invsqrt * u1 * SQRT_MINUS_D));
+        let u2 = INVSQRT_MINUS_D * ratio * z0 - t0;
+        to_bytes(&abs(&(ONE_MINUS_D * invsqrt * x0 * u2)))
+    }
+}
+
+/// Two representatives are equal if they differ by a point of order dividing 2,
+/// see RFC 9496 section 5.3.3.
+impl PartialEq for DecafPoint {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.X * other.0.Y == self.0.Y * other.0.X
+    }
+}
+
+impl Eq for DecafPoint {}
+
+impl Hash for DecafPoint {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.encode().hash(state);
+    }
+}
+
+/// The encoding of the element in hexadecimal.
+impl Display for DecafPoint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        Bytes::fmt_hex(self.encode(), f)
+    }
+}
+
+impl From<Affine<Fp448>> for DecafPoint {
+    fn from(affine: Affine<Fp448>) -> Self {
+        Self(affine.into())
+    }
+}
+
+impl Coordinates for DecafPoint {
+    type Field = Fp448;
+    type Affine = Affine<Fp448>;
+
+    fn into_affine(&self) -> Option<Self::Affine> {
+        self.0.into_affine()
+    }
+}
+
+impl CurveOperations for Decaf448 {
+    type Field = Fp448;
+    type Affine = Affine<Fp448>;
+    type Point = DecafPoint;
+    type Cached = <Ed448Operations as CurveOperations>::Cached;
+
+    const UNIFIED: bool = true;
+
+    fn identity() -> Self::Point {
+        DecafPoint(Ed448Operations::identity())
+    }
+
+    fn is_on_curve(point: &Self::Affine) -> bool {
+        Ed448Operations::is_on_curve(point)
+    }
+
+    fn neg_in_place(point: &mut Self::Point) {
+        Ed448Operations::neg_in_place(&mut point.0);
+    }
+
+    fn add_in_place(lhs: &mut Self::Point, rhs: &Self::Point) {
+        Ed448Operations::add_in_place(&mut lhs.0, &rhs.0);
+    }
+
+    fn add_affine_in_place(lhs: &mut Self::Point, rhs: &Self::Affine) {
+        Ed448Operations::add_affine_in_place(&mut lhs.0, rhs);
+    }
+
+    fn double_in_place(point: &mut Self::Point) {
+        Ed448Operations::double_in_place(&mut point.0);
+    }
+
+    fn double_n_in_place(point: &mut Self::Point, n: usize) {
+        Ed448Operations::double_n_in_place(&mut point.0, n);
+    }
+
+    fn to_cached(point: &Self::Point) -> Self::Cached {
+        Ed448Operations::to_cached(&point.0)
+    }
+
+    fn add_cached_in_place(lhs: &mut Self::Point, rhs: &Self::Cached) {
+        Ed448Operations::add_cached_in_place(&mut lhs.0, rhs);
+    }
+}
+
+impl PrimeGroupConfig for Decaf448 {
+    type Public = DecafPoint;
+    type ScalarField = ScalarEd448;
+
+    const NAME: &'static str = "decaf448";
+
+    type Cofactor = [u64; 1];
+
+    // The group has prime order
+    const COFACTOR: Self::Cofactor = [1];
+
+    /// The image of twice the Ed448 base point, or a random multiple of it.
+    fn generator<R: Rng>(rng: Option<&mut R>) -> Self::Public {
+        let base = <Ed448Operations as PrimeSubGroupConfig>::generator::<R>(None);
+        let mut point = DecafPoint::from(base);
+        Self::double_in_place(&mut point);
+        if let Some(rng) = rng {
+            let scalar = ScalarEd448::rand(rng);
+            point = Self::scalar_mul(&point, &scalar);
+        }
+        point
+    }
+
+    /// Checks that the representative is on the curve and is killed by the group order
+    /// up to a point of order dividing 2.
+    fn is_valid(input: &Self::Public) -> bool {
+        let ExtendedPoint {
+            X: x,
+            Y: y,
+            T: t,
+            Z: z,
+        } = input.0;
+        let on_curve = x.square() + y.square() == z.square() + Ed448Parameters::D * t.square()
+            && x * y == z * t
+            && z != Fp448::zero();
+
+        on_curve && ScalarMul::wnaf::<Self>(input, &ScalarEd448::MODULUS) == Self::identity()
+    }
+
+    fn as_public(input: &Self::Point) -> Option<Self::Public> {
+        Some(*input)
+    }
+
+    fn compress(input: &Self::Public) -> Vec<u8> {
+        input.encode().to_vec()
+    }
+
+    fn add_public_in_place(lhs: &mut Self::Point, rhs: &Self::Public) {
+        Self::add_in_place(lhs, rhs)
+    }
+
+    /// Decodes the first 56 bytes of the digest, with the sign bit cleared, as an encoded
+    /// element.
+    fn batch_generators_from_label(label: &[u8], n: usize) -> Vec<Self::Public> {
+        generators_from_label::<Self>(label, n, |digest| {
+            let mut bytes = [0u8; 56];
+            bytes.copy_from_slice(&digest[..56]);
+            bytes[0] &= 0xfe;
+            DecafPoint::decode(&bytes)
+        })
+    }
+
+    fn msm<I, J>(bases: I, scalars: J) -> Self::Point
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Self::Point>,
+        J: IntoIterator,
+        J::Item: Borrow<Self::ScalarField>,
+    {
+        VariableBaseMSM::msm_simple::<Self, _, _, _>(bases, scalars)
+    }
+
+    fn msm_pub<I, J>(bases: I, scalars: J) -> Self::Point
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Self::Public>,
+        J: IntoIterator,
+        J::Item: Borrow<Self::ScalarField>,
+    {
+        Self::msm(bases, scalars)
+    }
+}
+
+impl CurveEncoding for Decaf448 {
+    /// Decodes the canonical 56 byte encoding, see `DecafPoint::decode`.
+    fn decompress(bytes: &[u8]) -> Option<Self::Public> {
+        DecafPoint::decode(bytes.try_into().ok()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::check_generators;
+    use cryp_std::rand::rngs::ThreadRng;
+    use cryp_std::rand::thread_rng;
+
+    fn from_hex<const N: usize>(hex: &str) -> [u8; N] {
+        let mut bytes = [0u8; N];
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&hex[2 * i..2 * i + 2], 16).unwrap();
+        }
+        bytes
+    }
+
+    /// The encodings of the small multiples of the generator of RFC 9496, appendix B.1
+    #[test]
+    fn test_multiples() {
+        use cryp_std::string::ToString;
+
+        let multiples = [
+            "0000000000000000000000000000000000000000000000000000000000000000\
+             000000000000000000000000000000000000000000000000",
+            "6666666666666666666666666666666666666666666666666666666633333333\
+             333333333333333333333333333333333333333333333333",
+            "c898eb4f87f97c564c6fd61fc7e49689314a1f818ec85eeb3bd5514ac816d387\
+             78f69ef347a89fca817e66defdedce178c7cc709b2116e75",
+            "a0c09bf2ba7208fda0f4bfe3d0f5b29a543012306d43831b5adc6fe7f8596fa3\
+             08763db15468323b11cf6e4aeb8c18fe44678f44545a69bc",
+            "b46f1836aa287c0a5a5653f0ec5ef9e903f436e21c1570c29ad9e5f596da97ee\
+             af17150ae30bcb3174d04bc2d712c8c7789d7cb4fda138f4",
+        ];
+
+        let g = GroupDecaf448::generator::<ThreadRng>(None);
+        let mut point = GroupDecaf448::identity();
+        for (i, hex) in multiples.iter().enumerate() {
+            let hex: cryp_std::string::String = hex.split_whitespace().collect();
+            let bytes = from_hex::<56>(&hex);
+            let public = point.as_public().unwrap();
+            assert_eq!(public.point.encode(), bytes);
+            assert_eq!(public.to_string(), hex);
+
+            let decoded = DecafPoint::decode(&bytes).unwrap();
+            assert_eq!(decoded, public.point);
+            assert_eq!(decoded.encode(), bytes);
+            assert_eq!(PublicDecaf448::decompress(&bytes), Some(public));
+            assert_eq!(GroupDecaf448::from(g).mul_int(&[i as u64]), point);
+            point += g;
+        }
+    }
+
+    #[test]
+    fn test_bad_encodings() {
+        let mut p = [0xffu8; 56];
+        p[28] = 0xfe;
+        let mut p_plus_two = p;
+        p_plus_two[0] = 0x01;
+        p_plus_two[28] = 0xff;
+        let mut one = [0u8; 56];
+        one[0] = 1;
+        let mut four = [0u8; 56];
+        four[0] = 4;
+
+        // Non-canonical field encodings, a negative field element and a non-square
+        for bytes in [p, p_plus_two, [0xff; 56], one, four] {
+            assert_eq!(DecafPoint::decode(&bytes), None);
+        }
+    }
+
+    #[test]
+    fn test_equality() {
+        let g = GroupDecaf448::generator::<ThreadRng>(None);
+        let edwards = g.point.0;
+
+        // Adding the point of order 2 gives the same element of the group
+        let order_two = ExtendedPoint::from(Affine::new(Fp448::zero(), -Fp448::one()));
+        let mut other = edwards;
+        Ed448Operations::add_in_place(&mut other, &order_two);
+        assert_ne!(other, edwards);
+        assert_eq!(DecafPoint(other), g.point);
+        assert_eq!(DecafPoint(other).encode(), g.point.encode());
+
+        // A point of order 4 changes the element and is not in the even subgroup
+        let order_four = ExtendedPoint::from(Affine::new(Fp448::one(), Fp448::zero()));
+        let mut other = edwards;
+        Ed448Operations::add_in_place(&mut other, &order_four);
+        assert_ne!(DecafPoint(other), g.point);
+        assert!(!GroupDecaf448::is_valid(&PublicDecaf448::new(DecafPoint(
+            other
+        ))));
+    }
+
+    #[test]
+    fn test_group() {
+        let g = GroupDecaf448::generator::<ThreadRng>(None);
+        assert!(GroupDecaf448::is_valid(&g));
+
+        let point = GroupDecaf448::from(g);
+        let identity = GroupDecaf448::identity();
+        let order = ScalarEd448::MODULUS;
+
+        assert_eq!(point + point, point.double());
+        assert_eq!(identity + point, point);
+        assert_eq!(point + (-point), identity);
+        assert_eq!(point.mul_int(&order), identity);
+
+        let mut rng = thread_rng();
+        let h = GroupDecaf448::generator(Some(&mut rng));
+        assert!(GroupDecaf448::is_valid(&h));
+        let decoded = DecafPoint::decode(&h.point.encode()).unwrap();
+        assert_eq!(decoded, h.point);
+
+        for _ in 0..5 {
+            let a = ScalarEd448::rand(&mut rng);
+            let b = ScalarEd448::rand(&mut rng);
+            assert_eq!(point * &a + point * &b, point * &(a + b));
+        }
+    }
+
+    #[test]
+    fn test_generators() {
+        check_generators::<GroupDecaf448>(
+            "a47da10ad6b58fa49eac668654c53ad9e84a0d892d7a0ce83bb963a36a029c6b\
+             26882ff8c93ca2abe3b8263d46d14e106b73927eae8931ce",
+        );
+    }
+
+    #[test]
+    fn test_introspection() {
+        assert_eq!(GroupDecaf448::name(), "decaf448");
+        assert_eq!(GroupDecaf448::cofactor(), [1]);
+        assert_eq!(GroupDecaf448::order(), ScalarEd448::MODULUS);
+    }
+}
@@ -0,0 +1,759 @@
+use crate::hash_to_curve::*;
+use crate::models::CurveOperations;
+use crate::models::generators_from_label;
+use crate::weierstrass::*;
+use cryp_alg::ff::*;
+use cryp_std::rand::Rng;
+use cryp_std::vec::Vec;
+
+pub type FpSecp256k1 = F<MontgomeryOperations<4, FpSecp256k1Params>>;
+pub type ScalarSecp256k1 = F<MontgomeryOperations<4, ScalarSecp256k1Parameters>>;
+pub type GroupSecp256k1 = GroupEC<ShortWeierstrassCompleteOperations<Secp256k1Parameters>>;
+pub type AffineSecp256k1 = PublicEC<ShortWeierstrassCompleteOperations<Secp256k1Parameters>>;
+
+/// Parameters of the curve secp256k1, see SEC 2 section 2.4.1.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Secp256k1Parameters;
+
+/// Parameters for the prime field FpSecp256k1
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FpSecp256k1Params;
+
+impl Secp256k1Parameters {
+    // 55066263022277343669578718895168534326250603453777594175500187360389116729240
+    const X: [u64; 4] = [
+        6481385041966929816,
+        188021827762530521,
+        6170039885052185351,
+        8772561819708210092,
+    ];
+
+    // 32670510020758816978083085130507043184471273380659243275938904335757337482424
+    const Y: [u64; 4] = [
+        11261198710074299576,
+        18237243440184513561,
+        6747795201694173352,
+        5204712524664259685,
+    ];
+
+    /// A primitive cube root of unity `beta` in the base field in Montgomery form.
+    ///
+    /// The map `(x, y) -> (beta * x, y)` is an endomorphism of the curve acting as
+    /// multiplication by `LAMBDA` on the prime order group.
+    // beta = 0x7ae96a2b657c07106e64479eac3434e99cf0497512f58995c1396c28719501ee
+    pub const BETA: FpSecp256k1 =
+        FpSecp256k1::from_RAW_limbs(<FpSecp256k1 as PrimeField>::BigInteger::from_limbs([
+            6387289667796044110,
+            287633767014301871,
+            17936018142961481989,
+            8811915745022393683,
+        ]));
+
+    /// The eigenvalue of the endomorphism, a cube root of unity in the scalar field.
+    // lambda = 0x5363ad4cc05c30e0a5261c028812645a122e22ea20816678df02967c1b23bd72
+    pub const LAMBDA: ScalarSecp256k1 =
+        ScalarSecp256k1::from_RAW_limbs(<ScalarSecp256k1 as PrimeField>::BigInteger::from_limbs([
+            17329265591798885534,
+            3212165691671483468,
+            8334304762764295569,
+            5992109773982062137,
+        ]));
+
+    // The short basis {(a1, b1), (a2, b2)} of the lattice of decompositions of zero is
+    // a1 = b2 = 0x3086d221a7d46bcde86c90e49284eb15,
+    // b1 = -0xe4437ed6010e88286f547fa90abfe4c3,
+    // a2 = 0x114ca50f7a8e2f3f657c1108d9d44cfd8.
+
+    // -b1
+    const MINUS_B1: [u64; 4] = [8022177200260244675, 16448129721693014056, 0, 0];
+
+    // -b2 mod n
+    const MINUS_B2: [u64; 4] = [
+        15521037813275514412,
+        9955218817652372589,
+        18446744073709551614,
+        18446744073709551615,
+    ];
+
+    // round(2^384 * b2 / n)
+    const G1: [u64; 4] = [
+        16758774485344825393,
+        4443515802769476223,
+        16747920425669159701,
+        3496713202691238861,
+    ];
+
+    // round(2^384 * (-b1) / n)
+    const G2: [u64; 4] = [
+        1545214808910233457,
+        2455034284347819718,
+        8022177200260244676,
+        16448129721693014056,
+    ];
+
+    /// The GLV endomorphism `(x, y) -> (beta * x, y)` in projective coordinates.
+    pub fn endomorphism(point: &Projective<FpSecp256k1>) -> Projective<FpSecp256k1> {
+        Projective {
+            X: point.X * Self::BETA,
+            Y: point.Y,
+            Z: point.Z,
+        }
+    }
+
+    /// Decomposes a scalar `k` as `k = k1 + k2 * LAMBDA mod n`.
+    ///
+    /// Both `k1` and `k2` are returned as a pair of a sign (`true` if negative) and an
+    /// absolute value of at most 128 bits.
+    pub fn decompose(k: &ScalarSecp256k1) -> ((bool, [u64; 2]), (bool, [u64; 2])) {
+        // c_i = round(k * g_i / 2^384)
+        let mul_shift = |g: [u64; 4]| {
+            let (_, high) = k.as_int().carrying_mul(
+                g.into(),
+                <ScalarSecp256k1 as PrimeField>::BigInteger::zero(),
+            );
+            let c = ((high.limbs[3] as u128) << 64 | high.limbs[2] as u128)
+                + (high.limbs[1] >> 63) as u128;
+            ScalarSecp256k1::from_int(&[c as u64, (c >> 64) as u64, 0, 0].into())
+        };
+        let c1 = mul_shift(Self::G1);
+        let c2 = mul_shift(Self::G2);
+
+        let k2 = c1 * ScalarSecp256k1::from_int(&Self::MINUS_B1.into())
+            + c2 * ScalarSecp256k1::from_int(&Self::MINUS_B2.into());
+        let k1 = *k - k2 * Self::LAMBDA;
+
+        (Self::split_sign(k1), Self::split_sign(k2))
+    }
+
+    /// The sign and absolute value of a scalar of at most 128 bits or its negative, selected
+    /// with a mask rather than a branch.
+    fn split_sign(k: ScalarSecp256k1) -> (bool, [u64; 2]) {
+        let int = k.as_int();
+        let neg_int = (-k).as_int();
+        let negative = (int.limbs[2] | int.limbs[3]) != 0;
+        debug_assert!(!negative || (neg_int.limbs[2] | neg_int.limbs[3]) == 0);
+
+        let mask = 0u64.wrapping_sub(negative as u64);
+        (
+            negative,
+            [
+                mask_select(int.limbs[0], neg_int.limbs[0], mask),
+                mask_select(int.limbs[1], neg_int.limbs[1], mask),
+            ],
+        )
+    }
+
+    /// The point or its negative if `choice` is set.
+    fn conditional_neg(point: &Projective<FpSecp256k1>, choice: bool) -> Projective<FpSecp256k1> {
+        let mask = 0u64.wrapping_sub(choice as u64);
+        Projective {
+            X: point.X,
+            Y: conditional_select(&point.Y, &-point.Y, mask),
+            Z: point.Z,
+        }
+    }
+
+    /// The entry `index` of the table. All the entries are read and the entry is selected
+    /// with masks derived arithmetically from the index.
+    fn select(table: &[Projective<FpSecp256k1>; 4], index: usize) -> Projective<FpSecp256k1> {
+        let mut res = table[0];
+        for (i, entry) in table.iter().enumerate().skip(1) {
+            // all ones if i == index, zero otherwise
+            let mask = 0u64.wrapping_sub((((i ^ index) as u64).wrapping_sub(1)) >> 63);
+            res.X = conditional_select(&res.X, &entry.X, mask);
+            res.Y = conditional_select(&res.Y, &entry.Y, mask);
+            res.Z = conditional_select(&res.Z, &entry.Z, mask);
+        }
+        res
+    }
+}
+
+/// `a` if the mask is zero and `b` if all its bits are set.
+fn mask_select(a: u64, b: u64, mask: u64) -> u64 {
+    a ^ (mask & (a ^ b))
+}
+
+/// `a` if the mask is zero and `b` if all its bits are set, selected limb by limb.
+fn conditional_select(a: &FpSecp256k1, b: &FpSecp256k1, mask: u64) -> FpSecp256k1 {
+    let (a, b) = (a.as_RAW_limbs(), b.as_RAW_limbs());
+    let mut limbs = a.limbs;
+    for (limb, b) in limbs.iter_mut().zip(b.limbs) {
+        *limb = mask_select(*limb, b, mask);
+    }
+    FpSecp256k1::from_RAW_limbs(limbs.into())
+}
+
+/// An element of FpSecp256k1 given in Montgomery form.
+const fn fp(limbs: [u64; 4]) -> FpSecp256k1 {
+    FpSecp256k1::from_RAW_limbs(<FpSecp256k1 as PrimeField>::BigInteger::from_limbs(limbs))
+}
+
+// The coefficients of the 3-isogeny of RFC 9380, appendix E.1, in order of increasing degree
+const ISO_X_NUM: [FpSecp256k1; 4] = [
+    // 0x8e38e38e38e38e38e38e38e38e38e38e38e38e38e38e38e38e38e38daaaaa8c7
+    fp([253880346804, 0, 0, 0]),
+    // 0x7d3d4c80bc321d5b9f315cea7fd44c5d595d2fc0bf63b92dfff1044f17c6581
+    fp([
+        15401556054675218246,
+        3224699913824136141,
+        5815130584626317824,
+        16947662544290920057,
+    ]),
+    // 0x534c328d23f234e6e2a413deca25caece4506144037c40314ecbd0b53d9dd262
+    fp([
+        5242624389536649661,
+        6503044766135799011,
+        13715044361241875287,
+        702316956669180165,
+    ]),
+    // 0x8e38e38e38e38e38e38e38e38e38e38e38e38e38e38e38e38e38e38daaaaa88c
+    fp([477218697, 0, 0, 0]),
+];
+
+const ISO_X_DEN: [FpSecp256k1; 3] = [
+    // 0xd35771193d94918a9ca34ccbb7b640dd86cd409542f8487d9fe6b745781eb49b
+    fp([
+        10013643957699995642,
+        13279921378413469365,
+        9434573195234168324,
+        14865030926825602763,
+    ]),
+    // 0xedadc6f64383dc1df7c4b2d51b54225406d36b641f5e41bbc52a56612a8c6d14
+    fp([
+        10290131358410743717,
+        3187170674093536253,
+        12754934808919567890,
+        6320852610022621491,
+    ]),
+    // 0x1
+    fp([4294968273, 0, 0, 0]),
+];
+
+const ISO_Y_NUM: [FpSecp256k1; 4] = [
+    // 0x4bda12f684bda12f684bda12f684bda12f684bda12f684bda12f684b8e38e23c
+    fp([
+        18446743860074648259,
+        18446744073709551615,
+        18446744073709551615,
+        18446744073709551615,
+    ]),
+    // 0xc75e0c32d5cb7c0fa9d0a54b12a0a6d5647ab046d686da6fdffc90fc201d71a3
+    fp([
+        13429969373273428526,
+        5674984992785315314,
+        2875401403253613739,
+        12950111799174569234,
+    ]),
+    // 0x29a6194691f91a73715209ef6512e576722830a201be2018a765e85a9ecee931
+    fp([
+        11844684229475616502,
+        12474894419922675313,
+        16080894217475713451,
+        9574530515189365890,
+    ]),
+    // 0x2f684bda12f684bda12f684bda12f684bda12f684bda12f684bda12f38e38d84
+    fp([159072899, 0, 0, 0]),
+];
+
+const ISO_Y_DEN: [FpSecp256k1; 4] = [
+    // 0xfffffffffffffffffffffffffffffffffffffffffffffffffffffffefffff93b
+    fp([
+        18446740822418568955,
+        18446744073709551615,
+        18446744073709551615,
+        18446744073709551615,
+    ]),
+    // 0x7a06534bb8bdb49fd5e9e6632722c2989467c1bfc8e8d978dfb425d2685c2573
+    fp([
+        11594187807980371856,
+        2946275987821304864,
+        9856975511992953358,
+        7701604633057705058,
+    ]),
+    // 0x6484aa716545ca2cf3a70c3fa8fe337e0a3d21162f0d6299a7bf8192bfd2a76f
+    fp([
+        6211825002908823904,
+        4780756011140304380,
+        9909030176524576027,
+        257906878179156429,
+    ]),
+    // 0x1
+    fp([4294968273, 0, 0, 0]),
+];
+
+impl MontParameters<4usize> for FpSecp256k1Params {
+    type Limb = u64;
+
+    // 2^256 - 2^32 - 977
+    const MODULUS: [Self::Limb; 4] = [
+        18446744069414583343,
+        18446744073709551615,
+        18446744073709551615,
+        18446744073709551615,
+    ];
+
+    const R: [Self::Limb; 4] = [4294968273, 0, 0, 0];
+
+    const R2: [Self::Limb; 4] = [8392367050913, 1, 0, 0];
+
+    const MP: Self::Limb = 15580212934572586289;
+}
+
+impl ShortWeierstrass for Secp256k1Parameters {
+    type Field = FpSecp256k1;
+
+    const A: Self::Field =
+        FpSecp256k1::from_RAW_limbs(<FpSecp256k1 as PrimeField>::BigInteger::from_limbs([0; 4]));
+
+    // The element b = 7 in Montgomery form
+    const B: Self::Field =
+        FpSecp256k1::from_RAW_limbs(<FpSecp256k1 as PrimeField>::BigInteger::from_limbs([
+            30064777911,
+            0,
+            0,
+            0,
+        ]));
+
+    const A_KIND: CoefficientKind = CoefficientKind::Zero;
+}
+
+// The scalar Field
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ScalarSecp256k1Parameters;
+
+impl MontParameters<4usize> for ScalarSecp256k1Parameters {
+    type Limb = u64;
+
+    // 115792089237316195423570985008687907852837564279074904382605163141518161494337
+    const MODULUS: [Self::Limb; 4] = [
+        13822214165235122497,
+        13451932020343611451,
+        18446744073709551614,
+        18446744073709551615,
+    ];
+
+    const R: [Self::Limb; 4] = [4624529908474429119, 4994812053365940164, 1, 0];
+
+    const R2: [Self::Limb; 4] = [
+        9902555850136342848,
+        8364476168144746616,
+        16616019711348246470,
+        11342065889886772165,
+    ];
+
+    const MP: Self::Limb = 5408259542528602431;
+}
+
+impl PrimeSubGroupConfig for ShortWeierstrassCompleteOperations<Secp256k1Parameters> {
+    type ScalarField = ScalarSecp256k1;
+
+    const NAME: &'static str = "secp256k1";
+
+    type Cofactor = [u64; 1];
+
+    const COFACTOR: Self::Cofactor = [1];
+
+    fn generator<R: Rng>(rng: Option<&mut R>) -> Self::Affine {
+        let x = FpSecp256k1::from_int(&Secp256k1Parameters::X.into());
+        let y = FpSecp256k1::from_int(&Secp256k1Parameters::Y.into());
+        let affine_point = Self::Affine::new(x, y);
+
+        let mut point = Self::Point::from(affine_point);
+        if let Some(rng) = rng {
+            let scalar = ScalarSecp256k1::rand(rng);
+            point = <Self as PrimeSubGroupConfig>::scalar_mul(&point, &scalar);
+        }
+        point.into_affine().unwrap()
+    }
+
+    // The curve has prime order, so every point on it is in the group.
+    fn is_in_subgroup(_point: &Self::Affine) -> bool {
+        true
+    }
+
+    fn compress(input: &Self::Affine) -> Vec<u8> {
+        sec1_compress(input)
+    }
+
+    /// Uses the first 32 bytes of the digest as `x` and the next bit as the parity of `y`.
+    fn batch_generators_from_label(label: &[u8], n: usize) -> Vec<Self::Affine> {
+        generators_from_label::<Self>(label, n, |digest| {
+            let mut bytes = [0u8; 33];
+            bytes[0] = 0x02 | (digest[32] & 1);
+            bytes[1..].copy_from_slice(&digest[..32]);
+            sec1_decompress::<Secp256k1Parameters>(&bytes).map(Into::into)
+        })
+    }
+
+    /// The suite `secp256k1_XMD:SHA-256_SSWU_RO_` of RFC 9380.
+    fn hash_to_curve(msg: &[u8], dst: &[u8]) -> Option<Self::Point> {
+        Some(hash_to_curve::<Self>(msg, dst))
+    }
+
+    /// The suite `secp256k1_XMD:SHA-256_SSWU_NU_` of RFC 9380.
+    fn encode_to_curve(msg: &[u8], dst: &[u8]) -> Option<Self::Point> {
+        Some(encode_to_curve::<Self>(msg, dst))
+    }
+
+    /// Scalar multiplication in constant time using the GLV endomorphism.
+    ///
+    /// The scalar is decomposed as `k = k1 + k2 * lambda` with `k1, k2` of 128 bits and
+    /// `k1 * P + k2 * phi(P)` is computed with a joint double-and-add (the Straus-Shamir
+    /// trick), halving the number of doublings of the ladder. The signs of `k1, k2` and
+    /// the bits of the scalars are used through masked selections on the limbs, so that
+    /// neither the branches nor the memory accesses depend on the scalar.
+    fn scalar_mul(base: &Self::Point, scalar: &Self::ScalarField) -> Self::Point {
+        let ((neg1, k1), (neg2, k2)) = Secp256k1Parameters::decompose(scalar);
+
+        let p = Secp256k1Parameters::conditional_neg(base, neg1);
+        let q =
+            Secp256k1Parameters::conditional_neg(&Secp256k1Parameters::endomorphism(base), neg2);
+        let mut p_plus_q = p;
+        Self::add_in_place(&mut p_plus_q, &q);
+        let table = [Self::identity(), p, q, p_plus_q];
+
+        // the complete formulas handle the additions of the identity
+        let mut res = Self::identity();
+        for (b1, b2) in Bits::into_iter_be(&k1).zip(Bits::into_iter_be(&k2)) {
+            Self::double_in_place(&mut res);
+            let entry = Secp256k1Parameters::select(&table, b1 as usize | (b2 as usize) << 1);
+            Self::add_in_place(&mut res, &entry);
+        }
+        res
+    }
+}
+
+impl SSWUMap for Secp256k1Parameters {
+    // A' = 0x3f8731abdd661adca08a5558f0f5d272e953d363cb6f0e5d405447c01a444533
+    const ISO_A: Self::Field = fp([
+        15812504324673914017,
+        4924912935180573090,
+        11593825521208392688,
+        5790129131709978969,
+    ]);
+
+    // B' = 1771
+    const ISO_B: Self::Field = fp([7606388811483, 0, 0, 0]);
+
+    // Z = -11
+    const Z: Self::Field = fp([
+        18446744022169932340,
+        18446744073709551615,
+        18446744073709551615,
+        18446744073709551615,
+    ]);
+
+    /// The 3-isogeny from the curve `y^2 = x^3 + A'x + B'`.
+    fn isogeny_map(point: Affine<FpSecp256k1>) -> Option<Affine<FpSecp256k1>> {
+        isogeny_map(&point, &ISO_X_NUM, &ISO_X_DEN, &ISO_Y_NUM, &ISO_Y_DEN)
+    }
+}
+
+impl HashToCurveConfig for ShortWeierstrassCompleteOperations<Secp256k1Parameters> {
+    const L: usize = 48;
+
+    fn expand_message(msg: &[u8], dst: &[u8], len_in_bytes: usize) -> Vec<u8> {
+        expand_message_xmd::<Sha256>(msg, dst, len_in_bytes)
+    }
+
+    fn map_to_curve(u: &FpSecp256k1) -> Self::Point {
+        map_to_curve_sswu::<Secp256k1Parameters>(u).map_or_else(Self::identity, Into::into)
+    }
+}
+
+impl CurveEncoding for ShortWeierstrassCompleteOperations<Secp256k1Parameters> {
+    /// Decodes a compressed or uncompressed SEC1 encoding.
+    fn decompress(bytes: &[u8]) -> Option<Self::Affine> {
+        sec1_decompress::<Secp256k1Parameters>(bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::check_generators;
+    use cryp_std::rand::rngs::ThreadRng;
+    use cryp_std::rand::thread_rng;
+    use cryp_std::rand::UniformRand;
+
+    /// Parses a big endian hexadecimal string of 64 characters.
+    fn from_hex(hex: &str) -> [u64; 4] {
+        let mut limbs = [0u64; 4];
+        for (i, limb) in limbs.iter_mut().enumerate() {
+            let start = 48 - 16 * i;
+            *limb = u64::from_str_radix(&hex[start..start + 16], 16).unwrap();
+        }
+        limbs
+    }
+
+    fn affine(x: &str, y: &str) -> Affine<FpSecp256k1> {
+        Affine::new(
+            FpSecp256k1::from_int(&from_hex(x).into()),
+            FpSecp256k1::from_int(&from_hex(y).into()),
+        )
+    }
+
+    fn from_parts((negative, k): (bool, [u64; 2])) -> ScalarSecp256k1 {
+        let k = ScalarSecp256k1::from_int(&[k[0], k[1], 0, 0].into());
+        if negative {
+            -k
+        } else {
+            k
+        }
+    }
+
+    #[test]
+    fn test_parameters() {
+        assert_eq!(Secp256k1Parameters::A, FpSecp256k1::zero());
+        assert_eq!(
+            Secp256k1Parameters::B,
+            FpSecp256k1::from_int(&[7, 0, 0, 0].into())
+        );
+
+        let beta = Secp256k1Parameters::BETA;
+        assert_ne!(beta, FpSecp256k1::one());
+        assert_eq!(beta.square() * beta, FpSecp256k1::one());
+
+        let lambda = Secp256k1Parameters::LAMBDA;
+        assert_ne!(lambda, ScalarSecp256k1::one());
+        assert_eq!(lambda.square() * lambda, ScalarSecp256k1::one());
+
+        let mut rng = thread_rng();
+        let x = FpSecp256k1::rand(&mut rng);
+        assert_eq!(x * x.inverse().unwrap(), FpSecp256k1::one());
+        let s = ScalarSecp256k1::rand(&mut rng);
+        assert_eq!(s * s.inverse().unwrap(), ScalarSecp256k1::one());
+    }
+
+    #[test]
+    fn test_endomorphism() {
+        let mut rng = thread_rng();
+        let g = GroupSecp256k1::generator(Some(&mut rng));
+
+        let lambda = Secp256k1Parameters::LAMBDA;
+        let phi_g = Secp256k1Parameters::endomorphism(&g.point.into());
+        assert_eq!(
+            phi_g.into_affine().unwrap(),
+            (GroupSecp256k1::from(g) * &lambda)
+                .as_public()
+                .unwrap()
+                .point
+        );
+        assert_eq!(
+            phi_g.into_affine().unwrap(),
+            GroupSecp256k1::from(g)
+                .mul_int(&lambda.as_int())
+                .as_public()
+                .unwrap()
+                .point
+        );
+    }
+
+    #[test]
+    fn test_decompose() {
+        let mut rng = thread_rng();
+        let lambda = Secp256k1Parameters::LAMBDA;
+
+        let mut scalars = (0..100)
+            .map(|_| ScalarSecp256k1::rand(&mut rng))
+            .collect::<Vec<_>>();
+        scalars.extend([
+            ScalarSecp256k1::zero(),
+            ScalarSecp256k1::one(),
+            -ScalarSecp256k1::one(),
+            lambda,
+            -lambda,
+        ]);
+
+        for k in scalars {
+            let (k1, k2) = Secp256k1Parameters::decompose(&k);
+            assert_eq!(from_parts(k1) + from_parts(k2) * lambda, k);
+        }
+    }
+
+    #[test]
+    fn test_selections() {
+        type Ops = ShortWeierstrassCompleteOperations<Secp256k1Parameters>;
+        let g = Projective::from(GroupSecp256k1::generator::<ThreadRng>(None).point);
+        let mut double = g;
+        Ops::double_in_place(&mut double);
+        let mut neg = g;
+        Ops::neg_in_place(&mut neg);
+        let points = [Ops::identity(), g, double, neg];
+        for (i, point) in points.iter().enumerate() {
+            assert_eq!(Secp256k1Parameters::select(&points, i), *point);
+        }
+
+        let p = points[1];
+        assert_eq!(Secp256k1Parameters::conditional_neg(&p, false), p);
+        assert_eq!(Secp256k1Parameters::conditional_neg(&p, true), points[3]);
+    }
+
+    #[test]
+    fn test_glv_against_ladder() {
+        let mut rng = thread_rng();
+        let g = GroupSecp256k1::from(GroupSecp256k1::generator(Some(&mut rng)));
+        let identity = GroupSecp256k1::identity();
+
+        for _ in 0..10 {
+            let k = ScalarSecp256k1::rand(&mut rng);
+            assert_eq!(g * &k, g.mul_int(&k.as_int()));
+        }
+        for k in [
+            ScalarSecp256k1::zero(),
+            ScalarSecp256k1::one(),
+            -ScalarSecp256k1::one(),
+            Secp256k1Parameters::LAMBDA,
+        ] {
+            assert_eq!(g * &k, g.mul_int(&k.as_int()));
+        }
+        assert_eq!(identity * &ScalarSecp256k1::rand(&mut rng), identity);
+        assert_eq!(g.mul_int(&ScalarSecp256k1::MODULUS), identity);
+    }
+
+    #[test]
+    fn test_msm() {
+        let mut rng = thread_rng();
+        let bases = GroupSecp256k1::batch_generators(40, &mut rng);
+        let mut scalars = (0..40)
+            .map(|_| ScalarSecp256k1::rand(&mut rng))
+            .collect::<Vec<_>>();
+        scalars[3] = ScalarSecp256k1::zero();
+        scalars[7] = -ScalarSecp256k1::one();
+
+        let expected = bases
+            .iter()
+            .zip(scalars.iter())
+            .map(|(b, s)| *b * s)
+            .sum::<GroupSecp256k1>();
+        assert_eq!(GroupSecp256k1::msm_non_ct(&bases, &scalars), expected);
+    }
+
+    #[test]
+    fn test_scalar_mul_vectors() {
+        let g = GroupSecp256k1::from(GroupSecp256k1::generator::<ThreadRng>(None));
+
+        let vectors: [([u64; 4], &str, &str); 4] = [
+            (
+                [1, 0, 0, 0],
+                "79BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798",
+                "483ADA7726A3C4655DA4FBFC0E1108A8FD17B448A68554199C47D08FFB10D4B8",
+            ),
+            (
+                [2, 0, 0, 0],
+                "C6047F9441ED7D6D3045406E95C07CD85C778E4B8CEF3CA7ABAC09B95C709EE5",
+                "1AE168FEA63DC339A3C58419466CEAEEF7F632653266D0E1236431A950CFE52A",
+            ),
+            (
+                [3, 0, 0, 0],
+                "F9308A019258C31049344F85F89D5229B531C845836F99B08601F113BCE036F9",
+                "388F7B0F632DE8140FE337E62A37F3566500A99934C2231B6CB9FD7584B8E672",
+            ),
+            (
+                [112233445566778899, 0, 0, 0],
+                "A90CC3D3F3E146DAADFC74CA1372207CB4B725AE708CEF713A98EDD73D99EF29",
+                "5A79D6B289610C68BC3B47F3D72F9788A26A06868B4D8E433E1E2AD76FB7DC76",
+            ),
+        ];
+
+        for (k, x, y) in vectors.iter() {
+            let expected = affine(x, y);
+            let scalar = ScalarSecp256k1::from_int(&(*k).into());
+            assert_eq!((g * &scalar).as_public().unwrap().point, expected);
+            assert_eq!(g.mul_int_non_ct(k).as_public().unwrap().point, expected);
+        }
+    }
+
+    #[test]
+    fn test_generators() {
+        check_generators::<GroupSecp256k1>(
+            "03e7b99625ea1fe0ee5eb3dcb068775e7915fa55d8d5a3b3db5060394301ec8a02",
+        );
+    }
+
+    /// The test vectors of RFC 9380, appendix J.8, for the suites
+    /// secp256k1_XMD:SHA-256_SSWU_RO_ and secp256k1_XMD:SHA-256_SSWU_NU_
+    #[test]
+    fn test_hash_to_curve() {
+        let q128 = [b"q128_".as_slice(), &[b'q'; 128]].concat();
+        let a512 = [b"a512_".as_slice(), &[b'a'; 512]].concat();
+        let msgs: [&[u8]; 5] = [b"", b"abc", b"abcdef0123456789", &q128, &a512];
+
+        let dst = b"QUUX-V01-CS02-with-secp256k1_XMD:SHA-256_SSWU_RO_";
+        let expected = [
+            (
+                "c1cae290e291aee617ebaef1be6d73861479c48b841eaba9b7b5852ddfeb1346",
+                "64fa678e07ae116126f08b022a94af6de15985c996c3a91b64c406a960e51067",
+            ),
+            (
+                "3377e01eab42db296b512293120c6cee72b6ecf9f9205760bd9ff11fb3cb2c4b",
+                "7f95890f33efebd1044d382a01b1bee0900fb6116f94688d487c6c7b9c8371f6",
+            ),
+            (
+                "bac54083f293f1fe08e4a70137260aa90783a5cb84d3f35848b324d0674b0e3a",
+                "4436476085d4c3c4508b60fcf4389c40176adce756b398bdee27bca19758d828",
+            ),
+            (
+                "e2167bc785333a37aa562f021f1e881defb853839babf52a7f72b102e41890e9",
+                "f2401dd95cc35867ffed4f367cd564763719fbc6a53e969fb8496a1e6685d873",
+            ),
+            (
+                "e3c8d35aaaf0b9b647e88a0a0a7ee5d5bed5ad38238152e4e6fd8c1f8cb7c998",
+                "8446eeb6181bf12f56a9d24e262221cc2f0c4725c7e3803024b5888ee5823aa6",
+            ),
+        ];
+        for (msg, (x, y)) in msgs.iter().zip(expected) {
+            let point = GroupSecp256k1::hash_to_curve(msg, dst).unwrap();
+            assert_eq!(point.as_public().unwrap().point, affine(x, y));
+        }
+
+        let dst = b"QUUX-V01-CS02-with-secp256k1_XMD:SHA-256_SSWU_NU_";
+        let expected = [
+            (
+                "a4792346075feae77ac3b30026f99c1441b4ecf666ded19b7522cf65c4c55c5b",
+                "62c59e2a6aeed1b23be5883e833912b08ba06be7f57c0e9cdc663f31639ff3a7",
+            ),
+            (
+                "3f3b5842033fff837d504bb4ce2a372bfeadbdbd84a1d2b678b6e1d7ee426b9d",
+                "902910d1fef15d8ae2006fc84f2a5a7bda0e0407dc913062c3a493c4f5d876a5",
+            ),
+        ];
+        for (msg, (x, y)) in msgs.iter().zip(expected) {
+            let point = GroupSecp256k1::encode_to_curve(msg, dst).unwrap();
+            assert_eq!(point.as_public().unwrap().point, affine(x, y));
+        }
+    }
+
+    #[test]
+    fn test_introspection() {
+        use cryp_std::string::ToString;
+
+        assert_eq!(GroupSecp256k1::name(), "secp256k1");
+        assert_eq!(GroupSecp256k1::cofactor(), [1]);
+        assert_eq!(GroupSecp256k1::order(), ScalarSecp256k1::MODULUS);
+
+        let g = GroupSecp256k1::generator::<ThreadRng>(None);
+        assert!(GroupSecp256k1::is_valid(&g));
+        assert_eq!(
+            g.to_string(),
+            "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798"
+        );
+    }
+
+    #[test]
+    fn test_encoding() {
+        let mut rng = thread_rng();
+        let g = GroupSecp256k1::generator::<ThreadRng>(None);
+        assert_eq!(AffineSecp256k1::decompress(&g.compress()), Some(g));
+
+        for point in GroupSecp256k1::batch_generators(20, &mut rng) {
+            let negative = (-GroupSecp256k1::from(point)).as_public().unwrap();
+            for point in [point, negative] {
+                assert_eq!(AffineSecp256k1::decompress(&point.compress()), Some(point));
+                let uncompressed = sec1_uncompressed(&point.point);
+                assert_eq!(uncompressed.len(), 65);
+                assert_eq!(AffineSecp256k1::decompress(&uncompressed), Some(point));
+            }
+        }
+    }
+}
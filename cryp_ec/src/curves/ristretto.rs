@@ -0,0 +1,662 @@
+use super::ed25519::{Ed25519Parameters, Fp25519, ScalarEd25519};
+use crate::edwards::*;
+use crate::models::scalar_mul::{ScalarMul, VariableBaseMSM};
+use crate::models::{generators_from_label, CurveOperations};
+use core::borrow::Borrow;
+use cryp_alg::ff::*;
+use cryp_std::{
+    fmt::{Display, Formatter, Result},
+    hash::{Hash, Hasher},
+    rand::Rng,
+    vec::Vec,
+};
+
+pub type GroupRistretto255 = GroupEC<Ristretto255>;
+pub type PublicRistretto255 = PublicEC<Ristretto255>;
+
+type Ed25519Operations = EdwardsAM1UnifiedOperations<Ed25519Parameters>;
+
+/// The prime order group ristretto255 of RFC 9496.
+///
+/// The group is the quotient of the even subgroup of Ed25519 by its 4-torsion, so that an
+/// element is represented by any of the four Edwards points in its coset. The curve
+/// arithmetic is the one of `EdwardsAM1UnifiedOperations<Ed25519Parameters>`, only the
+/// equality, the encoding and the hash to the group differ.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Ristretto255;
+
+/// An element of ristretto255, represented by an Edwards point in extended coordinates.
+#[derive(Clone, Copy, Debug)]
+pub struct RistrettoPoint(ExtendedPoint<Fp25519>);
+
+// The constants of RFC 9496, section 4.1, in the regular representation.
+
+// sqrt(-1) = 19681161376707505956807079304988542015446066515923890162744021073123829784752
+const SQRT_M1: Fp25519 =
+    Fp25519::from_RAW_limbs(<Fp25519 as PrimeField>::BigInteger::from_limbs([
+        14190309331451158704,
+        3405592160176694392,
+        3120150775007532967,
+        3135389899092516619,
+    ]));
+
+// sqrt(a * d - 1) = 25063068953384623474111414158702152701244531502492656460079210482610430750235
+const SQRT_AD_MINUS_ONE: Fp25519 =
+    Fp25519::from_RAW_limbs(<Fp25519 as PrimeField>::BigInteger::from_limbs([
+        9122030738550238747,
+        12654426710632584381,
+        1098030350228378109,
+        3992777241779194028,
+    ]));
+
+// 1 / sqrt(a - d) = 54469307008909316920995813868745141605393597292927456921205312896311721017578
+const INVSQRT_A_MINUS_D: Fp25519 =
+    Fp25519::from_RAW_limbs(<Fp25519 as PrimeField>::BigInteger::from_limbs([
+        11081385791884968170,
+        11326295877414384318,
+        1640009081278289984,
+        8677461240088362146,
+    ]));
+
+// 1 - d^2 = 1159843021668779879193775521855586647937357759715417654439879720876111806838
+const ONE_MINUS_D_SQ: Fp25519 =
+    Fp25519::from_RAW_limbs(<Fp25519 as PrimeField>::BigInteger::from_limbs([
+        16319929876706476406,
+        3207021675000247567,
+        11066659153227341796,
+        184773653600395479,
+    ]));
+
+// (d - 1)^2 = 40440834346308536858101042469323190826248399146238708352240133220865137265952
+const D_MINUS_ONE_SQ: Fp25519 =
+    Fp25519::from_RAW_limbs(<Fp25519 as PrimeField>::BigInteger::from_limbs([
+        3579616966176754976,
+        15176649350078929305,
+        5538533841961701099,
+        6442596607655158337,
+    ]));
+
+// (p - 5) / 8
+const P_MINUS_5_DIV_8: [u64; 4] = [
+    18446744073709551613,
+    18446744073709551615,
+    18446744073709551615,
+    1152921504606846975,
+];
+
+/// A field element is negative if its least significant bit is set.
+fn is_negative(x: &Fp25519) -> bool {
+    x.as_int().limbs[0] & 1 == 1
+}
+
+/// The non-negative one of `x` and `-x`.
+fn abs(x: &Fp25519) -> Fp25519 {
+    if is_negative(x) {
+        -*x
+    } else {
+        *x
+    }
+}
+
+/// Computes the non-negative square root of `u / v` if it exists, or else of
+/// `sqrt(-1) * u / v`, see RFC 9496 section 4.2. The flag is set in the first case.
+fn sqrt_ratio_m1(u: &Fp25519, v: &Fp25519) -> (bool, Fp25519) {
+    let v3 = v.square() * v;
+    let v7 = v3.square() * v;
+    let mut r = (*u * v3) * (*u * v7).exp(&P_MINUS_5_DIV_8);
+    let check = *v * r.square();
+
+    let correct_sign = check == *u;
+    let flipped_sign = check == -*u;
+    let flipped_sign_i = check == -*u * SQRT_M1;
+
+    if flipped_sign || flipped_sign_i {
+        r *= SQRT_M1;
+    }
+    (correct_sign || flipped_sign, abs(&r))
+}
+
+fn to_bytes(x: &Fp25519) -> [u8; 32] {
+    let mut bytes = [0u8; 32];
+    for (chunk, limb) in bytes.chunks_mut(8).zip(x.as_int().limbs.iter()) {
+        chunk.copy_from_slice(&limb.to_le_bytes());
+    }
+    bytes
+}
+
+/// Decodes the 255 least significant bits of the bytes as a field element.
+fn from_bytes(bytes: &[u8]) -> Fp25519 {
+    let mut limbs = [0u64; 4];
+    for (limb, chunk) in limbs.iter_mut().zip(bytes.chunks(8)) {
+        *limb = u64::from_le_bytes(chunk.try_into().unwrap());
+    }
+    limbs[3] &= (1 << 63) - 1;
+    Fp25519::from_int(&limbs.into())
+}
+
+impl RistrettoPoint {
+    /// Decodes a canonical 32 byte encoding, see RFC 9496 section 4.3.1.
+    ///
+    /// Returns `None` if the bytes are not the encoding of a group element.
+    pub fn decode(bytes: &[u8; 32]) -> Option<Self> {
+        let s = from_bytes(bytes);
+        if to_bytes(&s) != *bytes || is_negative(&s) {
+            return None;
+        }
+
+        let one = Fp25519::one();
+        let ss = s.square();
+        let u1 = one - ss;
+        let u2 = one + ss;
+        let u2_sqr = u2.square();
+
+        let v = -(Ed25519Parameters::D * u1.square()) - u2_sqr;
+        let (was_square, invsqrt) = sqrt_ratio_m1(&one, &(v * u2_sqr));
+
+        let den_x = invsqrt * u2;
+        let den_y = invsqrt * den_x * v;
+
+        let x = abs(&(s.double() * den_x));
+        let y = u1 * den_y;
+        let t = x * y;
+
+        if !was_square || is_negative(&t) || y == Fp25519::zero() {
+            return None;
+        }
+        Some(Self(ExtendedPoint {
+            X: x,
+            Y: y,
+            T: t,
+            Z: one,
+        }))
+    }
+
+    /// The canonical 32 byte encoding, see RFC 9496 section 4.3.2.
+    pub fn encode(&self) -> [u8; 32] {
+        let ExtendedPoint {
+            X: x0,
+            Y: y0,
+            T: t0,
+            Z: z0,
+        } = self.0;
+
+        let u1 = (z0 + y0) * (z0 - y0);
+        let u2 = x0 * y0;
+        let (_, invsqrt) = sqrt_ratio_m1(&Fp25519::one(), &(u1 * u2.square()));
+
+        let den1 = invsqrt * u1;
+        let den2 = invsqrt * u2;
+        let z_inv = den1 * den2 * t0;
+
+        let (x, mut y, den_inv) = if is_negative(&(t0 * z_inv)) {
+            (y0 * SQRT_M1, x0 * SQRT_M1, den1 * INVSQRT_A_MINUS_D)
+        } else {
+            (x0, y0, den2)
+        };
+        if is_negative(&(x * z_inv)) {
+            y = -y;
+        }
+        to_bytes(&abs(&(den_inv * (z0 - y))))
+    }
+
+    /// The one-way map from 64 uniformly random bytes to the group, see RFC 9496 section 4.3.4.
+    ///
+    /// Together with a hash function with a 64 byte output this gives a hash to the group.
+    pub fn from_uniform_bytes(bytes: &[u8; 64]) -> Self {
+        let mut point = Self::map(&from_bytes(&bytes[..32]));
+        Ed25519Operations::add_in_place(&mut point.0, &Self::map(&from_bytes(&bytes[32..])).0);
+        point
+    }
+
+    /// The Elligator map of RFC 9496 section 4.3.4.
+    fn map(t: &Fp25519) -> Self {
+        let one = Fp25519::one();
+        let d = Ed25519Parameters::D;
+
+        let r = SQRT_M1 * t.square();
+        let u = (r + one) * ONE_MINUS_D_SQ;
+        let v = (-one - r * d) * (r + d);
+
+        let (was_square, mut s) = sqrt_ratio_m1(&u, &v);
+        let c = if was_square {
+            -one
+        } else {
+            s = -abs(&(s * t));
+            r
+        };
+
+        let n = c * (r - one) * D_MINUS_ONE_SQ - v;
+
+        let w0 = s.double() * v;
+        let w1 = n * SQRT_AD_MINUS_ONE;
+        let w2 = one - s.square();
+        let w3 = one + s.square();
+
+        Self(ExtendedPoint {
+            X: w0 * w3,
+            Y: w2 * w1,
+            T: w0 * w2,
+            Z: w1 * w3,
+        })
+    }
+}
+
+/// Two representatives are equal if they differ by a point of order dividing 4,
+/// see RFC 9496 section 4.3.3.
+impl PartialEq for RistrettoPoint {
+    fn eq(&self, other: &Self) -> bool {
+        let (p, q) = (&self.0, &other.0);
+        p.X * q.Y == p.Y * q.X || p.Y * q.Y == p.X * q.X
+    }
+}
+
+impl Eq for RistrettoPoint {}
+
+impl Hash for RistrettoPoint {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.encode().hash(state);
+    }
+}
+
+/// The encoding of the element in hexadecimal.
+impl Display for RistrettoPoint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        Bytes::fmt_hex(self.encode(), f)
+    }
+}
+
+impl From<Affine<Fp25519>> for RistrettoPoint {
+    fn from(affine: Affine<Fp25519>) -> Self {
+        Self(affine.into())
+    }
+}
+
+impl Coordinates for RistrettoPoint {
+    type Field = Fp25519;
+    type Affine = Affine<Fp25519>;
+
+    fn into_affine(&self) -> Option<Self::Affine> {
+        self.0.into_affine()
+    }
+}
+
+impl CurveOperations for Ristretto255 {
+    type Field = Fp25519;
+    type Affine = Affine<Fp25519>;
+    type Point = RistrettoPoint;
+    type Cached = <Ed25519Operations as CurveOperations>::Cached;
+
+    const UNIFIED: bool = true;
+
+    fn identity() -> Self::Point {
+        RistrettoPoint(Ed25519Operations::identity())
+    }
+
+    fn is_on_curve(point: &Self::Affine) -> bool {
+        Ed25519Operations::is_on_curve(point)
+    }
+
+    fn neg_in_place(point: &mut Self::Point) {
+        Ed25519Operations::neg_in_place(&mut point.0);
+    }
+
+    fn add_in_place(lhs: &mut Self::Point, rhs: &Self::Point) {
+        Ed25519Operations::add_in_place(&mut lhs.0, &rhs.0);
+    }
+
+    fn add_affine_in_place(lhs: &mut Self::Point, rhs: &Self::Affine) {
+        Ed25519Operations::add_affine_in_place(&mut lhs.0, rhs);
+    }
+
+    fn double_in_place(point: &mut Self::Point) {
+        Ed25519Operations::double_in_place(&mut point.0);
+    }
+
+    fn double_n_in_place(point: &mut Self::Point, n: usize) {
+        Ed25519Operations::double_n_in_place(&mut point.0, n);
+    }
+
+    fn to_cached(point: &Self::Point) -> Self::Cached {
+        Ed25519Operations::to_cached(&point.0)
+    }
+
+    fn add_cached_in_place(lhs: &mut Self::Point, rhs: &Self::Cached) {
+        Ed25519Operations::add_cached_in_place(&mut lhs.0, rhs);
+    }
+}
+
+impl PrimeGroupConfig for Ristretto255 {
+    type Public = RistrettoPoint;
+    type ScalarField = ScalarEd25519;
+
+    const NAME: &'static str = "ristretto255";
+
+    type Cofactor = [u64; 1];
+
+    // The group has prime order
+    const COFACTOR: Self::Cofactor = [1];
+
+    /// The image of the Ed25519 base point, or a random multiple of it.
+    fn generator<R: Rng>(rng: Option<&mut R>) -> Self::Public {
+        let base = <Ed25519Operations as PrimeSubGroupConfig>::generator::<R>(None);
+        let mut point = RistrettoPoint::from(base);
+        if let Some(rng) = rng {
+            let scalar = ScalarEd25519::rand(rng);
+            point = Self::scalar_mul(&point, &scalar);
+        }
+        point
+    }
+
+    /// Checks that the representative is on the curve and is killed by the group order
+    /// up to a point of order dividing 4.
+    fn is_valid(input: &Self::Public) -> bool {
+        let ExtendedPoint {
+            X: x,
+            Y: y,
+            T: t,
+            Z: z,
+        } = input.0;
+        let on_curve = y.square() - x.square() == z.square() + Ed25519Parameters::D * t.square()
+            && x * y == z * t
+            && z != Fp25519::zero();
+
+        on_curve && ScalarMul::wnaf::<Self>(input, &ScalarEd25519::MODULUS) == Self::identity()
+    }
+
+    fn as_public(input: &Self::Point) -> Option<Self::Public> {
+        Some(*input)
+    }
+
+    fn compress(input: &Self::Public) -> Vec<u8> {
+        input.encode().to_vec()
+    }
+
+    fn add_public_in_place(lhs: &mut Self::Point, rhs: &Self::Public) {
+        Self::add_in_place(lhs, rhs)
+    }
+
+    /// Maps the 64 byte digest to the group with the hash to group map of RFC 9496.
+    fn batch_generators_from_label(label: &[u8], n: usize) -> Vec<Self::Public> {
+        generators_from_label::<Self>(label, n, |digest| {
+            Some(RistrettoPoint::from_uniform_bytes(digest))
+        })
+    }
+
+    fn msm<I, J>(bases: I, scalars: J) -> Self::Point
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Self::Point>,
+        J: IntoIterator,
+        J::Item: Borrow<Self::ScalarField>,
+    {
+        VariableBaseMSM::msm_simple::<Self, _, _, _>(bases, scalars)
+    }
+
+    fn msm_pub<I, J>(bases: I, scalars: J) -> Self::Point
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Self::Public>,
+        J: IntoIterator,
+        J::Item: Borrow<Self::ScalarField>,
+    {
+        Self::msm(bases, scalars)
+    }
+}
+
+impl CurveEncoding for Ristretto255 {
+    /// Decodes the canonical 32 byte encoding, see `RistrettoPoint::decode`.
+    fn decompress(bytes: &[u8]) -> Option<Self::Public> {
+        RistrettoPoint::decode(bytes.try_into().ok()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::check_generators;
+    use cryp_std::rand::rngs::ThreadRng;
+    use cryp_std::rand::thread_rng;
+
+    fn from_hex<const N: usize>(hex: &str) -> [u8; N] {
+        let mut bytes = [0u8; N];
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&hex[2 * i..2 * i + 2], 16).unwrap();
+        }
+        bytes
+    }
+
+    /// The encodings of the small multiples of the generator of RFC 9496, appendix A.1
+    #[test]
+    fn test_multiples() {
+        use cryp_std::string::ToString;
+
+        let multiples = [
+            "0000000000000000000000000000000000000000000000000000000000000000",
+            "e2f2ae0a6abc4e71a884a961c500515f58e30b6aa582dd8db6a65945e08d2d76",
+            "6a493210f7499cd17fecb510ae0cea23a110e8d5b901f8acadd3095c73a3b919",
+            "94741f5d5d52755ece4f23f044ee27d5d1ea1e2bd196b462166b16152a9d0259",
+            "da80862773358b466ffadfe0b3293ab3d9fd53c5ea6c955358f568322daf6a57",
+            "e882b131016b52c1d3337080187cf768423efccbb517bb495ab812c4160ff44e",
+            "f64746d3c92b13050ed8d80236a7f0007c3b3f962f5ba793d19a601ebb1df403",
+            "44f53520926ec81fbd5a387845beb7df85a96a24ece18738bdcfa6a7822a176d",
+            "903293d8f2287ebe10e2374dc1a53e0bc887e592699f02d077d5263cdd55601c",
+            "02622ace8f7303a31cafc63f8fc48fdc16e1c8c8d234b2f0d6685282a9076031",
+            "20706fd788b2720a1ed2a5dad4952b01f413bcf0e7564de8cdc816689e2db95f",
+            "bce83f8ba5dd2fa572864c24ba1810f9522bc6004afe95877ac73241cafdab42",
+            "e4549ee16b9aa03099ca208c67adafcafa4c3f3e4e5303de6026e3ca8ff84460",
+            "aa52e000df2e16f55fb1032fc33bc42742dad6bd5a8fc0be0167436c5948501f",
+            "46376b80f409b29dc2b5f6f0c52591990896e5716f41477cd30085ab7f10301e",
+            "e0c418f7c8d9c4cdd7395b93ea124f3ad99021bb681dfc3302a9d99a2e53e64e",
+        ];
+
+        let g = GroupRistretto255::generator::<ThreadRng>(None);
+        let mut point = GroupRistretto255::identity();
+        for (i, hex) in multiples.iter().enumerate() {
+            let bytes = from_hex::<32>(hex);
+            let public = point.as_public().unwrap();
+            assert_eq!(public.point.encode(), bytes);
+            assert_eq!(public.to_string(), *hex);
+
+            let decoded = RistrettoPoint::decode(&bytes).unwrap();
+            assert_eq!(decoded, public.point);
+            assert_eq!(decoded.encode(), bytes);
+            assert_eq!(PublicRistretto255::decompress(&bytes), Some(public));
+            assert_eq!(GroupRistretto255::from(g).mul_int(&[i as u64]), point);
+            point += g;
+        }
+    }
+
+    /// Invalid encodings of RFC 9496, appendix A.2
+    #[test]
+    fn test_bad_encodings() {
+        let bad = [
+            // Non-canonical field encodings
+            "00ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+            "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f",
+            "f3ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f",
+            "edffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f",
+            // Negative field elements
+            "0100000000000000000000000000000000000000000000000000000000000000",
+            "01ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f",
+            "ed57ffd8c914fb201471d1c3d245ce3c746fcbe63a3679d51b6a516ebebe0e20",
+            "c34c4e1826e5d403b78e246e88aa051c36ccf0aafebffe137d148a2bf9104562",
+            "c940e5a4404157cfb1628b108db051a8d439e1a421394ec4ebccb9ec92a8ac78",
+            "47cfc5497c53dc8e61c91d17fd626ffb1c49e2bca94eed052281b510b1117a24",
+            "f1c6165d33367351b0da8f6e4511010c68174a03b6581212c71c0e1d026c3c72",
+            "87260f7a2f12495118360f02c26a470f450dadf34a413d21042b43b9d93e1309",
+            // Non-square x^2
+            "26948d35ca62e643e26a83177332e6b6afeb9d08e4268b650f1f5bbd8d81d371",
+            "4eac077a713c57b4f4397629a4145982c661f48044dd3f96427d40b147d9742f",
+            "de6a7b00deadc788eb6b6c8d20c0ae96c2f2019078fa604fee5b87d6e989ad7b",
+            "bcab477be20861e01e4a0e295284146a510150d9817763caf1a6f4b422d67042",
+            "2a292df7e32cababbd9de088d1d1abec9fc0440f637ed2fba145094dc14bea08",
+            "f4a9e534fc0d216c44b218fa0c42d99635a0127ee2e53c712f70609649fdff22",
+            "8268436f8c4126196cf64b3c7ddbda90746a378625f9813dd9b8457077256731",
+            "2810e5cbc2cc4d4eece54f61c6f69758e289aa7ab440b3cbeaa21995c2f4232b",
+            // Negative xy value
+            "3eb858e78f5a7254d8c9731174a94f76755fd3941c0ac93735c07ba14579630e",
+            "a45fdc55c76448c049a1ab33f17023edfb2be3581e9c7aade8a6125215e04220",
+            "d483fe813c6ba647ebbfd3ec41adca1c6130c2beeee9d9bf065c8d151c5f396e",
+            "8a2e1d30050198c65a54483123960ccc38aef6848e1ec8f5f780e8523769ba32",
+            "32888462f8b486c68ad7dd9610be5192bbeaf3b443951ac1a8118419d9fa097b",
+            "227142501b9d4355ccba290404bde41575b037693cef1f438c47f8fbf35d1165",
+            "5c37cc491da847cfeb9281d407efc41e15144c876e0170b499a96a22ed31e01e",
+            "445425117cb8c90edcbc7c1cc0e74f747f2c1efa5630a967c64f287792a48a4b",
+            // s = -1, which causes y = 0
+            "ecffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f",
+        ];
+
+        for hex in bad.iter() {
+            assert_eq!(RistrettoPoint::decode(&from_hex(hex)), None);
+        }
+    }
+
+    /// The one-way map test vectors of RFC 9496, appendix A.3
+    #[test]
+    fn test_from_uniform_bytes() {
+        let vectors = [
+            (
+                "5d1be09e3d0c82fc538112490e35701979d99e06ca3e2b5b54bffe8b4dc772c1\
+                 4d98b696a1bbfb5ca32c436cc61c16563790306c79eaca7705668b47dffe5bb6",
+                "3066f82a1a747d45120d1740f14358531a8f04bbffe6a819f86dfe50f44a0a46",
+            ),
+            (
+                "f116b34b8f17ceb56e8732a60d913dd10cce47a6d53bee9204be8b44f6678b27\
+                 0102a56902e2488c46120e9276cfe54638286b9e4b3cdb470b542d46c2068d38",
+                "f26e5b6f7d362d2d2a94c5d0e7602cb4773c95a2e5c31a64f133189fa76ed61b",
+            ),
+            (
+                "8422e1bbdaab52938b81fd602effb6f89110e1e57208ad12d9ad767e2e25510c\
+                 27140775f9337088b982d83d7fcf0b2fa1edffe51952cbe7365e95c86eaf325c",
+                "006ccd2a9e6867e6a2c5cea83d3302cc9de128dd2a9a57dd8ee7b9d7ffe02826",
+            ),
+            (
+                "ac22415129b61427bf464e17baee8db65940c233b98afce8d17c57beeb7876c2\
+                 150d15af1cb1fb824bbd14955f2b57d08d388aab431a391cfc33d5bafb5dbbaf",
+                "f8f0c87cf237953c5890aec3998169005dae3eca1fbb04548c635953c817f92a",
+            ),
+            (
+                "165d697a1ef3d5cf3c38565beefcf88c0f282b8e7dbd28544c483432f1cec767\
+                 5debea8ebb4e5fe7d6f6e5db15f15587ac4d4d4a1de7191e0c1ca6664abcc413",
+                "ae81e7dedf20a497e10c304a765c1767a42d6e06029758d2d7e8ef7cc4c41179",
+            ),
+            (
+                "a836e6c9a9ca9f1e8d486273ad56a78c70cf18f0ce10abb1c7172ddd605d7fd2\
+                 979854f47ae1ccf204a33102095b4200e5befc0465accc263175485f0e17ea5c",
+                "e2705652ff9f5e44d3e841bf1c251cf7dddb77d140870d1ab2ed64f1a9ce8628",
+            ),
+            (
+                "2cdc11eaeb95daf01189417cdddbf95952993aa9cb9c640eb5058d09702c7462\
+                 2c9965a697a3b345ec24ee56335b556e677b30e6f90ac77d781064f866a3c982",
+                "80bd07262511cdde4863f8a7434cef696750681cb9510eea557088f76d9e5065",
+            ),
+        ];
+
+        for (input, output) in vectors.iter() {
+            let point = RistrettoPoint::from_uniform_bytes(&from_hex(input));
+            assert_eq!(point.encode(), from_hex::<32>(output));
+            assert!(GroupRistretto255::is_valid(&PublicRistretto255::new(point)));
+        }
+    }
+
+    #[test]
+    fn test_equality() {
+        let g = GroupRistretto255::generator::<ThreadRng>(None);
+        let edwards = g.point.0;
+
+        // Adding a point of order 4 gives the same element of the group
+        let (zero, one) = (Fp25519::zero(), Fp25519::one());
+        let order_four = ExtendedPoint::from(Affine::new(SQRT_M1, zero));
+        let order_two = ExtendedPoint::from(Affine::new(zero, -one));
+        for torsion in [order_four, order_two] {
+            let mut other = edwards;
+            Ed25519Operations::add_in_place(&mut other, &torsion);
+            assert_ne!(other, edwards);
+            assert_eq!(RistrettoPoint(other), g.point);
+            assert_eq!(RistrettoPoint(other).encode(), g.point.encode());
+        }
+
+        // A point of order 8 changes the element
+        let mut other = edwards;
+        let order_eight = RistrettoPoint::from(Affine::new(
+            Fp25519::from_int(
+                &[
+                    16042180617441759562,
+                    6636401977974972984,
+                    16803943075630959291,
+                    2293943682165459496,
+                ]
+                .into(),
+            ),
+            Fp25519::from_int(
+                &[
+                    12693310623469398054,
+                    17336870590538367813,
+                    4121856693609750485,
+                    431311394219112625,
+                ]
+                .into(),
+            ),
+        ));
+        Ed25519Operations::add_in_place(&mut other, &order_eight.0);
+        assert_ne!(RistrettoPoint(other), g.point);
+        assert!(!GroupRistretto255::is_valid(&PublicRistretto255::new(
+            RistrettoPoint(other)
+        )));
+    }
+
+    #[test]
+    fn test_group() {
+        let mut rng = thread_rng();
+        let g = GroupRistretto255::from(GroupRistretto255::generator::<ThreadRng>(None));
+        let identity = GroupRistretto255::identity();
+        let order = ScalarEd25519::MODULUS;
+
+        assert_eq!(g + g, g.double());
+        assert_eq!(g + identity, g);
+        assert_eq!(g - g, identity);
+        assert_eq!(g.mul_int(&order), identity);
+        assert_eq!(g.mul_int_non_ct(&order), identity);
+
+        for _ in 0..5 {
+            let a = ScalarEd25519::rand(&mut rng);
+            let b = ScalarEd25519::rand(&mut rng);
+            assert_eq!(g * &a + g * &b, g * &(a + b));
+            assert_eq!(g.mul_int_non_ct(&a.as_int()), g * &a);
+
+            let public = (g * &a).as_public().unwrap();
+            assert!(GroupRistretto255::is_valid(&public));
+            let decoded = RistrettoPoint::decode(&public.point.encode()).unwrap();
+            assert_eq!(decoded, public.point);
+        }
+
+        let generators = GroupRistretto255::batch_generators(4, &mut rng);
+        let scalars = (0..4)
+            .map(|_| ScalarEd25519::rand(&mut rng))
+            .collect::<Vec<_>>();
+        let expected = generators
+            .iter()
+            .zip(scalars.iter())
+            .map(|(g, s)| g * s)
+            .sum::<GroupRistretto255>();
+        assert_eq!(GroupRistretto255::msm(&generators, &scalars), expected);
+        assert_eq!(
+            GroupRistretto255::msm_non_ct(&generators, &scalars),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_generators() {
+        check_generators::<GroupRistretto255>(
+            "7e849078940fcce1888ad654ae648f824c9382e90001e5c5d4df89e1fcc5121b",
+        );
+    }
+
+    #[test]
+    fn test_introspection() {
+        assert_eq!(GroupRistretto255::name(), "ristretto255");
+        assert_eq!(GroupRistretto255::cofactor(), [1]);
+        assert_eq!(GroupRistretto255::order(), ScalarEd25519::MODULUS);
+    }
+}
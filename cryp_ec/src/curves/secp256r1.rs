@@ -0,0 +1,444 @@
+use crate::hash_to_curve::*;
+use crate::models::CurveOperations;
+use crate::models::generators_from_label;
+use crate::weierstrass::*;
+use cryp_alg::ff::*;
+use cryp_std::rand::Rng;
+use cryp_std::vec::Vec;
+
+pub type Fp256 = F<MontgomeryOperations<4, Fp256Params>>;
+pub type ScalarP256 = F<MontgomeryOperations<4, ScalarP256Parameters>>;
+pub type GroupP256 = GroupEC<ShortWeierstrassCompleteOperations<P256Parameters>>;
+pub type AffineP256 = PublicEC<ShortWeierstrassCompleteOperations<P256Parameters>>;
+
+/// Parameters of the NIST curve P-256 (secp256r1), see FIPS 186-4 D.1.2.3.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct P256Parameters;
+
+/// Parameters for the prime field Fp256
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Fp256Params;
+
+impl P256Parameters {
+    // 48439561293906451759052585252797914202762949526041747995844080717082404635286
+    const X: [u64; 4] = [
+        17627433388654248598,
+        8575836109218198432,
+        17923454489921339634,
+        7716867327612699207,
+    ];
+
+    // 36134250956749795798585127919587881956611106672985015071877198253568414405109
+    const Y: [u64; 4] = [
+        14678990851816772085,
+        3156516839386865358,
+        10297457778147434006,
+        5756518291402817435,
+    ];
+}
+
+impl MontParameters<4usize> for Fp256Params {
+    type Limb = u64;
+
+    // 2^256 - 2^224 + 2^192 + 2^96 - 1
+    const MODULUS: [Self::Limb; 4] = [18446744073709551615, 4294967295, 0, 18446744069414584321];
+
+    const R: [Self::Limb; 4] = [1, 18446744069414584320, 18446744073709551615, 4294967294];
+
+    const R2: [Self::Limb; 4] = [3, 18446744056529682431, 18446744073709551614, 21474836477];
+
+    const MP: Self::Limb = 1;
+}
+
+impl ShortWeierstrass for P256Parameters {
+    type Field = Fp256;
+
+    // The element a = -3 in Montgomery form
+    const A: Self::Field = Fp256::from_RAW_limbs(<Fp256 as PrimeField>::BigInteger::from_limbs([
+        18446744073709551612,
+        17179869183,
+        0,
+        18446744056529682436,
+    ]));
+
+    // The element b in Montgomery form
+    // b = 41058363725152142129326129780047268409114441015993725554835256314039467401291
+    const B: Self::Field = Fp256::from_RAW_limbs(<Fp256 as PrimeField>::BigInteger::from_limbs([
+        15608596021259845087,
+        12461466548982526096,
+        16546823903870267094,
+        15866188208926050356,
+    ]));
+
+    const A_KIND: CoefficientKind = CoefficientKind::MinusThree;
+}
+
+// The scalar Field
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ScalarP256Parameters;
+
+impl MontParameters<4usize> for ScalarP256Parameters {
+    type Limb = u64;
+
+    // 115792089210356248762697446949407573529996955224135760342422259061068512044369
+    const MODULUS: [Self::Limb; 4] = [
+        17562291160714782033,
+        13611842547513532036,
+        18446744073709551615,
+        18446744069414584320,
+    ];
+
+    const R: [Self::Limb; 4] = [884452912994769583, 4834901526196019579, 0, 4294967295];
+
+    const R2: [Self::Limb; 4] = [
+        9449762124159643298,
+        5087230966250696614,
+        2901921493521525849,
+        7413256579398063648,
+    ];
+
+    const MP: Self::Limb = 14758798090332847183;
+}
+
+impl PrimeSubGroupConfig for ShortWeierstrassCompleteOperations<P256Parameters> {
+    type ScalarField = ScalarP256;
+
+    const NAME: &'static str = "P-256";
+
+    type Cofactor = [u64; 1];
+
+    const COFACTOR: Self::Cofactor = [1];
+
+    fn generator<R: Rng>(rng: Option<&mut R>) -> Self::Affine {
+        let x = Fp256::from_int(&P256Parameters::X.into());
+        let y = Fp256::from_int(&P256Parameters::Y.into());
+        let affine_point = Self::Affine::new(x, y);
+
+        let mut point = Self::Point::from(affine_point);
+        if let Some(rng) = rng {
+            let scalar = ScalarP256::rand(rng);
+            point = <Self as PrimeSubGroupConfig>::scalar_mul(&point, &scalar);
+        }
+        point.into_affine().unwrap()
+    }
+
+    // The curve has prime order, so every point on it is in the group.
+    fn is_in_subgroup(_point: &Self::Affine) -> bool {
+        true
+    }
+
+    fn compress(input: &Self::Affine) -> Vec<u8> {
+        sec1_compress(input)
+    }
+
+    /// Uses the first 32 bytes of the digest as `x` and the next bit as the parity of `y`.
+    fn batch_generators_from_label(label: &[u8], n: usize) -> Vec<Self::Affine> {
+        generators_from_label::<Self>(label, n, |digest| {
+            let mut bytes = [0u8; 33];
+            bytes[0] = 0x02 | (digest[32] & 1);
+            bytes[1..].copy_from_slice(&digest[..32]);
+            sec1_decompress::<P256Parameters>(&bytes).map(Into::into)
+        })
+    }
+
+    /// The suite `P256_XMD:SHA-256_SSWU_RO_` of RFC 9380.
+    fn hash_to_curve(msg: &[u8], dst: &[u8]) -> Option<Self::Point> {
+        Some(hash_to_curve::<Self>(msg, dst))
+    }
+
+    /// The suite `P256_XMD:SHA-256_SSWU_NU_` of RFC 9380.
+    fn encode_to_curve(msg: &[u8], dst: &[u8]) -> Option<Self::Point> {
+        Some(encode_to_curve::<Self>(msg, dst))
+    }
+}
+
+impl SSWUMap for P256Parameters {
+    const ISO_A: Self::Field = Self::A;
+    const ISO_B: Self::Field = Self::B;
+
+    // The element Z = -10 in Montgomery form
+    const Z: Self::Field = Fp256::from_RAW_limbs(<Fp256 as PrimeField>::BigInteger::from_limbs([
+        18446744073709551605,
+        47244640255,
+        0,
+        18446744026464911371,
+    ]));
+}
+
+impl HashToCurveConfig for ShortWeierstrassCompleteOperations<P256Parameters> {
+    const L: usize = 48;
+
+    fn expand_message(msg: &[u8], dst: &[u8], len_in_bytes: usize) -> Vec<u8> {
+        expand_message_xmd::<Sha256>(msg, dst, len_in_bytes)
+    }
+
+    fn map_to_curve(u: &Fp256) -> Self::Point {
+        map_to_curve_sswu::<P256Parameters>(u).map_or_else(Self::identity, Into::into)
+    }
+}
+
+impl CurveEncoding for ShortWeierstrassCompleteOperations<P256Parameters> {
+    /// Decodes a compressed or uncompressed SEC1 encoding.
+    fn decompress(bytes: &[u8]) -> Option<Self::Affine> {
+        sec1_decompress::<P256Parameters>(bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::check_generators;
+    use cryp_std::rand::rngs::ThreadRng;
+    use cryp_std::rand::thread_rng;
+    use cryp_std::rand::UniformRand;
+
+    /// Parses a big endian hexadecimal string of 64 characters.
+    fn from_hex(hex: &str) -> [u64; 4] {
+        let mut limbs = [0u64; 4];
+        for (i, limb) in limbs.iter_mut().enumerate() {
+            let start = 48 - 16 * i;
+            *limb = u64::from_str_radix(&hex[start..start + 16], 16).unwrap();
+        }
+        limbs
+    }
+
+    fn affine(x: &str, y: &str) -> Affine<Fp256> {
+        Affine::new(
+            Fp256::from_int(&from_hex(x).into()),
+            Fp256::from_int(&from_hex(y).into()),
+        )
+    }
+
+    #[test]
+    fn test_parameters() {
+        let three = Fp256::from_int(&[3, 0, 0, 0].into());
+        assert_eq!(P256Parameters::A, -three);
+
+        let b = from_hex("5ac635d8aa3a93e7b3ebbd55769886bc651d06b0cc53b0f63bce3c3e27d2604b");
+        assert_eq!(P256Parameters::B, Fp256::from_int(&b.into()));
+
+        let modulus_minus_one = Fp256::zero() - Fp256::one();
+        assert_eq!(modulus_minus_one + Fp256::one(), Fp256::zero());
+
+        let mut rng = thread_rng();
+        let x = Fp256::rand(&mut rng);
+        assert_eq!(x * x.inverse().unwrap(), Fp256::one());
+        assert_eq!(x.exp(&modulus_minus_one.as_int()), Fp256::one());
+
+        let s = ScalarP256::rand(&mut rng);
+        assert_eq!(s * s.inverse().unwrap(), ScalarP256::one());
+    }
+
+    #[test]
+    fn test_group() {
+        let g = GroupP256::generator::<ThreadRng>(None);
+        let (x, y) = (g.point.x, g.point.y);
+
+        // check y^2 = x^3 - 3x + b
+        assert_eq!(
+            y.square(),
+            x.square() * x + P256Parameters::A * x + P256Parameters::B
+        );
+        assert!(GroupP256::is_valid(&g));
+
+        let mut off_curve = g;
+        off_curve.point.y += Fp256::one();
+        assert!(!GroupP256::is_valid(&off_curve));
+
+        let point = GroupP256::from(g);
+        let identity = GroupP256::identity();
+        let order = ScalarP256::MODULUS;
+
+        assert_eq!(point + point, point.double());
+        assert_eq!(identity + point, point);
+        assert_eq!(point + (-point), identity);
+        assert_eq!(point.mul_int(&order), identity);
+        assert_eq!(point.mul_int_non_ct(&order), identity);
+
+        let mut rng = thread_rng();
+        for _ in 0..5 {
+            let a = ScalarP256::rand(&mut rng);
+            let b = ScalarP256::rand(&mut rng);
+            assert_eq!(point * &a + point * &b, point * &(a + b));
+            assert_eq!(point.mul_int_non_ct(&a.as_int()), point * &a);
+        }
+    }
+
+    /// Test vectors for point multiplication on P-256 from the NIST curve test vectors
+    /// collected at http://point-at-infinity.org/ecc/nisttv
+    #[test]
+    fn test_scalar_mul_vectors() {
+        let g = GroupP256::from(GroupP256::generator::<ThreadRng>(None));
+
+        let vectors: [([u64; 4], &str, &str); 5] = [
+            (
+                [1, 0, 0, 0],
+                "6B17D1F2E12C4247F8BCE6E563A440F277037D812DEB33A0F4A13945D898C296",
+                "4FE342E2FE1A7F9B8EE7EB4A7C0F9E162BCE33576B315ECECBB6406837BF51F5",
+            ),
+            (
+                [2, 0, 0, 0],
+                "7CF27B188D034F7E8A52380304B51AC3C08969E277F21B35A60B48FC47669978",
+                "07775510DB8ED040293D9AC69F7430DBBA7DADE63CE982299E04B79D227873D1",
+            ),
+            (
+                [3, 0, 0, 0],
+                "5ECBE4D1A6330A44C8F7EF951D4BF165E6C6B721EFADA985FB41661BC6E7FD6C",
+                "8734640C4998FF7E374B06CE1A64A2ECD82AB036384FB83D9A79B127A27D5032",
+            ),
+            (
+                [112233445566778899, 0, 0, 0],
+                "339150844EC15234807FE862A86BE77977DBFB3AE3D96F4C22795513AEAAB82F",
+                "B1C14DDFDC8EC1B2583F51E85A5EB3A155840F2034730E9B5ADA38B674336A21",
+            ),
+            (
+                // 29852220098221261079183923314599206100666902414330245206392788703677545185283
+                from_hex("41FFC1FFFFFE01FFFC0003FFFE0007C001FFF00003FFF07FFE0007C000000003"),
+                "9EACE8F4B071E677C5350B02F2BB2B384AAE89D58AA72CA97A170572E0FB222F",
+                "1BBDAEC2430B09B93F7CB08678636CE12EAAFD58390699B5FD2F6E1188FC2A78",
+            ),
+        ];
+
+        for (k, x, y) in vectors.iter() {
+            let expected = affine(x, y);
+            assert_eq!(g.mul_int(k).as_public().unwrap().point, expected);
+            assert_eq!(g.mul_int_non_ct(k).as_public().unwrap().point, expected);
+            let scalar = ScalarP256::from_int(&(*k).into());
+            assert_eq!((g * &scalar).as_public().unwrap().point, expected);
+        }
+
+        // (n - 1)G = -G
+        let minus_one = -ScalarP256::one();
+        assert_eq!(
+            (g * &minus_one).as_public().unwrap().point,
+            affine(
+                "6B17D1F2E12C4247F8BCE6E563A440F277037D812DEB33A0F4A13945D898C296",
+                "B01CBD1C01E58065711814B583F061E9D431CCA994CEA1313449BF97C840AE0A",
+            )
+        );
+    }
+
+    #[test]
+    fn test_generators() {
+        check_generators::<GroupP256>(
+            "03a478d77100ed819ef5fbff3c4946613f617da8cae8a3db33b17448c87ea6f70c",
+        );
+    }
+
+    /// The test vectors of RFC 9380, appendix J.1, for the suites
+    /// P256_XMD:SHA-256_SSWU_RO_ and P256_XMD:SHA-256_SSWU_NU_
+    #[test]
+    fn test_hash_to_curve() {
+        let q128 = [b"q128_".as_slice(), &[b'q'; 128]].concat();
+        let a512 = [b"a512_".as_slice(), &[b'a'; 512]].concat();
+        let msgs: [&[u8]; 5] = [b"", b"abc", b"abcdef0123456789", &q128, &a512];
+
+        let dst = b"QUUX-V01-CS02-with-P256_XMD:SHA-256_SSWU_RO_";
+        let expected = [
+            (
+                "2c15230b26dbc6fc9a37051158c95b79656e17a1a920b11394ca91c44247d3e4",
+                "8a7a74985cc5c776cdfe4b1f19884970453912e9d31528c060be9ab5c43e8415",
+            ),
+            (
+                "0bb8b87485551aa43ed54f009230450b492fead5f1cc91658775dac4a3388a0f",
+                "5c41b3d0731a27a7b14bc0bf0ccded2d8751f83493404c84a88e71ffd424212e",
+            ),
+            (
+                "65038ac8f2b1def042a5df0b33b1f4eca6bff7cb0f9c6c1526811864e544ed80",
+                "cad44d40a656e7aff4002a8de287abc8ae0482b5ae825822bb870d6df9b56ca3",
+            ),
+            (
+                "4be61ee205094282ba8a2042bcb48d88dfbb609301c49aa8b078533dc65a0b5d",
+                "98f8df449a072c4721d241a3b1236d3caccba603f916ca680f4539d2bfb3c29e",
+            ),
+            (
+                "457ae2981f70ca85d8e24c308b14db22f3e3862c5ea0f652ca38b5e49cd64bc5",
+                "ecb9f0eadc9aeed232dabc53235368c1394c78de05dd96893eefa62b0f4757dc",
+            ),
+        ];
+        for (msg, (x, y)) in msgs.iter().zip(expected) {
+            let point = GroupP256::hash_to_curve(msg, dst).unwrap();
+            assert_eq!(point.as_public().unwrap().point, affine(x, y));
+        }
+
+        let dst = b"QUUX-V01-CS02-with-P256_XMD:SHA-256_SSWU_NU_";
+        let expected = [
+            (
+                "f871caad25ea3b59c16cf87c1894902f7e7b2c822c3d3f73596c5ace8ddd14d1",
+                "87b9ae23335bee057b99bac1e68588b18b5691af476234b8971bc4f011ddc99b",
+            ),
+            (
+                "fc3f5d734e8dce41ddac49f47dd2b8a57257522a865c124ed02b92b5237befa4",
+                "fe4d197ecf5a62645b9690599e1d80e82c500b22ac705a0b421fac7b47157866",
+            ),
+        ];
+        for (msg, (x, y)) in msgs.iter().zip(expected) {
+            let point = GroupP256::encode_to_curve(msg, dst).unwrap();
+            assert_eq!(point.as_public().unwrap().point, affine(x, y));
+        }
+    }
+
+    #[test]
+    fn test_introspection() {
+        use cryp_std::string::ToString;
+
+        assert_eq!(GroupP256::name(), "P-256");
+        assert_eq!(GroupP256::cofactor(), [1]);
+        assert_eq!(GroupP256::order(), ScalarP256::MODULUS);
+
+        // y of the base point is odd
+        let g = GroupP256::generator::<ThreadRng>(None);
+        assert_eq!(
+            g.to_string(),
+            "036b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296"
+        );
+    }
+
+    #[test]
+    fn test_encoding() {
+        fn from_hex(hex: &str) -> Vec<u8> {
+            (0..hex.len())
+                .step_by(2)
+                .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+                .collect()
+        }
+
+        let g = GroupP256::generator::<ThreadRng>(None);
+        let uncompressed = from_hex(
+            "046b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296\
+             4fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5",
+        );
+        assert_eq!(sec1_uncompressed(&g.point), uncompressed);
+        assert_eq!(AffineP256::decompress(&uncompressed), Some(g));
+        assert_eq!(AffineP256::decompress(&g.compress()), Some(g));
+
+        let mut rng = thread_rng();
+        for point in GroupP256::batch_generators(20, &mut rng) {
+            let negative = (-GroupP256::from(point)).as_public().unwrap();
+            for point in [point, negative] {
+                assert_eq!(point.compress().len(), 33);
+                assert_eq!(AffineP256::decompress(&point.compress()), Some(point));
+                let uncompressed = sec1_uncompressed(&point.point);
+                assert_eq!(AffineP256::decompress(&uncompressed), Some(point));
+            }
+        }
+
+        let mut off_curve = uncompressed.clone();
+        off_curve[64] ^= 1;
+        let invalid = [
+            // the point at infinity
+            "00",
+            // wrong prefixes and lengths
+            "056b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296",
+            "036b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c2",
+            "",
+            // x = p is non-canonical
+            "02ffffffff00000001000000000000000000000000ffffffffffffffffffffffff",
+            // there is no point with x = 1
+            "020000000000000000000000000000000000000000000000000000000000000001",
+        ];
+        for bytes in invalid.iter().map(|hex| from_hex(hex)).chain([off_curve]) {
+            assert_eq!(AffineP256::decompress(&bytes), None);
+        }
+    }
+}
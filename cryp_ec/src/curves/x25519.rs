@@ -0,0 +1,507 @@
+use super::ed25519::{Ed25519Parameters, Fp25519};
+use crate::hash_to_curve::Elligator2Map;
+use crate::models::CoefficientKind;
+use crate::montgomery::*;
+use cryp_alg::ff::*;
+
+/// Parameters of Curve25519, the Montgomery curve `y^2 = x^3 + 486662x^2 + x` birationally
+/// equivalent to the Edwards curve of Ed25519, see RFC 7748 section 4.1.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Curve25519Parameters;
+
+/// The `u`-coordinate of the base point of Curve25519, `u = 9`, in its 32 byte encoding.
+pub const X25519_BASEPOINT: [u8; 32] = [
+    9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+];
+
+impl MontgomeryCurve for Curve25519Parameters {
+    type Field = Fp25519;
+
+    // The element A = 486662 in the regular representation
+    const A: Self::Field =
+        Fp25519::from_RAW_limbs(<Fp25519 as PrimeField>::BigInteger::from_limbs([
+            486662, 0, 0, 0,
+        ]));
+
+    // The element B = 1 in the regular representation
+    const B: Self::Field =
+        Fp25519::from_RAW_limbs(<Fp25519 as PrimeField>::BigInteger::from_limbs([
+            1, 0, 0, 0,
+        ]));
+
+    // The element (A - 2) / 4 = 121665 in the regular representation
+    const A24: Self::Field =
+        Fp25519::from_RAW_limbs(<Fp25519 as PrimeField>::BigInteger::from_limbs([
+            121665, 0, 0, 0,
+        ]));
+}
+
+impl MontgomeryEdwardsMap for Curve25519Parameters {
+    type Edwards = Ed25519Parameters;
+
+    // The square root of -486664 of RFC 7748, section 4.1, in the regular representation
+    // 51042569399160536130206135233146329284152202253034631822681833788666877215207
+    const SCALE: Self::Field =
+        Fp25519::from_RAW_limbs(<Fp25519 as PrimeField>::BigInteger::from_limbs([
+            3716027510060384743,
+            4205847681119217021,
+            3280018162556579969,
+            8131550443321948484,
+        ]));
+}
+
+impl Elligator2Map for Curve25519Parameters {
+    // The element Z = 2 in the regular representation
+    const Z: Self::Field =
+        Fp25519::from_RAW_limbs(<Fp25519 as PrimeField>::BigInteger::from_limbs([2, 0, 0, 0]));
+}
+
+impl MontgomeryWeierstrassMap for Curve25519Parameters {
+    type Weierstrass = Wei25519Parameters;
+}
+
+/// Parameters of Wei25519, the short Weierstrass curve isomorphic to Curve25519.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Wei25519Parameters;
+
+impl ShortWeierstrass for Wei25519Parameters {
+    type Field = Fp25519;
+
+    // The element a = (3 - A^2) / 3 in the regular representation
+    // 19298681539552699237261830834781317975544997444273427339909597334573241639236
+    const A: Self::Field =
+        Fp25519::from_RAW_limbs(<Fp25519 as PrimeField>::BigInteger::from_limbs([
+            12297829303526400324,
+            12297829382473034410,
+            12297829382473034410,
+            3074457345618258602,
+        ]));
+
+    // The element b = (2A^3 - 9A) / 27 in the regular representation
+    // 55751746669818908907645289078257140818241103727901012315294400837956729358436
+    const B: Self::Field =
+        Fp25519::from_RAW_limbs(<Fp25519 as PrimeField>::BigInteger::from_limbs([
+            2741388824290576484,
+            17080318586768103348,
+            683212743470724133,
+            8881765665119413741,
+        ]));
+
+    const A_KIND: CoefficientKind = CoefficientKind::General;
+}
+
+/// The X25519 function of RFC 7748, section 5.
+///
+/// The scalar is clamped: the three least significant bits and the most significant bit are
+/// cleared and bit 254 is set. The most significant bit of `u` is ignored and non-canonical
+/// values of `u` are reduced modulo `2^255 - 19`. The result is the `u`-coordinate of the
+/// product, encoded in 32 bytes little endian.
+pub fn x25519(scalar: &[u8; 32], u: &[u8; 32]) -> [u8; 32] {
+    let mut k = *scalar;
+    k[0] &= 248;
+    k[31] &= 127;
+    k[31] |= 64;
+
+    let mut u = *u;
+    u[31] &= 127;
+
+    let u = Fp25519::from_int(&limbs_from_le_bytes(&u).into());
+    let result = Curve25519Parameters::ladder(&u, &limbs_from_le_bytes(&k));
+
+    let mut bytes = [0u8; 32];
+    for (chunk, limb) in bytes.chunks_mut(8).zip(result.as_int().limbs.iter()) {
+        chunk.copy_from_slice(&limb.to_le_bytes());
+    }
+    bytes
+}
+
+fn limbs_from_le_bytes(bytes: &[u8; 32]) -> [u64; 4] {
+    let mut limbs = [0u64; 4];
+    for (limb, chunk) in limbs.iter_mut().zip(bytes.chunks(8)) {
+        *limb = u64::from_le_bytes(chunk.try_into().unwrap());
+    }
+    limbs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::curves::edwards25519::{GroupEd25519, ScalarEd25519};
+    use crate::edwards::EdwardsAM1UnifiedOperations;
+    use crate::hash_to_curve::{hash_to_field, map_to_curve_elligator2_montgomery};
+    use crate::models::{CurveOperations, PrimeSubGroupConfig, ShortWeierstrassCompleteOperations};
+    use crate::weierstrass::{Coordinates, Group, PrimeGroup, Projective};
+    use cryp_std::rand::rngs::ThreadRng;
+    use cryp_std::rand::{thread_rng, Rng};
+    use cryp_std::vec::Vec;
+
+    fn from_hex(hex: &str) -> [u8; 32] {
+        let mut bytes = [0u8; 32];
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&hex[2 * i..2 * i + 2], 16).unwrap();
+        }
+        bytes
+    }
+
+    #[test]
+    fn test_parameters() {
+        assert!(Curve25519Parameters::verify());
+        assert_eq!(Curve25519Parameters::B, Fp25519::one());
+        assert_eq!(
+            Curve25519Parameters::A,
+            Fp25519::from_int(&[486662, 0, 0, 0].into())
+        );
+    }
+
+    /// Test vectors of RFC 7748, section 5.2
+    #[test]
+    fn test_vectors() {
+        let vectors = [
+            (
+                "a546e36bf0527c9d3b16154b82465edd62144c0ac1fc5a18506a2244ba449ac4",
+                "e6db6867583030db3594c1a424b15f7c726624ec26b3353b10a903a6d0ab1c4c",
+                "c3da55379de9c6908e94ea4df28d084f32eccf03491c71f754b4075577a28552",
+            ),
+            (
+                "4b66e9d4d1b4673c5ad22691957d6af5c11b6421e0ea01d42ca4169e7918ba0d",
+                "e5210f12786811d3f4b7959d0538ae2c31dbe7106fc03c3efc4cd549c715a493",
+                "95cbde9476e8907d7aade45cb4b873f88b595a68799fa152e6f8f7647aac7957",
+            ),
+        ];
+
+        for (scalar, u, expected) in vectors.iter() {
+            assert_eq!(x25519(&from_hex(scalar), &from_hex(u)), from_hex(expected));
+        }
+    }
+
+    /// The iterated test of RFC 7748, section 5.2, for 1 and 1,000 iterations
+    #[test]
+    fn test_iterated() {
+        let mut k = X25519_BASEPOINT;
+        let mut u = X25519_BASEPOINT;
+
+        for i in 1..=1000 {
+            let result = x25519(&k, &u);
+            u = k;
+            k = result;
+
+            if i == 1 {
+                assert_eq!(
+                    k,
+                    from_hex("422c8e7a6227d7bca1350b3e2bb7279f7897b87bb6854b783c60e80311ae3079")
+                );
+            }
+        }
+        assert_eq!(
+            k,
+            from_hex("684cf59ba83309552800ef566f2f4d3c1c3887c49360e3875f2eb94d99532c51")
+        );
+    }
+
+    /// The Diffie-Hellman test of RFC 7748, section 6.1
+    #[test]
+    fn test_diffie_hellman() {
+        let alice = from_hex("77076d0a7318a57d3c16c17251b26645df4c2f87ebc0992ab177fba51db92c2a");
+        let bob = from_hex("5dab087e624a8a4b79e17f8b83800ee66f3bb1292618b6fd1c2f8b27ff88e0eb");
+
+        let alice_public = x25519(&alice, &X25519_BASEPOINT);
+        let bob_public = x25519(&bob, &X25519_BASEPOINT);
+        assert_eq!(
+            alice_public,
+            from_hex("8520f0098930a754748b7ddcb43ef75a0dbf3a0d26381af4eba4a98eaa9b4e6a")
+        );
+        assert_eq!(
+            bob_public,
+            from_hex("de9edb7d7b7dc1b4d35b61c2ece435373f8343c85b78674dadfc7e146f882b4f")
+        );
+
+        let shared = from_hex("4a5d9d5ba4ce2de1728e3bf480350f25e07e21c947d19e3376f09b3c1e161742");
+        assert_eq!(x25519(&alice, &bob_public), shared);
+        assert_eq!(x25519(&bob, &alice_public), shared);
+    }
+
+    #[test]
+    fn test_non_canonical_input() {
+        let alice = from_hex("77076d0a7318a57d3c16c17251b26645df4c2f87ebc0992ab177fba51db92c2a");
+        let expected = x25519(&alice, &X25519_BASEPOINT);
+
+        // p + 9 is reduced to 9
+        let u = from_hex("f6ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f");
+        assert_eq!(x25519(&alice, &u), expected);
+
+        // the most significant bit is ignored
+        let mut u = X25519_BASEPOINT;
+        u[31] |= 0x80;
+        assert_eq!(x25519(&alice, &u), expected);
+
+        // the point (0, 0) of order 2 is sent to zero
+        assert_eq!(x25519(&alice, &[0u8; 32]), [0u8; 32]);
+    }
+
+    #[test]
+    fn test_map_parameters() {
+        let one = Fp25519::one();
+        let (a, d) = (-one, Ed25519Parameters::D);
+        let (big_a, big_b) = (Curve25519Parameters::A, Curve25519Parameters::B);
+
+        assert_eq!(big_a, (a + d).double() / (a - d));
+        assert_eq!(
+            big_b * Curve25519Parameters::SCALE.square(),
+            one.double().double() / (a - d)
+        );
+
+        let three = one.double() + one;
+        assert_eq!(
+            Wei25519Parameters::A,
+            (three - big_a.square()) / (three * big_b.square())
+        );
+        assert_eq!(
+            Wei25519Parameters::B,
+            (big_a.square() * big_a.double() - three.square() * big_a)
+                / (three.square() * three * big_b.square() * big_b)
+        );
+    }
+
+    #[test]
+    fn test_edwards_montgomery() {
+        // The base point of Ed25519 is sent to the base point of Curve25519, RFC 7748 section 4.1
+        let generator = GroupEd25519::generator::<ThreadRng>(None);
+        let g = generator.point;
+        // v = 14781619447589544791020593568409986887264606134616475288964881837755586237401
+        let v = [
+            3020162326831485913,
+            10537663908514914738,
+            16149588496846737740,
+            2354847837539305140,
+        ];
+        let expected = Affine::new(
+            Fp25519::from_int(&[9, 0, 0, 0].into()),
+            Fp25519::from_int(&v.into()),
+        );
+        assert_eq!(
+            edwards_to_montgomery::<Curve25519Parameters>(&g),
+            Some(expected)
+        );
+        assert_eq!(
+            montgomery_to_edwards::<Curve25519Parameters>(Some(expected)),
+            Some(g)
+        );
+
+        // The exceptional points
+        let (zero, one) = (Fp25519::zero(), Fp25519::one());
+        let identity = Affine::new(zero, one);
+        let order_two = Affine::new(zero, -one);
+        assert_eq!(
+            edwards_to_montgomery::<Curve25519Parameters>(&identity),
+            None
+        );
+        assert_eq!(
+            montgomery_to_edwards::<Curve25519Parameters>(None),
+            Some(identity)
+        );
+        assert_eq!(
+            edwards_to_montgomery::<Curve25519Parameters>(&order_two),
+            Some(Affine::new(zero, zero))
+        );
+        assert_eq!(
+            montgomery_to_edwards::<Curve25519Parameters>(Some(Affine::new(zero, zero))),
+            Some(order_two)
+        );
+
+        let mut rng = thread_rng();
+        let g = GroupEd25519::from(generator);
+        for _ in 0..10 {
+            let p = (g * &ScalarEd25519::rand(&mut rng))
+                .as_public()
+                .unwrap()
+                .point;
+            let image = edwards_to_montgomery::<Curve25519Parameters>(&p).unwrap();
+
+            // the image is on the curve and the map can be inverted
+            assert_eq!(
+                image.y.square(),
+                image.x.square() * image.x + Curve25519Parameters::A * image.x.square() + image.x
+            );
+            assert_eq!(
+                montgomery_to_edwards::<Curve25519Parameters>(Some(image)),
+                Some(p)
+            );
+        }
+    }
+
+    fn affine(x: &str, y: &str) -> Affine<Fp25519> {
+        let (mut x, mut y) = (from_hex(x), from_hex(y));
+        x.reverse();
+        y.reverse();
+        Affine::new(
+            Fp25519::from_int(&limbs_from_le_bytes(&x).into()),
+            Fp25519::from_int(&limbs_from_le_bytes(&y).into()),
+        )
+    }
+
+    /// Sends points of Curve25519 to the prime order subgroup through the Edwards curve
+    fn clear_cofactor(points: &[Affine<Fp25519>]) -> Affine<Fp25519> {
+        type Ops = EdwardsAM1UnifiedOperations<Ed25519Parameters>;
+        let mut sum = Ops::identity();
+        for point in points {
+            let image = montgomery_to_edwards::<Curve25519Parameters>(Some(*point)).unwrap();
+            Ops::add_in_place(&mut sum, &image.into());
+        }
+        let sum = Ops::clear_cofactor(&sum).into_affine().unwrap();
+        edwards_to_montgomery::<Curve25519Parameters>(&sum).unwrap()
+    }
+
+    /// The test vectors of RFC 9380, appendix J.7, for the suites
+    /// curve25519_XMD:SHA-512_ELL2_RO_ and curve25519_XMD:SHA-512_ELL2_NU_
+    #[test]
+    fn test_hash_to_curve() {
+        type Ops = EdwardsAM1UnifiedOperations<Ed25519Parameters>;
+        let q128 = [b"q128_".as_slice(), &[b'q'; 128]].concat();
+        let a512 = [b"a512_".as_slice(), &[b'a'; 512]].concat();
+        let msgs: [&[u8]; 5] = [b"", b"abc", b"abcdef0123456789", &q128, &a512];
+        let map = |u: &Fp25519| {
+            let point = map_to_curve_elligator2_montgomery::<Curve25519Parameters>(u);
+            assert_eq!(
+                point.y.square(),
+                point.x.square() * point.x + Curve25519Parameters::A * point.x.square() + point.x
+            );
+            point
+        };
+
+        let dst = b"QUUX-V01-CS02-with-curve25519_XMD:SHA-512_ELL2_RO_";
+        let expected = [
+            (
+                "2de3780abb67e861289f5749d16d3e217ffa722192d16bbd9d1bfb9d112b98c0",
+                "3b5dc2a498941a1033d176567d457845637554a2fe7a3507d21abd1c1bd6e878",
+            ),
+            (
+                "2b4419f1f2d48f5872de692b0aca72cc7b0a60915dd70bde432e826b6abc526d",
+                "1b8235f255a268f0a6fa8763e97eb3d22d149343d495da1160eff9703f2d07dd",
+            ),
+            (
+                "68ca1ea5a6acf4e9956daa101709b1eee6c1bb0df1de3b90d4602382a104c036",
+                "2a375b656207123d10766e68b938b1812a4a6625ff83cb8d5e86f58a4be08353",
+            ),
+            (
+                "096e9c8bae6c06b554c1ee69383bb0e82267e064236b3a30608d4ed20b73ac5a",
+                "1eb5a62612cafb32b16c3329794645b5b948d9f8ffe501d4e26b073fef6de355",
+            ),
+            (
+                "1bc61845a138e912f047b5e70ba9606ba2a447a4dade024c8ef3dd42b7bbc5fe",
+                "623d05e47b70e25f7f1d51dda6d7c23c9a18ce015fe3548df596ea9e38c69bf1",
+            ),
+        ];
+        for (msg, (x, y)) in msgs.iter().zip(expected) {
+            let u = hash_to_field::<Ops>(msg, dst, 2);
+            let points: Vec<_> = u.iter().map(map).collect();
+            assert_eq!(clear_cofactor(&points), affine(x, y));
+        }
+
+        let dst = b"QUUX-V01-CS02-with-curve25519_XMD:SHA-512_ELL2_NU_";
+        let expected = [
+            (
+                "1bb913f0c9daefa0b3375378ffa534bda5526c97391952a7789eb976edfe4d08",
+                "4548368f4f983243e747b62a600840ae7c1dab5c723991f85d3a9768479f3ec4",
+            ),
+            (
+                "7c22950b7d900fa866334262fcaea47a441a578df43b894b4625c9b450f9a026",
+                "5547bc00e4c09685dcbc6cb6765288b386d8bdcb595fa5a6e3969e08097f0541",
+            ),
+            (
+                "31ad08a8b0deeb2a4d8b0206ca25f567ab4e042746f792f4b7973f3ae2096c52",
+                "405070c28e78b4fa269427c82827261991b9718bd6c6e95d627d701a53c30db1",
+            ),
+            (
+                "027877759d155b1997d0d84683a313eb78bdb493271d935b622900459d52ceaa",
+                "54d691731a53baa30707f4a87121d5169fb5d587d70fb0292b5830dedbec4c18",
+            ),
+            (
+                "5fd892c0958d1a75f54c3182a18d286efab784e774d1e017ba2fb252998b5dc1",
+                "750af3c66101737423a4519ac792fb93337bd74ee751f19da4cf1e94f4d6d0b8",
+            ),
+        ];
+        for (msg, (x, y)) in msgs.iter().zip(expected) {
+            let u = hash_to_field::<Ops>(msg, dst, 1);
+            assert_eq!(clear_cofactor(&[map(&u[0])]), affine(x, y));
+        }
+    }
+
+    /// An Ed25519 key gives the same shared secrets through X25519
+    #[test]
+    fn test_shared_keys() {
+        let mut rng = thread_rng();
+        let g = GroupEd25519::from(GroupEd25519::generator::<ThreadRng>(None));
+
+        for _ in 0..5 {
+            let mut k = [0u8; 32];
+            rng.fill(&mut k[..]);
+            k[0] &= 248;
+            k[31] &= 127;
+            k[31] |= 64;
+
+            let public = g
+                .mul_int(&limbs_from_le_bytes(&k))
+                .as_public()
+                .unwrap()
+                .point;
+            let u = edwards_to_montgomery::<Curve25519Parameters>(&public)
+                .unwrap()
+                .x;
+
+            let mut bytes = [0u8; 32];
+            for (chunk, limb) in bytes.chunks_mut(8).zip(u.as_int().limbs.iter()) {
+                chunk.copy_from_slice(&limb.to_le_bytes());
+            }
+            assert_eq!(x25519(&k, &X25519_BASEPOINT), bytes);
+        }
+    }
+
+    #[test]
+    fn test_montgomery_weierstrass() {
+        type Operations = ShortWeierstrassCompleteOperations<Wei25519Parameters>;
+
+        let to_weierstrass = |p: &GroupEd25519| {
+            let p = edwards_to_montgomery::<Curve25519Parameters>(&p.as_public().unwrap().point);
+            montgomery_to_weierstrass::<Curve25519Parameters>(p)
+        };
+
+        assert_eq!(
+            montgomery_to_weierstrass::<Curve25519Parameters>(None),
+            None
+        );
+        assert_eq!(
+            weierstrass_to_montgomery::<Curve25519Parameters>(None),
+            None
+        );
+
+        let mut rng = thread_rng();
+        let g = GroupEd25519::from(GroupEd25519::generator::<ThreadRng>(None));
+        for _ in 0..10 {
+            let p = g * &ScalarEd25519::rand(&mut rng);
+            let q = g * &ScalarEd25519::rand(&mut rng);
+            let (p_image, q_image) = (to_weierstrass(&p).unwrap(), to_weierstrass(&q).unwrap());
+
+            // the image is on the curve and the map can be inverted
+            let (x, y) = (p_image.x, p_image.y);
+            assert_eq!(
+                y.square(),
+                x.square() * x + Wei25519Parameters::A * x + Wei25519Parameters::B
+            );
+            let montgomery =
+                edwards_to_montgomery::<Curve25519Parameters>(&p.as_public().unwrap().point);
+            assert_eq!(
+                weierstrass_to_montgomery::<Curve25519Parameters>(Some(p_image)),
+                montgomery
+            );
+
+            // the map is a homomorphism
+            let mut sum = Projective::from(p_image);
+            Operations::add_affine_in_place(&mut sum, &q_image);
+            assert_eq!(sum.into_affine(), to_weierstrass(&(p + q)));
+
+            let mut double = Projective::from(p_image);
+            Operations::double_in_place(&mut double);
+            assert_eq!(double.into_affine(), to_weierstrass(&(p + p)));
+        }
+    }
+}
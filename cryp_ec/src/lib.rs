@@ -12,19 +12,47 @@ mod common {
     use super::*;
     pub use cryp_alg::{Field, Group, PrimeGroup};
     pub use models::{
-        Affine, Coordinates, ExtendedPoint, GroupEC, JacobianPoint, PrimeGroupConfig,
-        PrimeSubGroupConfig, Projective, PublicEC,
+        Affine, CompletedPoint, Coordinates, CurveEncoding, ExtendedPoint, GroupEC,
+        JacobianPoint, PrimeGroupConfig, PrimeSubGroupConfig, Projective, ProjectiveNielsPoint,
+        PublicEC,
     };
 }
 
 pub mod weierstrass {
     use super::*;
     pub use common::*;
-    pub use models::ShortWeierstrass;
+    pub use models::{
+        sec1_compress, sec1_decompress, sec1_uncompressed, CoefficientKind, ShortWeierstrass,
+        ShortWeierstrassCompleteOperations, ShortWeierstrassOperations,
+    };
 }
 
 pub mod edwards {
     use super::*;
     pub use common::*;
-    pub use models::{EdwardsAM1UnifiedOperations, TwistedEdwardsAM1};
+    pub use models::{
+        EdwardsAM1UnifiedOperations, EdwardsGeneralUnifiedOperations, TwistedEdwardsAM1,
+        TwistedEdwardsGeneral,
+    };
+}
+
+/// Hashing to elliptic curves, following RFC 9380.
+pub mod hash_to_curve {
+    use super::*;
+    pub use cryp_hash::{Digest, ExtendableOutput, Sha256, Sha512, Shake128, Shake256};
+    pub use models::{
+        encode_to_curve, expand_message_xmd, expand_message_xof, hash_to_curve, hash_to_field,
+        isogeny_map, map_to_curve_elligator2, map_to_curve_elligator2_montgomery,
+        map_to_curve_sswu, Elligator2Map, HashToCurveConfig, HashToField, SSWUMap,
+    };
+}
+
+pub mod montgomery {
+    use super::*;
+    pub use cryp_alg::Field;
+    pub use models::{
+        edwards_to_montgomery, montgomery_to_edwards, montgomery_to_weierstrass,
+        weierstrass_to_montgomery, Affine, MontgomeryCurve, MontgomeryEdwardsMap,
+        MontgomeryWeierstrassMap, ShortWeierstrass, TwistedEdwardsAM1,
+    };
 }
@@ -7,12 +7,19 @@
 //! We currently support the following coordinate systems:
 //!
 //! - Short Weierstrass: `short_weierstrass` module
-//! Curves of the form `y^2 = x^3 + Ax + B`
-//! where A, B are non-zero constants.
+//!   Curves of the form `y^2 = x^3 + Ax + B`
+//!   where A, B are non-zero constants.
 //!
 //! - Twisted Edwards
-//! Curves of the form `Ax^2 + y^2 = 1 + Dx^2y^2`
-//! where A, D are constants.
+//!   Curves of the form `Ax^2 + y^2 = 1 + Dx^2y^2`
+//!   where A, D are constants.
+//!
+//! - Montgomery: `montgomery` module
+//!   Curves of the form `By^2 = x^3 + Ax^2 + x`,
+//!   used through an x-only ladder.
+//!
+//! The `birational` module contains the maps between Montgomery curves and the equivalent
+//! twisted Edwards and short Weierstrass curves.
 //!
 //!
 //! Curve operations are implemented through the `CurveOperations` trait. These usually depend
@@ -23,6 +30,11 @@
 //! For example, the twisted Edwards curve operations are more efficient
 //! if one assumes that `A` is equal to `-1`.
 //!
+//! The `encoding` module contains the `CurveEncoding` trait for decoding the compressed
+//! encodings of the elements of a prime order group.
+//!
+//! The `hash_to_curve` module implements the hashing of messages to curve points of RFC 9380.
+//!
 //! The `primegroup` module contains the `PrimeGroupConfig` trait which encodes the information
 //! needed to define a prime order group related to the elliptic curve. This can be a subroup
 //! of the elliptic curve, in which case the `PrimeSubGroupConfig` trait can be used, and each
@@ -30,22 +42,50 @@
 //!
 //!
 
-use cryp_std::{hash::Hash, rand::Rng, vec::Vec};
+use cryp_std::{fmt::Debug, hash::Hash};
 
 use cryp_alg::ff;
 
-use ff::{Field, PrimeField};
+use ff::Field;
 
+mod birational;
 mod coordinates;
+mod encoding;
+mod hash_to_curve;
+mod montgomery;
 mod primegroup;
-mod scalar_mul;
+pub(crate) mod scalar_mul;
 mod short_weierstrass;
 mod twisted_edwards;
 
-pub use coordinates::{Affine, Coordinates, ExtendedPoint, JacobianPoint, Projective};
+pub use birational::{
+    edwards_to_montgomery, montgomery_to_edwards, montgomery_to_weierstrass,
+    weierstrass_to_montgomery, MontgomeryEdwardsMap, MontgomeryWeierstrassMap,
+};
+pub use coordinates::{
+    Affine, CompletedPoint, Coordinates, ExtendedPoint, JacobianPoint, Projective,
+    ProjectiveNielsPoint,
+};
+pub(crate) use encoding::{field_from_bytes_be, field_from_bytes_be_reduced};
+pub(crate) use primegroup::generators_from_label;
+#[cfg(test)]
+pub(crate) use primegroup::check_generators;
+pub use encoding::CurveEncoding;
+pub use hash_to_curve::{
+    encode_to_curve, expand_message_xmd, expand_message_xof, hash_to_curve, hash_to_field,
+    isogeny_map, map_to_curve_elligator2, map_to_curve_elligator2_montgomery, map_to_curve_sswu,
+    Elligator2Map, HashToCurveConfig, HashToField, SSWUMap,
+};
+pub use montgomery::MontgomeryCurve;
 pub use primegroup::{GroupEC, PrimeGroupConfig, PrimeSubGroupConfig, PublicEC};
-pub use short_weierstrass::ShortWeierstrass;
-pub use twisted_edwards::{EdwardsAM1UnifiedOperations, TwistedEdwardsAM1};
+pub use short_weierstrass::{
+    sec1_compress, sec1_decompress, sec1_uncompressed, CoefficientKind, ShortWeierstrass,
+    ShortWeierstrassCompleteOperations, ShortWeierstrassOperations,
+};
+pub use twisted_edwards::{
+    EdwardsAM1UnifiedOperations, EdwardsGeneralUnifiedOperations, TwistedEdwardsAM1,
+    TwistedEdwardsGeneral,
+};
 
 /// A trait for the operations on an elliptic curve.
 ///
@@ -53,13 +93,17 @@ pub use twisted_edwards::{EdwardsAM1UnifiedOperations, TwistedEdwardsAM1};
 /// for different curve models and coordinate systems.
 pub trait CurveOperations {
     type Field: Field;
-    type Affine: Clone + Copy + PartialEq + Eq + Hash + Send + Sync + Into<Self::Point>;
+    type Affine: Clone + Copy + PartialEq + Eq + Hash + Debug + Send + Sync + Into<Self::Point>;
     type Point: Coordinates<Field = Self::Field, Affine = Self::Affine>;
+    /// A representation of a point with precomputed values, for points that are added many times.
+    type Cached: Clone + Copy + Debug;
 
     const UNIFIED: bool;
 
     /// Identity point of the curve.
     fn identity() -> Self::Point;
+    /// Checks that a point in affine representation satisfies the curve equation.
+    fn is_on_curve(point: &Self::Affine) -> bool;
     /// Negates the point in place.
     fn neg_in_place(point: &mut Self::Point);
     /// Adds a point to the given point in place.
@@ -68,4 +112,18 @@ pub trait CurveOperations {
     fn add_affine_in_place(lhs: &mut Self::Point, rhs: &Self::Affine);
     /// Doubles the point in place.
     fn double_in_place(point: &mut Self::Point);
+    /// Converts a point to the cached representation.
+    fn to_cached(point: &Self::Point) -> Self::Cached;
+    /// Adds a point in cached representation to the given point in place.
+    fn add_cached_in_place(lhs: &mut Self::Point, rhs: &Self::Cached);
+
+    /// Doubles the point `n` times in place.
+    ///
+    /// The default implementation calls `double_in_place` `n` times. Implementations can
+    /// skip computing coordinates that are only needed by additions.
+    fn double_n_in_place(point: &mut Self::Point, n: usize) {
+        for _ in 0..n {
+            Self::double_in_place(point);
+        }
+    }
 }
@@ -0,0 +1,92 @@
+//! Birational maps between curve models.
+//!
+//! A Montgomery curve `By^2 = x^3 + Ax^2 + x` is birationally equivalent to the twisted Edwards
+//! curve `ax^2 + y^2 = 1 + dx^2y^2` with `A = 2(a + d) / (a - d)`, and, up to a scaling of `y`,
+//! `B = 4 / (a - d)`. It is also isomorphic to the short Weierstrass curve
+//! `y^2 = x^3 + a'x + b'` with `a' = (3 - A^2) / (3B^2)` and `b' = (2A^3 - 9A) / (27B^3)`.
+//!
+//! Montgomery and Weierstrass points are given in affine coordinates, with `None` standing for
+//! the point at infinity. The maps are group homomorphisms, so keys can be moved between
+//! Ed25519 and X25519 and Weierstrass-only algorithms applied to the other models.
+
+use super::coordinates::Affine;
+use super::ff::*;
+use super::{MontgomeryCurve, ShortWeierstrass, TwistedEdwardsAM1};
+
+/// A Montgomery curve birationally equivalent to a twisted Edwards curve with `a = -1`.
+pub trait MontgomeryEdwardsMap: MontgomeryCurve {
+    /// The parameters of the equivalent twisted Edwards curve.
+    type Edwards: TwistedEdwardsAM1<Field = Self::Field>;
+
+    /// The element `s` with `B s^2 = 4 / (a - d)`, which scales the `y`-coordinates.
+    const SCALE: Self::Field;
+}
+
+/// A Montgomery curve isomorphic to a short Weierstrass curve.
+pub trait MontgomeryWeierstrassMap: MontgomeryCurve {
+    /// The parameters of the isomorphic short Weierstrass curve.
+    type Weierstrass: ShortWeierstrass<Field = Self::Field>;
+}
+
+/// Maps a point of the twisted Edwards curve to the equivalent Montgomery curve.
+///
+/// The point is sent to `((1 + y) / (1 - y), s (1 + y) / ((1 - y) x))`. The exceptional
+/// points are the identity `(0, 1)`, sent to the point at infinity `None`, and the point
+/// `(0, -1)` of order two, sent to `(0, 0)`.
+pub fn edwards_to_montgomery<M: MontgomeryEdwardsMap>(
+    point: &Affine<M::Field>,
+) -> Option<Affine<M::Field>> {
+    let one = M::Field::one();
+    if point.x == M::Field::zero() {
+        return (point.y != one).then(|| Affine::new(M::Field::zero(), M::Field::zero()));
+    }
+    let u = (one + point.y) / (one - point.y);
+    let v = M::SCALE * u / point.x;
+    Some(Affine::new(u, v))
+}
+
+/// Maps a point of the Montgomery curve, `None` being the point at infinity, to the
+/// equivalent twisted Edwards curve.
+///
+/// The point is sent to `(s u / v, (u - 1) / (u + 1))`, the inverse of
+/// `edwards_to_montgomery`. Returns `None` for the points with `v = 0` other than `(0, 0)`
+/// and for the points with `u = -1`, which correspond to points at infinity of the Edwards
+/// curve. There are no such points on the curve if `d` is not a square.
+pub fn montgomery_to_edwards<M: MontgomeryEdwardsMap>(
+    point: Option<Affine<M::Field>>,
+) -> Option<Affine<M::Field>> {
+    let (zero, one) = (M::Field::zero(), M::Field::one());
+    let point = match point {
+        None => return Some(Affine::new(zero, one)),
+        Some(point) => point,
+    };
+    if point.x == zero && point.y == zero {
+        return Some(Affine::new(zero, -one));
+    }
+    let x = M::SCALE * point.x * point.y.inverse()?;
+    let y = (point.x - one) * (point.x + one).inverse()?;
+    Some(Affine::new(x, y))
+}
+
+/// Maps a point of the Montgomery curve, `None` being the point at infinity, to the
+/// isomorphic short Weierstrass curve.
+///
+/// The point is sent to `((u + A / 3) / B, v / B)` and the point at infinity to itself.
+pub fn montgomery_to_weierstrass<M: MontgomeryWeierstrassMap>(
+    point: Option<Affine<M::Field>>,
+) -> Option<Affine<M::Field>> {
+    let three = M::Field::one().double() + M::Field::one();
+    let b_inverse = M::B.inverse().expect("B is non-zero");
+    point.map(|point| Affine::new((point.x + M::A / three) * b_inverse, point.y * b_inverse))
+}
+
+/// Maps a point of the short Weierstrass curve, `None` being the point at infinity, to the
+/// isomorphic Montgomery curve.
+///
+/// The point is sent to `(Bx - A / 3, By)`, the inverse of `montgomery_to_weierstrass`.
+pub fn weierstrass_to_montgomery<M: MontgomeryWeierstrassMap>(
+    point: Option<Affine<M::Field>>,
+) -> Option<Affine<M::Field>> {
+    let three = M::Field::one().double() + M::Field::one();
+    point.map(|point| Affine::new(M::B * point.x - M::A / three, M::B * point.y))
+}
@@ -1,6 +1,7 @@
 use super::Field;
 use cryp_std::fmt::{Debug, Display};
 use cryp_std::hash::{Hash, Hasher};
+use cryp_std::vec::Vec;
 
 /// A trait for the coordinates of a point on an elliptic curve.
 pub trait Coordinates:
@@ -9,7 +10,44 @@ pub trait Coordinates:
     type Field;
     type Affine;
 
+    #[allow(clippy::wrong_self_convention)]
     fn into_affine(&self) -> Option<Self::Affine>;
+
+    /// Converts several points to affine coordinates, `None` standing for the points at
+    /// infinity.
+    ///
+    /// The default implementation converts the points one by one.
+    fn normalize_batch(points: &[Self]) -> Vec<Option<Self::Affine>> {
+        points.iter().map(Self::into_affine).collect()
+    }
+}
+
+/// The inverses of the values, or `None` for the zero values.
+///
+/// Uses Montgomery's trick: a single inversion of the product of the non zero values, and
+/// three multiplications per value.
+fn batch_inverse<F: Field>(values: &[F]) -> Vec<Option<F>> {
+    // the products of the non zero values before each value
+    let mut products = Vec::with_capacity(values.len());
+    let mut product = F::one();
+    for value in values {
+        products.push(product);
+        if *value != F::zero() {
+            product *= *value;
+        }
+    }
+
+    let mut inverse = product
+        .inverse()
+        .expect("a product of non zero values is not zero");
+    let mut inverses = cryp_std::vec![None; values.len()];
+    for ((value, product), result) in values.iter().zip(products).zip(&mut inverses).rev() {
+        if *value != F::zero() {
+            *result = Some(inverse * product);
+            inverse *= *value;
+        }
+    }
+    inverses
 }
 
 /// Standard affine coordinates
@@ -57,6 +95,21 @@ impl<F: Field> Coordinates for Projective<F> {
 
         Some(Affine { x, y })
     }
+
+    /// Shares a single field inversion between the points.
+    fn normalize_batch(points: &[Self]) -> Vec<Option<Self::Affine>> {
+        let z = points.iter().map(|point| point.Z).collect::<Vec<_>>();
+        batch_inverse(&z)
+            .into_iter()
+            .zip(points)
+            .map(|(z_inv, point)| {
+                z_inv.map(|z_inv| Affine {
+                    x: point.X * z_inv,
+                    y: point.Y * z_inv,
+                })
+            })
+            .collect()
+    }
 }
 
 impl<F: Field> PartialEq for Projective<F> {
@@ -136,6 +189,21 @@ impl<F: Field> Coordinates for ExtendedPoint<F> {
 
         Some(Affine { x, y })
     }
+
+    /// Shares a single field inversion between the points.
+    fn normalize_batch(points: &[Self]) -> Vec<Option<Self::Affine>> {
+        let z = points.iter().map(|point| point.Z).collect::<Vec<_>>();
+        batch_inverse(&z)
+            .into_iter()
+            .zip(points)
+            .map(|(z_inv, point)| {
+                z_inv.map(|z_inv| Affine {
+                    x: point.X * z_inv,
+                    y: point.Y * z_inv,
+                })
+            })
+            .collect()
+    }
 }
 
 impl<F: Field> Display for ExtendedPoint<F> {
@@ -159,6 +227,72 @@ impl<F: Field> From<Affine<F>> for ExtendedPoint<F> {
     }
 }
 
+// ---------------------------------------------
+// Completed Point
+// ---------------------------------------------
+
+/// Completed twisted Edwards coordinates, also called P1xP1
+///
+/// x = X/Z , y = Y/T
+///
+/// This is the output of the doubling formula before its final multiplications. It can be
+/// converted to projective coordinates with 3 multiplications, or to extended coordinates
+/// with 4, so that a chain of doublings does not need to compute T.
+#[derive(Debug, Clone, Copy)]
+#[allow(non_snake_case)]
+pub struct CompletedPoint<F: Field> {
+    pub X: F,
+    pub Y: F,
+    pub Z: F,
+    pub T: F,
+}
+
+impl<F: Field> CompletedPoint<F> {
+    pub fn into_projective(&self) -> Projective<F> {
+        Projective {
+            X: self.X * self.T,
+            Y: self.Y * self.Z,
+            Z: self.Z * self.T,
+        }
+    }
+
+    pub fn into_extended(&self) -> ExtendedPoint<F> {
+        ExtendedPoint {
+            X: self.X * self.T,
+            Y: self.Y * self.Z,
+            T: self.X * self.Y,
+            Z: self.Z * self.T,
+        }
+    }
+}
+
+impl<F: Field> From<ExtendedPoint<F>> for Projective<F> {
+    fn from(point: ExtendedPoint<F>) -> Self {
+        Projective {
+            X: point.X,
+            Y: point.Y,
+            Z: point.Z,
+        }
+    }
+}
+
+// ---------------------------------------------
+// Projective Niels Point
+// ---------------------------------------------
+
+/// A twisted Edwards point with the values used by the addition formula precomputed
+///
+/// Y_plus_X = Y + X, Y_minus_X = Y - X, Z2 = 2Z, T2d = 2dT for a point (X : Y : Z : T)
+/// in extended coordinates.
+#[derive(Debug, Clone, Copy)]
+#[allow(non_snake_case)]
+pub struct ProjectiveNielsPoint<F: Field> {
+    pub Y_plus_X: F,
+    pub Y_minus_X: F,
+    pub Z2: F,
+    pub T2d: F,
+}
+
 // ---------------------------------------------
 // Jacobian Point
 // ---------------------------------------------
@@ -184,6 +318,24 @@ impl<F: Field> Coordinates for JacobianPoint<F> {
 
         Some(Affine { x, y })
     }
+
+    /// Shares a single field inversion between the points.
+    fn normalize_batch(points: &[Self]) -> Vec<Option<Self::Affine>> {
+        let z = points.iter().map(|point| point.Z).collect::<Vec<_>>();
+        batch_inverse(&z)
+            .into_iter()
+            .zip(points)
+            .map(|(z_inv, point)| {
+                z_inv.map(|z_inv| {
+                    let z_inv2 = z_inv.square();
+                    Affine {
+                        x: point.X * z_inv2,
+                        y: point.Y * z_inv2 * z_inv,
+                    }
+                })
+            })
+            .collect()
+    }
 }
 
 impl<F: Field> PartialEq for JacobianPoint<F> {
@@ -223,7 +375,7 @@ impl<F: Field> From<Affine<F>> for JacobianPoint<F> {
 
 #[cfg(test)]
 mod tests {
-    use super::*;
+    
 
     #[test]
     fn test_projective_to_affine() {}
@@ -0,0 +1,67 @@
+use super::ff::*;
+use super::{GroupEC, PrimeGroupConfig, PublicEC};
+use cryp_std::vec::Vec;
+
+/// Byte encodings of the elements of a prime order group.
+///
+/// The encoding of an element is given by `PrimeGroupConfig::compress` and this trait adds
+/// the inverse map. Only canonical encodings of points on the curve are accepted, so that
+/// every element has exactly one encoding.
+pub trait CurveEncoding: PrimeGroupConfig {
+    /// Decodes an element from its encoding.
+    ///
+    /// Returns `None` if the bytes are not a canonical encoding of a point on the curve.
+    /// For a subgroup of a curve with a non-trivial cofactor the point is not checked to be
+    /// in the subgroup, this is done by `is_valid`.
+    fn decompress(bytes: &[u8]) -> Option<Self::Public>;
+}
+
+impl<P: CurveEncoding> PublicEC<P> {
+    /// The canonical compressed encoding of the element.
+    pub fn compress(&self) -> Vec<u8> {
+        P::compress(&self.point)
+    }
+
+    /// Decodes an element from its compressed encoding, see `CurveEncoding::decompress`.
+    pub fn decompress(bytes: &[u8]) -> Option<Self> {
+        P::decompress(bytes).map(Self::new)
+    }
+}
+
+impl<P: CurveEncoding> GroupEC<P> {
+    /// Decodes an element from its compressed encoding, see `CurveEncoding::decompress`.
+    pub fn decompress(bytes: &[u8]) -> Option<Self> {
+        PublicEC::<P>::decompress(bytes).map(Self::from)
+    }
+}
+
+/// Decodes a field element from big endian bytes.
+///
+/// Returns `None` unless there are as many bytes as in the encoding of the modulus and
+/// the integer they encode is smaller than the modulus.
+pub(crate) fn field_from_bytes_be<F: PrimeField>(bytes: &[u8]) -> Option<F> {
+    if bytes.len() != Bytes::into_iter_be(&F::MODULUS).count() {
+        return None;
+    }
+    let element = field_from_bytes_be_reduced::<F>(bytes);
+
+    // the element is reduced, so the encoding is canonical if it is unchanged
+    Bytes::into_iter_be(&element.as_int())
+        .eq(bytes.iter().copied())
+        .then_some(element)
+}
+
+/// Reduces the integer encoded by big endian bytes of any length modulo the characteristic.
+pub(crate) fn field_from_bytes_be_reduced<F: PrimeField>(bytes: &[u8]) -> F {
+    bytes
+        .iter()
+        .flat_map(|byte| (0..8).rev().map(move |i| (byte >> i) & 1 == 1))
+        .fold(F::zero(), |acc, bit| {
+            let acc = acc.double();
+            if bit {
+                acc + F::one()
+            } else {
+                acc
+            }
+        })
+}
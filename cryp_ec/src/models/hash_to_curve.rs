@@ -0,0 +1,130 @@
+//! Hashing to elliptic curves, as specified in RFC 9380.
+//!
+//! A message is hashed to the curve in three steps:
+//!
+//! - `hash_to_field` expands the message with `expand_message_xmd` or `expand_message_xof`
+//!   into uniform bytes and reduces them to elements of the field of the curve.
+//! - `map_to_curve` sends each field element to a point of the curve, with the simplified
+//!   SWU map of the `sswu` module for short Weierstrass curves or the Elligator 2 map of the
+//!   `elligator2` module for twisted Edwards curves.
+//! - `clear_cofactor` sends the point to the prime order subgroup.
+//!
+//! `hash_to_curve` adds the images of two field elements and is indistinguishable from a
+//! random oracle, while `encode_to_curve` maps a single element and is cheaper, but its
+//! output is not uniformly distributed. A curve provides these through the
+//! `HashToCurveConfig` trait, and the group through `PrimeGroupConfig::hash_to_curve` and
+//! `PrimeGroupConfig::encode_to_curve`.
+//!
+//! The maps use square roots that are not computed in constant time, so the message
+//! should not be secret.
+
+use super::ff::*;
+use super::{field_from_bytes_be_reduced, CurveOperations, PrimeSubGroupConfig};
+use cryp_std::vec::Vec;
+
+mod elligator2;
+mod expand;
+mod sswu;
+
+pub use elligator2::{map_to_curve_elligator2, map_to_curve_elligator2_montgomery, Elligator2Map};
+pub use expand::{expand_message_xmd, expand_message_xof};
+pub use sswu::{isogeny_map, map_to_curve_sswu, SSWUMap};
+
+/// A field whose elements can be derived from uniform bytes, with the operations used by the
+/// maps to curves.
+pub trait HashToField: Field {
+    /// The degree of the field over its prime subfield, `m` in RFC 9380.
+    const DEGREE: usize;
+
+    /// The element whose coordinates over the prime subfield are the big endian integers
+    /// encoded by `DEGREE` consecutive byte strings of equal length, reduced modulo the
+    /// characteristic.
+    fn from_uniform_bytes(bytes: &[u8]) -> Self;
+
+    /// The sign of the element, `sgn0` of RFC 9380, section 4.1.
+    fn sgn0(&self) -> bool;
+
+    /// A square root of the element, if it is a square.
+    fn sqrt(&self) -> Option<Self>;
+}
+
+impl<S: PrimeFieldOperations> HashToField for F<S> {
+    const DEGREE: usize = 1;
+
+    fn from_uniform_bytes(bytes: &[u8]) -> Self {
+        field_from_bytes_be_reduced(bytes)
+    }
+
+    /// The parity of the element as an integer in `[0, p)`.
+    fn sgn0(&self) -> bool {
+        Bytes::into_iter_be(&self.as_int())
+            .last()
+            .is_some_and(|byte| byte & 1 == 1)
+    }
+
+    fn sqrt(&self) -> Option<Self> {
+        PrimeField::sqrt(self)
+    }
+}
+
+impl<P: QuadExtParameters> HashToField for QuadExtField<P>
+where
+    P::BaseField: PrimeField + HashToField,
+{
+    const DEGREE: usize = 2;
+
+    fn from_uniform_bytes(bytes: &[u8]) -> Self {
+        let (c0, c1) = bytes.split_at(bytes.len() / 2);
+        Self::new(
+            P::BaseField::from_uniform_bytes(c0),
+            P::BaseField::from_uniform_bytes(c1),
+        )
+    }
+
+    /// The sign of `c0`, or of `c1` if `c0` is zero.
+    fn sgn0(&self) -> bool {
+        self.c0.sgn0() || (self.c0 == P::BaseField::zero() && self.c1.sgn0())
+    }
+
+    fn sqrt(&self) -> Option<Self> {
+        QuadExtField::sqrt(self)
+    }
+}
+
+/// A hash to curve suite of RFC 9380 for a prime order subgroup of a curve.
+///
+/// The cofactor is cleared with `PrimeSubGroupConfig::clear_cofactor`.
+pub trait HashToCurveConfig: PrimeSubGroupConfig + CurveOperations<Field: HashToField> {
+    /// The number of uniform bytes reduced to one coordinate of a field element, `L` in
+    /// RFC 9380. It is `ceil((ceil(log2(p)) + k) / 8)` for the security level `k`.
+    const L: usize;
+
+    /// The `expand_message` function of the suite.
+    fn expand_message(msg: &[u8], dst: &[u8], len_in_bytes: usize) -> Vec<u8>;
+
+    /// Maps a field element to a point of the curve, not necessarily in the subgroup.
+    fn map_to_curve(u: &Self::Field) -> Self::Point;
+}
+
+/// Hashes a message to `count` field elements, `hash_to_field` of RFC 9380, section 5.2.
+pub fn hash_to_field<P: HashToCurveConfig>(msg: &[u8], dst: &[u8], count: usize) -> Vec<P::Field> {
+    let len = P::Field::DEGREE * P::L;
+    P::expand_message(msg, dst, count * len)
+        .chunks_exact(len)
+        .map(P::Field::from_uniform_bytes)
+        .collect()
+}
+
+/// Hashes a message to the subgroup, `hash_to_curve` of RFC 9380, section 3.
+pub fn hash_to_curve<P: HashToCurveConfig>(msg: &[u8], dst: &[u8]) -> P::Point {
+    let u = hash_to_field::<P>(msg, dst, 2);
+    let mut point = P::map_to_curve(&u[0]);
+    P::add_in_place(&mut point, &P::map_to_curve(&u[1]));
+    P::clear_cofactor(&point)
+}
+
+/// Encodes a message to the subgroup, `encode_to_curve` of RFC 9380, section 3.
+pub fn encode_to_curve<P: HashToCurveConfig>(msg: &[u8], dst: &[u8]) -> P::Point {
+    let u = hash_to_field::<P>(msg, dst, 1);
+    P::clear_cofactor(&P::map_to_curve(&u[0]))
+}
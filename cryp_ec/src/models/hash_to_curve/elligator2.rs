@@ -0,0 +1,57 @@
+use super::HashToField;
+use crate::models::ff::*;
+use crate::models::Affine;
+use crate::models::MontgomeryEdwardsMap;
+
+/// A twisted Edwards curve with the Elligator 2 map of RFC 9380, section 6.7.1, through the
+/// birationally equivalent Montgomery curve.
+pub trait Elligator2Map: MontgomeryEdwardsMap<Field: HashToField> {
+    /// The non-square `Z` of the suite.
+    const Z: Self::Field;
+}
+
+/// The Elligator 2 map of RFC 9380, section 6.7.1, to the point `(s, t)` of the Montgomery
+/// curve. The computation does not run in constant time.
+pub fn map_to_curve_elligator2_montgomery<M: Elligator2Map>(u: &M::Field) -> Affine<M::Field> {
+    let one = M::Field::one();
+
+    // The curve K t^2 = s^3 + J s^2 + s, with J = A and K = B
+    let c1 = M::A / M::B;
+    let c2 = M::B.square().inverse().expect("B is not zero");
+    let g = |x: &M::Field| ((*x + c1) * x + c2) * x;
+
+    // x1 = -J / (K (1 + Z u^2)), or -J / K if the denominator is zero
+    let x1 = match (one + M::Z * u.square()).inverse() {
+        Some(tv1) => -c1 * tv1,
+        None => -c1,
+    };
+
+    // Either g(x1) or g(-x1 - J / K) is a square, and the sign of y depends on which
+    let (x, y) = match g(&x1).sqrt() {
+        Some(y1) => (x1, if y1.sgn0() { y1 } else { -y1 }),
+        None => {
+            let x2 = -x1 - c1;
+            let y2 = g(&x2).sqrt().expect("Z is not a square in the field");
+            (x2, if y2.sgn0() { -y2 } else { y2 })
+        }
+    };
+    Affine::new(x * M::B, y * M::B)
+}
+
+/// The Elligator 2 map to the Montgomery curve followed by the rational map to the twisted
+/// Edwards curve.
+///
+/// The rational map is the one of RFC 9380, section 6.8.2, which sends `(s, t)` to
+/// `(c s / t, (s - 1) / (s + 1))`, where `c` is the one of `SCALE` and `-SCALE` with sign 0.
+/// The points where a denominator vanishes are sent to the identity. The computation does
+/// not run in constant time.
+pub fn map_to_curve_elligator2<M: Elligator2Map>(u: &M::Field) -> Affine<M::Field> {
+    let (zero, one) = (M::Field::zero(), M::Field::one());
+    let Affine { x: s, y: t, .. } = map_to_curve_elligator2_montgomery::<M>(u);
+
+    let c = if M::SCALE.sgn0() { -M::SCALE } else { M::SCALE };
+    match (t * (s + one)).inverse() {
+        Some(inv) => Affine::new(c * s * (s + one) * inv, (s - one) * t * inv),
+        None => Affine::new(zero, one),
+    }
+}
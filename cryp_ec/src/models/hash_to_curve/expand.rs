@@ -0,0 +1,323 @@
+use cryp_hash::{Digest, ExtendableOutput};
+use cryp_std::vec::Vec;
+
+/// The prefix of domain separation tags longer than 255 bytes, which are hashed first.
+const OVERSIZE_DST_PREFIX: &[u8] = b"H2C-OVERSIZE-DST-";
+
+/// Expands a message into `len_in_bytes` uniform bytes with the hash function `H`,
+/// `expand_message_xmd` of RFC 9380, section 5.3.1.
+///
+/// Domain separation tags longer than 255 bytes are hashed as in section 5.3.3.
+///
+/// # Panics
+///
+/// If `len_in_bytes` is more than 255 digests or 65535 bytes.
+pub fn expand_message_xmd<H: Digest>(msg: &[u8], dst: &[u8], len_in_bytes: usize) -> Vec<u8> {
+    let ell = len_in_bytes.div_ceil(H::OUTPUT_SIZE);
+    assert!(
+        ell <= 255 && len_in_bytes <= 65535,
+        "The output of expand_message_xmd is too long"
+    );
+
+    let long_dst;
+    let dst = if dst.len() > 255 {
+        let mut hasher = H::new();
+        hasher.update(OVERSIZE_DST_PREFIX);
+        hasher.update(dst);
+        long_dst = hasher.finalize();
+        long_dst.as_ref()
+    } else {
+        dst
+    };
+    let dst_len = [dst.len() as u8];
+
+    // b_0 = H(Z_pad || msg || l_i_b_str || 0 || DST_prime)
+    let mut hasher = H::new();
+    hasher.update(&cryp_std::vec![0u8; H::BLOCK_SIZE]);
+    hasher.update(msg);
+    hasher.update(&(len_in_bytes as u16).to_be_bytes());
+    hasher.update(&[0]);
+    hasher.update(dst);
+    hasher.update(&dst_len);
+    let b_0 = hasher.finalize();
+
+    // b_i = H(strxor(b_0, b_(i - 1)) || i || DST_prime), with b_1 = H(b_0 || 1 || DST_prime)
+    let mut uniform_bytes = Vec::with_capacity(ell * H::OUTPUT_SIZE);
+    let mut b_i = b_0;
+    for i in 1..=ell {
+        let mut chained = b_0;
+        if i > 1 {
+            for (byte, previous) in chained.as_mut().iter_mut().zip(b_i.as_ref()) {
+                *byte ^= previous;
+            }
+        }
+        let mut hasher = H::new();
+        hasher.update(chained.as_ref());
+        hasher.update(&[i as u8]);
+        hasher.update(dst);
+        hasher.update(&dst_len);
+        b_i = hasher.finalize();
+        uniform_bytes.extend_from_slice(b_i.as_ref());
+    }
+    uniform_bytes.truncate(len_in_bytes);
+    uniform_bytes
+}
+
+/// Expands a message into `len_in_bytes` uniform bytes with the extendable output function
+/// `H`, `expand_message_xof` of RFC 9380, section 5.3.2.
+///
+/// Domain separation tags longer than 255 bytes are hashed as in section 5.3.3, to
+/// `2 * security_level / 8` bytes, where `security_level` is the target security in bits.
+///
+/// # Panics
+///
+/// If `len_in_bytes` is more than 65535.
+pub fn expand_message_xof<H: ExtendableOutput>(
+    msg: &[u8],
+    dst: &[u8],
+    len_in_bytes: usize,
+    security_level: usize,
+) -> Vec<u8> {
+    assert!(
+        len_in_bytes <= 65535,
+        "The output of expand_message_xof is too long"
+    );
+
+    let mut long_dst = Vec::new();
+    let dst = if dst.len() > 255 {
+        let mut hasher = H::default();
+        hasher.update(OVERSIZE_DST_PREFIX);
+        hasher.update(dst);
+        long_dst.resize((2 * security_level).div_ceil(8), 0);
+        hasher.finalize_into(&mut long_dst);
+        &long_dst
+    } else {
+        dst
+    };
+
+    // H(msg || l_i_b_str || DST_prime, len_in_bytes)
+    let mut hasher = H::default();
+    hasher.update(msg);
+    hasher.update(&(len_in_bytes as u16).to_be_bytes());
+    hasher.update(dst);
+    hasher.update(&[dst.len() as u8]);
+    let mut uniform_bytes = cryp_std::vec![0u8; len_in_bytes];
+    hasher.finalize_into(&mut uniform_bytes);
+    uniform_bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cryp_hash::{Sha256, Sha512, Shake128, Shake256};
+
+    fn to_hex(bytes: &[u8]) -> cryp_std::string::String {
+        use cryp_std::fmt::Write;
+
+        let mut hex = cryp_std::string::String::new();
+        for byte in bytes {
+            write!(hex, "{:02x}", byte).unwrap();
+        }
+        hex
+    }
+
+    /// The test vectors of RFC 9380, appendix K.1 for expand_message_xmd with SHA-256
+    #[test]
+    fn test_expand_message_xmd_sha256() {
+        let dst = b"QUUX-V01-CS02-with-expander-SHA256-128";
+        let q128 = [b"q128_".as_slice(), &[b'q'; 128]].concat();
+        let a512 = [b"a512_".as_slice(), &[b'a'; 512]].concat();
+        let vectors: [(&[u8], usize, &str); 10] = [
+            (
+                b"",
+                0x20,
+                "68a985b87eb6b46952128911f2a4412bbc302a9d759667f87f7a21d803f07235",
+            ),
+            (
+                b"abc",
+                0x20,
+                "d8ccab23b5985ccea865c6c97b6e5b8350e794e603b4b97902f53a8a0d605615",
+            ),
+            (
+                b"abcdef0123456789",
+                0x20,
+                "eff31487c770a893cfb36f912fbfcbff40d5661771ca4b2cb4eafe524333f5c1",
+            ),
+            (
+                &q128,
+                0x20,
+                "b23a1d2b4d97b2ef7785562a7e8bac7eed54ed6e97e29aa51bfe3f12ddad1ff9",
+            ),
+            (
+                &a512,
+                0x20,
+                "4623227bcc01293b8c130bf771da8c298dede7383243dc0993d2d94823958c4c",
+            ),
+            (
+                b"",
+                0x80,
+                "af84c27ccfd45d41914fdff5df25293e221afc53d8ad2ac06d5e3e29485dadbe\
+                 e0d121587713a3e0dd4d5e69e93eb7cd4f5df4cd103e188cf60cb02edc3edf18\
+                 eda8576c412b18ffb658e3dd6ec849469b979d444cf7b26911a08e63cf31f9dc\
+                 c541708d3491184472c2c29bb749d4286b004ceb5ee6b9a7fa5b646c993f0ced",
+            ),
+            (
+                b"abc",
+                0x80,
+                "abba86a6129e366fc877aab32fc4ffc70120d8996c88aee2fe4b32d6c7b6437a\
+                 647e6c3163d40b76a73cf6a5674ef1d890f95b664ee0afa5359a5c4e07985635\
+                 bbecbac65d747d3d2da7ec2b8221b17b0ca9dc8a1ac1c07ea6a1e60583e2cb00\
+                 058e77b7b72a298425cd1b941ad4ec65e8afc50303a22c0f99b0509b4c895f40",
+            ),
+            (
+                b"abcdef0123456789",
+                0x80,
+                "ef904a29bffc4cf9ee82832451c946ac3c8f8058ae97d8d629831a74c6572bd9\
+                 ebd0df635cd1f208e2038e760c4994984ce73f0d55ea9f22af83ba4734569d4b\
+                 c95e18350f740c07eef653cbb9f87910d833751825f0ebefa1abe5420bb52be1\
+                 4cf489b37fe1a72f7de2d10be453b2c9d9eb20c7e3f6edc5a60629178d9478df",
+            ),
+            (
+                &q128,
+                0x80,
+                "80be107d0884f0d881bb460322f0443d38bd222db8bd0b0a5312a6fedb49c1bb\
+                 d88fd75d8b9a09486c60123dfa1d73c1cc3169761b17476d3c6b7cbbd727acd0\
+                 e2c942f4dd96ae3da5de368d26b32286e32de7e5a8cb2949f866a0b80c58116b\
+                 29fa7fabb3ea7d520ee603e0c25bcaf0b9a5e92ec6a1fe4e0391d1cdbce8c68a",
+            ),
+            (
+                &a512,
+                0x80,
+                "546aff5444b5b79aa6148bd81728704c32decb73a3ba76e9e75885cad9def1d0\
+                 6d6792f8a7d12794e90efed817d96920d728896a4510864370c207f99bd4a608\
+                 ea121700ef01ed879745ee3e4ceef777eda6d9e5e38b90c86ea6fb0b36504ba4\
+                 a45d22e86f6db5dd43d98a294bebb9125d5b794e9d2a81181066eb954966a487",
+            ),
+        ];
+        for (msg, len, expected) in vectors {
+            assert_eq!(
+                to_hex(&expand_message_xmd::<Sha256>(msg, dst, len)),
+                expected
+            );
+        }
+    }
+
+    /// A domain separation tag of 256 bytes is hashed first, RFC 9380 appendix K.2
+    #[test]
+    fn test_expand_message_xmd_long_dst() {
+        let mut dst = b"QUUX-V01-CS02-with-expander-SHA256-128-long-DST-".to_vec();
+        dst.resize(256, b'1');
+        assert_eq!(
+            to_hex(&expand_message_xmd::<Sha256>(b"", &dst, 0x20)),
+            "e8dc0c8b686b7ef2074086fbdd2f30e3f8bfbd3bdf177f73f04b97ce618a3ed3"
+        );
+    }
+
+    /// The test vectors of RFC 9380, appendix K.3 for expand_message_xmd with SHA-512
+    #[test]
+    fn test_expand_message_xmd_sha512() {
+        let dst = b"QUUX-V01-CS02-with-expander-SHA512-256";
+        let vectors: [(&[u8], &str); 2] = [
+            (
+                b"",
+                "6b9a7312411d92f921c6f68ca0b6380730a1a4d982c507211a90964c394179ba",
+            ),
+            (
+                b"abc",
+                "0da749f12fbe5483eb066a5f595055679b976e93abe9be6f0f6318bce7aca8dc",
+            ),
+        ];
+        for (msg, expected) in vectors {
+            assert_eq!(
+                to_hex(&expand_message_xmd::<Sha512>(msg, dst, 0x20)),
+                expected
+            );
+        }
+    }
+
+    /// The test vectors of RFC 9380, appendix K.4 for expand_message_xof with SHAKE128
+    #[test]
+    fn test_expand_message_xof_shake128() {
+        let dst = b"QUUX-V01-CS02-with-expander-SHAKE128";
+        let q128 = [b"q128_".as_slice(), &[b'q'; 128]].concat();
+        let a512 = [b"a512_".as_slice(), &[b'a'; 512]].concat();
+        let vectors: [(&[u8], usize, &str); 7] = [
+            (
+                b"",
+                0x20,
+                "86518c9cd86581486e9485aa74ab35ba150d1c75c88e26b7043e44e2acd735a2",
+            ),
+            (
+                b"abc",
+                0x20,
+                "8696af52a4d862417c0763556073f47bc9b9ba43c99b505305cb1ec04a9ab468",
+            ),
+            (
+                b"abcdef0123456789",
+                0x20,
+                "912c58deac4821c3509dbefa094df54b34b8f5d01a191d1d3108a2c89077acca",
+            ),
+            (
+                &q128,
+                0x20,
+                "1adbcc448aef2a0cebc71dac9f756b22e51839d348e031e63b33ebb50faeaf3f",
+            ),
+            (
+                &a512,
+                0x20,
+                "df3447cc5f3e9a77da10f819218ddf31342c310778e0e4ef72bbaecee786a4fe",
+            ),
+            (
+                b"",
+                0x80,
+                "7314ff1a155a2fb99a0171dc71b89ab6e3b2b7d59e38e64419b8b6294d03ffee\
+                 42491f11370261f436220ef787f8f76f5b26bdcd850071920ce023f3ac468477\
+                 44f4612b8714db8f5db83205b2e625d95afd7d7b4d3094d3bdde815f52850bb4\
+                 1ead9822e08f22cf41d615a303b0d9dde73263c049a7b9898208003a739a2e57",
+            ),
+            (
+                b"abc",
+                0x80,
+                "c952f0c8e529ca8824acc6a4cab0e782fc3648c563ddb00da7399f2ae35654f4\
+                 860ec671db2356ba7baa55a34a9d7f79197b60ddae6e64768a37d699a7832349\
+                 6db3878c8d64d909d0f8a7de4927dcab0d3dbbc26cb20a49eceb0530b431cdf4\
+                 7bc8c0fa3e0d88f53b318b6739fbed7d7634974f1b5c386d6230c76260d5337a",
+            ),
+        ];
+        for (msg, len, expected) in vectors {
+            let uniform_bytes = expand_message_xof::<Shake128>(msg, dst, len, 128);
+            assert_eq!(to_hex(&uniform_bytes), expected);
+        }
+    }
+
+    /// A domain separation tag of 256 bytes is hashed first, RFC 9380 appendix K.5
+    #[test]
+    fn test_expand_message_xof_long_dst() {
+        let mut dst = b"QUUX-V01-CS02-with-expander-SHAKE128-long-DST-".to_vec();
+        dst.resize(256, b'1');
+        assert_eq!(
+            to_hex(&expand_message_xof::<Shake128>(b"", &dst, 0x20, 128)),
+            "827c6216330a122352312bccc0c8d6e7a146c5257a776dbd9ad9d75cd880fc53"
+        );
+    }
+
+    /// The test vectors of RFC 9380, appendix K.6 for expand_message_xof with SHAKE256
+    #[test]
+    fn test_expand_message_xof_shake256() {
+        let dst = b"QUUX-V01-CS02-with-expander-SHAKE256";
+        let vectors: [(&[u8], &str); 2] = [
+            (
+                b"",
+                "2ffc05c48ed32b95d72e807f6eab9f7530dd1c2f013914c8fed38c5ccc15ad76",
+            ),
+            (
+                b"abc",
+                "b39e493867e2767216792abce1f2676c197c0692aed061560ead251821808e07",
+            ),
+        ];
+        for (msg, expected) in vectors {
+            let uniform_bytes = expand_message_xof::<Shake256>(msg, dst, 0x20, 256);
+            assert_eq!(to_hex(&uniform_bytes), expected);
+        }
+    }
+}
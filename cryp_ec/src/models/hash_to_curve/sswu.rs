@@ -0,0 +1,77 @@
+use super::HashToField;
+use crate::models::ff::*;
+use crate::models::Affine;
+use crate::models::ShortWeierstrass;
+
+/// A short Weierstrass curve with the simplified Shallue-van de Woestijne-Ulas map of
+/// RFC 9380, section 6.6.2.
+///
+/// The map is computed on a curve `y^2 = x^3 + A'x + B'` with `A'B' != 0`, which is the
+/// curve itself when `AB != 0`. Otherwise it is an isogenous curve and the points are moved
+/// to the curve with `isogeny_map`, as in section 6.6.3.
+pub trait SSWUMap: ShortWeierstrass<Field: HashToField> {
+    /// The coefficient `A'` of the curve on which the map is computed.
+    const ISO_A: Self::Field;
+    /// The coefficient `B'` of the curve on which the map is computed.
+    const ISO_B: Self::Field;
+    /// The non-square `Z` of the suite.
+    const Z: Self::Field;
+
+    /// Maps a point of the curve `y^2 = x^3 + A'x + B'` to the curve, `None` standing for
+    /// the point at infinity.
+    ///
+    /// The default implementation is the identity, for the case `A' = A` and `B' = B`.
+    fn isogeny_map(point: Affine<Self::Field>) -> Option<Affine<Self::Field>> {
+        Some(point)
+    }
+}
+
+/// The simplified SWU map, `None` standing for the point at infinity.
+///
+/// This is the description of RFC 9380, section 6.6.2, which does not run in constant time.
+pub fn map_to_curve_sswu<P: SSWUMap>(u: &P::Field) -> Option<Affine<P::Field>> {
+    let (a, b, z) = (P::ISO_A, P::ISO_B, P::Z);
+    let g = |x: &P::Field| (x.square() + a) * x + b;
+
+    // x1 = (-B / A) * (1 + 1 / (Z^2 u^4 + Z u^2)), or B / (Z A) if the denominator is zero
+    let z_u2 = z * u.square();
+    let x1 = match (z_u2.square() + z_u2).inverse() {
+        Some(tv1) => -b / a * (P::Field::one() + tv1),
+        None => b / (z * a),
+    };
+
+    // Either g(x1) or g(Z u^2 x1) = Z^3 u^6 g(x1) is a square
+    let (x, y) = match g(&x1).sqrt() {
+        Some(y1) => (x1, y1),
+        None => {
+            let x2 = z_u2 * x1;
+            let y2 = g(&x2).sqrt().expect("Z is not a square in the field");
+            (x2, y2)
+        }
+    };
+    let y = if u.sgn0() == y.sgn0() { y } else { -y };
+
+    P::isogeny_map(Affine::new(x, y))
+}
+
+/// Evaluates an isogeny given by the rational maps `x_num / x_den` and `y * y_num / y_den`,
+/// with the coefficients of the polynomials in order of increasing degree.
+///
+/// Returns `None`, the point at infinity, if one of the denominators vanishes.
+pub fn isogeny_map<F: Field>(
+    point: &Affine<F>,
+    x_num: &[F],
+    x_den: &[F],
+    y_num: &[F],
+    y_den: &[F],
+) -> Option<Affine<F>> {
+    let eval = |coefficients: &[F]| {
+        coefficients
+            .iter()
+            .rev()
+            .fold(F::zero(), |acc, c| acc * point.x + c)
+    };
+    let x = eval(x_num) * eval(x_den).inverse()?;
+    let y = point.y * eval(y_num) * eval(y_den).inverse()?;
+    Some(Affine::new(x, y))
+}
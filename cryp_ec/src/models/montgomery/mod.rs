@@ -0,0 +1,77 @@
+use super::ff::*;
+use cryp_alg::{Bits, Integer};
+
+/// Montgomery Curve parameters
+///
+///  By2 = x3 + Ax2 + x
+/// with B(A^2 - 4) non-zero.
+///
+/// Only the x-coordinate (the `u`-coordinate in RFC 7748) of a point is used, through the
+/// differential ladder of "Speeding the Pollard and elliptic curve methods of factorization"
+/// by P. L. Montgomery.
+pub trait MontgomeryCurve {
+    /// The field over which the curve is defined
+    type Field: Field;
+
+    /// The parameter A
+    const A: Self::Field;
+
+    /// The parameter B
+    const B: Self::Field;
+
+    /// The element (A - 2) / 4
+    const A24: Self::Field;
+
+    fn verify() -> bool {
+        let four = Self::Field::one().double().double();
+        Self::A24 * four + Self::Field::one().double() == Self::A
+    }
+
+    /// Computes the x-coordinate of `scalar * P` for a point `P` with x-coordinate `u`.
+    ///
+    /// This is the ladder of RFC 7748, section 5. It processes every bit of the integer
+    /// with the same sequence of field operations, the swaps of the two accumulators being
+    /// performed with arithmetic instead of branches. The point at infinity, and the point
+    /// `(0, 0)`, are both sent to zero.
+    fn ladder(u: &Self::Field, scalar: &impl Integer) -> Self::Field {
+        let x1 = *u;
+        let (mut x2, mut z2) = (Self::Field::one(), Self::Field::zero());
+        let (mut x3, mut z3) = (*u, Self::Field::one());
+        let mut swap = false;
+
+        for bit in Bits::into_iter_be(scalar) {
+            swap ^= bit;
+            conditional_swap(&mut x2, &mut x3, swap);
+            conditional_swap(&mut z2, &mut z3, swap);
+            swap = bit;
+
+            let a = x2 + z2;
+            let aa = a.square();
+            let b = x2 - z2;
+            let bb = b.square();
+            let e = aa - bb;
+            let c = x3 + z3;
+            let d = x3 - z3;
+            let da = d * a;
+            let cb = c * b;
+
+            x3 = (da + cb).square();
+            z3 = x1 * (da - cb).square();
+            x2 = aa * bb;
+            z2 = e * (aa + Self::A24 * e);
+        }
+        conditional_swap(&mut x2, &mut x3, swap);
+        conditional_swap(&mut z2, &mut z3, swap);
+
+        z2.inverse()
+            .map_or(Self::Field::zero(), |inverse| x2 * inverse)
+    }
+}
+
+/// Swaps `a` and `b` if `choice` is set, as `a - c * (a - b)` and `b + c * (a - b)`.
+fn conditional_swap<F: Field>(a: &mut F, b: &mut F, choice: bool) {
+    let mask = [F::zero(), F::one()][choice as usize];
+    let diff = mask * (*a - *b);
+    *a -= diff;
+    *b += diff;
+}
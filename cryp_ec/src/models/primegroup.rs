@@ -1,4 +1,5 @@
 use core::borrow::Borrow;
+use cryp_alg::hash::label_digest;
 use cryp_alg::{Group, PrimeGroup};
 use cryp_std::{
     fmt::{Debug, Display},
@@ -21,10 +22,19 @@ pub use subgroup::PrimeSubGroupConfig;
 /// The group can come from a prime order subgroup (as is most common) or even
 /// be a quotient group such as in the case of Ristretto.
 pub trait PrimeGroupConfig: CurveOperations + Debug + Sized + 'static + PartialEq + Eq {
-    type Public: Into<Self::Point> + Send + Sync + Hash + PartialEq + Eq + Clone + Copy;
+    type Public: Into<Self::Point> + Send + Sync + Hash + PartialEq + Eq + Clone + Copy + Debug;
     /// Finite field with the same order as the subgroup.
     type ScalarField: PrimeField;
 
+    /// A short name identifying the group.
+    const NAME: &'static str;
+
+    /// The integer type of the cofactor.
+    type Cofactor: Integer + Debug + Clone + Copy + PartialEq + Eq + Send + Sync + 'static;
+
+    /// The cofactor of the group in the curve it is defined on.
+    const COFACTOR: Self::Cofactor;
+
     /// Gives a generator of the group.
     fn generator<R: Rng>(rng: Option<&mut R>) -> Self::Public;
 
@@ -34,6 +44,16 @@ pub trait PrimeGroupConfig: CurveOperations + Debug + Sized + 'static + PartialE
     /// Attempts to convert a `Point` element to a `Public`.
     fn as_public(input: &Self::Point) -> Option<Self::Public>;
 
+    /// Attempts to convert several `Point` elements to `Public`.
+    ///
+    /// The default implementation converts the points one by one.
+    fn batch_as_public(input: &[Self::Point]) -> Vec<Option<Self::Public>> {
+        input.iter().map(Self::as_public).collect()
+    }
+
+    /// The canonical compressed encoding of a `Public` element.
+    fn compress(input: &Self::Public) -> Vec<u8>;
+
     /// Adding a point to a point in the public representation.
     fn add_public_in_place(lhs: &mut Self::Point, rhs: &Self::Public);
 
@@ -41,7 +61,34 @@ pub trait PrimeGroupConfig: CurveOperations + Debug + Sized + 'static + PartialE
     ///
     /// The generators should be independent in the sense that the mutual
     /// discrete logarithms are not known.
-    fn batch_generators<R: Rng>(n: usize, rng: &mut R) -> Vec<Self::Public>;
+    ///
+    /// The default implementation derives the generators from a random label, so that their
+    /// discrete logarithms are not known to anyone, including the caller.
+    fn batch_generators<R: Rng>(n: usize, rng: &mut R) -> Vec<Self::Public> {
+        let mut label = [0u8; 32];
+        rng.fill_bytes(&mut label);
+        Self::batch_generators_from_label(&label, n)
+    }
+
+    /// Derives `n` generators of the group by hashing a label, see
+    /// `PrimeGroup::batch_generators_from_label`.
+    fn batch_generators_from_label(label: &[u8], n: usize) -> Vec<Self::Public>;
+
+    /// Hashes a message to the group with the `hash_to_curve` function of the RFC 9380 suite
+    /// of the curve, using the domain separation tag `dst`.
+    ///
+    /// The default implementation returns `None`, for groups without a suite.
+    fn hash_to_curve(_msg: &[u8], _dst: &[u8]) -> Option<Self::Point> {
+        None
+    }
+
+    /// Encodes a message to the group with the `encode_to_curve` function of the RFC 9380
+    /// suite of the curve, using the domain separation tag `dst`.
+    ///
+    /// The default implementation returns `None`, for groups without a suite.
+    fn encode_to_curve(_msg: &[u8], _dst: &[u8]) -> Option<Self::Point> {
+        None
+    }
 
     /// Scalar multiplication in constant time.
     ///
@@ -80,6 +127,21 @@ pub trait PrimeGroupConfig: CurveOperations + Debug + Sized + 'static + PartialE
         I::Item: Borrow<Self::Public>,
         J: IntoIterator,
         J::Item: Borrow<Self::ScalarField>;
+
+    /// Multi-scalar multiplication with a vector of public scalars.
+    ///
+    /// Does not run in constant time. The default implementation uses the bucket method
+    /// with the bases in cached representation.
+    fn msm_non_ct<I, J>(bases: I, scalars: J) -> Self::Point
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Self::Public>,
+        J: IntoIterator,
+        J::Item: Borrow<Self::ScalarField>,
+    {
+        let points = bases.into_iter().map(|b| -> Self::Point { (*b.borrow()).into() });
+        scalar_mul::VariableBaseMSM::msm_pippenger::<Self, _, _, _>(points, scalars)
+    }
 }
 
 #[derive(Debug)]
@@ -116,11 +178,27 @@ impl<P: PrimeGroupConfig> Group for GroupEC<P> {
     fn double_in_place(&mut self) {
         P::double_in_place(&mut self.point);
     }
+
+    /// Uses the curve operations directly, so that runs of doublings can be merged.
+    fn mul_int_non_ct(&self, scalar: &impl Integer) -> Self {
+        Self {
+            point: scalar_mul::ScalarMul::wnaf::<P>(&self.point, scalar),
+        }
+    }
 }
 
 impl<P: PrimeGroupConfig> PrimeGroup for GroupEC<P> {
     type ScalarField = P::ScalarField;
     type Public = PublicEC<P>;
+    type Cofactor = P::Cofactor;
+
+    fn name() -> &'static str {
+        P::NAME
+    }
+
+    fn cofactor() -> Self::Cofactor {
+        P::COFACTOR
+    }
 
     fn is_valid(input: &Self::Public) -> bool {
         P::is_valid(&input.point)
@@ -130,6 +208,14 @@ impl<P: PrimeGroupConfig> PrimeGroup for GroupEC<P> {
         P::as_public(&self.point).map(PublicEC::new)
     }
 
+    fn batch_as_public(elements: &[Self]) -> Vec<Option<Self::Public>> {
+        let points = elements.iter().map(|e| e.point).collect::<Vec<_>>();
+        P::batch_as_public(&points)
+            .into_iter()
+            .map(|public| public.map(PublicEC::new))
+            .collect()
+    }
+
     fn generator<R: Rng>(rng: Option<&mut R>) -> Self::Public {
         PublicEC::new(P::generator(rng))
     }
@@ -141,6 +227,13 @@ impl<P: PrimeGroupConfig> PrimeGroup for GroupEC<P> {
             .collect()
     }
 
+    fn batch_generators_from_label(label: &[u8], n: usize) -> Vec<Self::Public> {
+        P::batch_generators_from_label(label, n)
+            .into_iter()
+            .map(PublicEC::new)
+            .collect()
+    }
+
     fn msm<I, J>(bases: I, scalars: J) -> Self
     where
         I: IntoIterator,
@@ -152,6 +245,17 @@ impl<P: PrimeGroupConfig> PrimeGroup for GroupEC<P> {
         let point = P::msm_pub(bases, scalars);
         Self::new(point)
     }
+
+    fn msm_non_ct<I, J>(bases: I, scalars: J) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Self::Public>,
+        J: IntoIterator,
+        J::Item: Borrow<<Self as PrimeGroup>::ScalarField>,
+    {
+        let bases = bases.into_iter().map(|b| b.borrow().point);
+        Self::new(P::msm_non_ct(bases, scalars))
+    }
 }
 
 impl<P: PrimeGroupConfig> PublicEC<P> {
@@ -166,6 +270,55 @@ impl<P: PrimeGroupConfig> PublicEC<P> {
 
 // -----------------------------------------
 
+// Generators derived from a label
+
+// -----------------------------------------
+
+/// Derives `n` generators from a label by trying the digests `hash::label_digest` for
+/// increasing counters until `map` gives a point that is not the identity.
+///
+/// The map should send digests to points of the group, or to `None` if it fails.
+pub(crate) fn generators_from_label<P: PrimeGroupConfig>(
+    label: &[u8],
+    n: usize,
+    map: impl Fn(&[u8; 64]) -> Option<P::Point>,
+) -> Vec<P::Public> {
+    (0..n as u64)
+        .map(|index| {
+            (0..)
+                .filter_map(|counter| map(&label_digest(P::NAME, label, index, counter)))
+                .filter(|point| *point != P::identity())
+                .find_map(|point| P::as_public(&point))
+                .unwrap()
+        })
+        .collect()
+}
+
+/// Checks the generators of `batch_generators` and `batch_generators_from_label`, and pins
+/// the encoding of the first generator derived from the label `test`.
+#[cfg(test)]
+pub(crate) fn check_generators<G: PrimeGroup>(expected: &str) {
+    use cryp_std::rand::thread_rng;
+    use cryp_std::string::ToString;
+
+    let generators = G::batch_generators_from_label(b"test", 5);
+    for (i, g) in generators.iter().enumerate() {
+        assert!(G::is_valid(g));
+        assert!(!generators[..i].contains(g));
+    }
+    assert_eq!(generators[0].to_string(), expected);
+    assert_eq!(G::batch_generators_from_label(b"test", 2), generators[..2]);
+    assert_ne!(G::batch_generators_from_label(b"other", 1), generators[..1]);
+
+    let generators = G::batch_generators(5, &mut thread_rng());
+    for (i, g) in generators.iter().enumerate() {
+        assert!(G::is_valid(g));
+        assert!(!generators[..i].contains(g));
+    }
+}
+
+// -----------------------------------------
+
 // Basic traits (Clone, Copy, Hash) for GroupEC and PublicEC
 
 //------------------------------------------
@@ -184,6 +337,16 @@ impl<P: PrimeGroupConfig> GroupEC<P> {
     pub fn new(point: P::Point) -> Self {
         Self { point }
     }
+
+    /// Hashes a message to the group, see `PrimeGroupConfig::hash_to_curve`.
+    pub fn hash_to_curve(msg: &[u8], dst: &[u8]) -> Option<Self> {
+        P::hash_to_curve(msg, dst).map(Self::new)
+    }
+
+    /// Encodes a message to the group, see `PrimeGroupConfig::encode_to_curve`.
+    pub fn encode_to_curve(msg: &[u8], dst: &[u8]) -> Option<Self> {
+        P::encode_to_curve(msg, dst).map(Self::new)
+    }
 }
 
 impl<P: PrimeGroupConfig> Hash for GroupEC<P> {
@@ -192,17 +355,19 @@ impl<P: PrimeGroupConfig> Hash for GroupEC<P> {
     }
 }
 
+/// The compressed encoding of the point in hexadecimal, if it has a public representation.
 impl<P: PrimeGroupConfig> Display for GroupEC<P> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(f, "GroupEC({})", self.point)
+        match self.as_public() {
+            Some(public) => write!(f, "{}", public),
+            None => write!(f, "GroupEC({})", self.point),
+        }
     }
 }
 
 impl<P: PrimeGroupConfig> Clone for GroupEC<P> {
     fn clone(&self) -> Self {
-        Self {
-            point: self.point.clone(),
-        }
+        *self
     }
 }
 
@@ -220,11 +385,16 @@ impl<P: PrimeGroupConfig> PartialEq for PublicEC<P> {
 
 impl<P: PrimeGroupConfig> Eq for PublicEC<P> {}
 
+/// The compressed encoding of the point in hexadecimal.
+impl<P: PrimeGroupConfig> Display for PublicEC<P> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        Bytes::fmt_hex(P::compress(&self.point), f)
+    }
+}
+
 impl<P: PrimeGroupConfig> Clone for PublicEC<P> {
     fn clone(&self) -> Self {
-        Self {
-            point: self.point,
-        }
+        *self
     }
 }
 
@@ -294,6 +464,7 @@ impl<P: PrimeGroupConfig> SubAssign<&GroupEC<P>> for GroupEC<P>
 where
     P: PrimeGroupConfig,
 {
+    #[allow(clippy::suspicious_op_assign_impl)]
     fn sub_assign(&mut self, other: &GroupEC<P>) {
         P::neg_in_place(&mut self.point);
         *self += other;
@@ -455,6 +626,7 @@ impl<P: PrimeGroupConfig> SubAssign<&PublicEC<P>> for GroupEC<P>
 where
     P: PrimeGroupConfig,
 {
+    #[allow(clippy::suspicious_op_assign_impl)]
     fn sub_assign(&mut self, other: &PublicEC<P>) {
         P::neg_in_place(&mut self.point);
         *self += other;
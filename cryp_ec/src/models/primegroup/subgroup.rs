@@ -10,20 +10,78 @@ pub trait PrimeSubGroupConfig: CurveOperations + Debug + Sized + 'static + Eq +
     /// Finite field with the same order as the subgroup.
     type ScalarField: PrimeField;
 
+    /// A short name identifying the group.
+    const NAME: &'static str;
+
+    /// The integer type of the cofactor, usually `[u64; N]` for a small `N`.
+    type Cofactor: Integer + Debug + Clone + Copy + PartialEq + Eq + Send + Sync + 'static;
+
     /// The cofactor of the curve
     /// This is the number of points on the curve divided by the order of the group.
-    ///
-    /// Cofactors are usually small, so we use a `u16` to represent them.
-    const COFACTOR: u32;
+    const COFACTOR: Self::Cofactor;
 
     /// Gives a generator of the group.
     fn generator<R: Rng>(rng: Option<&mut R>) -> Self::Affine;
 
+    /// Checks that a point of the curve is in the prime order subgroup.
+    ///
+    /// The default implementation multiplies the point by the order of the subgroup
+    /// in variable time. Curves of prime order and curves with an efficient endomorphism
+    /// can provide a faster check.
+    fn is_in_subgroup(point: &Self::Affine) -> bool {
+        let order = Self::ScalarField::MODULUS;
+        scalar_mul::ScalarMul::wnaf::<Self>(&(*point).into(), &order) == Self::identity()
+    }
+
+    /// Multiplies a point by the cofactor.
+    ///
+    /// The cofactor is public, so the default implementation runs in variable time.
+    fn mul_by_cofactor(point: &Self::Point) -> Self::Point {
+        scalar_mul::ScalarMul::wnaf::<Self>(point, &Self::COFACTOR)
+    }
+
+    /// Maps a point of the curve to the prime order subgroup.
+    ///
+    /// The map is a multiplication by a multiple of the cofactor that is coprime to the order
+    /// of the subgroup. The default implementation multiplies by the cofactor, curves can
+    /// override it with a faster map, e.g. the effective cofactors of RFC 9380.
+    fn clear_cofactor(point: &Self::Point) -> Self::Point {
+        Self::mul_by_cofactor(point)
+    }
+
+    /// The canonical compressed encoding of a point of the subgroup.
+    fn compress(input: &Self::Affine) -> Vec<u8>;
+
     /// Gives a vector of generators of the group of size `n`.
     ///
     /// The generators should be independent in the sense that the mutual
     /// discrete logarithms are not known.
-    fn batch_generators<R: Rng>(n: usize, rng: &mut R) -> Vec<Self::Affine>;
+    ///
+    /// The default implementation derives the generators from a random label, see
+    /// `PrimeGroupConfig::batch_generators`.
+    fn batch_generators<R: Rng>(n: usize, rng: &mut R) -> Vec<Self::Affine> {
+        let mut label = [0u8; 32];
+        rng.fill_bytes(&mut label);
+        Self::batch_generators_from_label(&label, n)
+    }
+
+    /// Derives `n` generators of the subgroup by hashing a label, see
+    /// `PrimeGroup::batch_generators_from_label`.
+    fn batch_generators_from_label(label: &[u8], n: usize) -> Vec<Self::Affine>;
+
+    /// Hashes a message to the subgroup, see `PrimeGroupConfig::hash_to_curve`.
+    ///
+    /// Curves with a `HashToCurveConfig` suite can forward to `hash_to_curve`.
+    fn hash_to_curve(_msg: &[u8], _dst: &[u8]) -> Option<Self::Point> {
+        None
+    }
+
+    /// Encodes a message to the subgroup, see `PrimeGroupConfig::encode_to_curve`.
+    ///
+    /// Curves with a `HashToCurveConfig` suite can forward to `encode_to_curve`.
+    fn encode_to_curve(_msg: &[u8], _dst: &[u8]) -> Option<Self::Point> {
+        None
+    }
 
     /// Scalar multiplication in constant time.
     ///
@@ -78,21 +136,29 @@ where
     type Public = T::Affine;
     type ScalarField = T::ScalarField;
 
-    fn is_valid(input: &Self::Public) -> bool {
-        // An element is valid if input^MODULUS = identity
-        let power = -T::ScalarField::one();
-        let base = (*input).into();
+    const NAME: &'static str = <T as PrimeSubGroupConfig>::NAME;
+    type Cofactor = <T as PrimeSubGroupConfig>::Cofactor;
 
-        let mut base_power = T::scalar_mul(&base, &power);
-        T::add_in_place(&mut base_power, &base);
+    const COFACTOR: Self::Cofactor = <T as PrimeSubGroupConfig>::COFACTOR;
 
-        base_power == T::identity()
+    /// An element is valid if it is on the curve and in the prime order subgroup.
+    fn is_valid(input: &Self::Public) -> bool {
+        T::is_on_curve(input) && T::is_in_subgroup(input)
     }
 
     fn as_public(input: &Self::Point) -> Option<Self::Public> {
         input.into_affine()
     }
 
+    /// Shares a single field inversion between the points, see `Coordinates::normalize_batch`.
+    fn batch_as_public(input: &[Self::Point]) -> Vec<Option<Self::Public>> {
+        Self::Point::normalize_batch(input)
+    }
+
+    fn compress(input: &Self::Public) -> Vec<u8> {
+        <T as PrimeSubGroupConfig>::compress(input)
+    }
+
     fn add_public_in_place(lhs: &mut Self::Point, rhs: &Self::Public) {
         T::add_affine_in_place(lhs, rhs)
     }
@@ -105,6 +171,18 @@ where
         Self::batch_generators(n, rng)
     }
 
+    fn batch_generators_from_label(label: &[u8], n: usize) -> Vec<Self::Public> {
+        <T as PrimeSubGroupConfig>::batch_generators_from_label(label, n)
+    }
+
+    fn hash_to_curve(msg: &[u8], dst: &[u8]) -> Option<Self::Point> {
+        <T as PrimeSubGroupConfig>::hash_to_curve(msg, dst)
+    }
+
+    fn encode_to_curve(msg: &[u8], dst: &[u8]) -> Option<Self::Point> {
+        <T as PrimeSubGroupConfig>::encode_to_curve(msg, dst)
+    }
+
     fn scalar_mul(base: &Self::Point, scalar: &Self::ScalarField) -> Self::Point {
         T::scalar_mul(base, scalar)
     }
@@ -133,3 +211,31 @@ where
         T::msm_pub(bases, scalars)
     }
 }
+
+impl<P: PrimeSubGroupConfig> GroupEC<P> {
+    /// Multiplies the point by the cofactor of the curve.
+    pub fn mul_by_cofactor(&self) -> Self {
+        Self::new(P::mul_by_cofactor(&self.point))
+    }
+
+    /// Maps the point to the prime order subgroup, see `PrimeSubGroupConfig::clear_cofactor`.
+    pub fn clear_cofactor(&self) -> Self {
+        Self::new(P::clear_cofactor(&self.point))
+    }
+
+    /// Checks if the order of the point divides the cofactor.
+    ///
+    /// This is the case for the identity and for the points of the curve that have a
+    /// trivial component in the prime order subgroup.
+    pub fn is_small_order(&self) -> bool {
+        self.mul_by_cofactor() == Self::identity()
+    }
+
+    /// Checks if the point has no component of small order, i.e. it is in the prime order
+    /// subgroup.
+    pub fn is_torsion_free(&self) -> bool {
+        self.point
+            .into_affine()
+            .is_none_or(|point| P::is_in_subgroup(&point))
+    }
+}
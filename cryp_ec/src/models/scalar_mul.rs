@@ -8,14 +8,18 @@
 
 use super::CurveOperations;
 
-use super::Coordinates;
 use core::borrow::Borrow;
 use cryp_alg::PrimeField;
-use cryp_alg::{Bits, Integer};
+use cryp_alg::{Bits, Integer, Wnaf};
+use cryp_std::vec::Vec;
+
+/// The window width used for variable time scalar multiplication.
+const WNAF_WIDTH: usize = 5;
 
 pub struct ScalarMul;
 
 pub struct VariableBaseMSM;
+#[allow(dead_code)]
 pub struct FixedBaseMSM;
 
 impl ScalarMul {
@@ -41,6 +45,49 @@ impl ScalarMul {
         }
         res
     }
+
+    /// Variable time scalar multiplication using the width-`w` non-adjacent form of the scalar.
+    ///
+    /// The doublings between two non-zero digits are performed by a single call to
+    /// `double_n_in_place`.
+    pub fn wnaf<C: CurveOperations>(base: &C::Point, scalar: &impl Integer) -> C::Point {
+        let digits = Wnaf::recode(scalar, WNAF_WIDTH);
+
+        // table[i] = (2i + 1) * base and neg_table[i] = -(2i + 1) * base, in cached form
+        let mut double = *base;
+        C::double_in_place(&mut double);
+        let double = C::to_cached(&double);
+        let mut table = Vec::with_capacity(1 << (WNAF_WIDTH - 2));
+        let mut neg_table = Vec::with_capacity(1 << (WNAF_WIDTH - 2));
+        let mut multiple = *base;
+        for _ in 0..(1 << (WNAF_WIDTH - 2)) {
+            table.push(C::to_cached(&multiple));
+            let mut neg = multiple;
+            C::neg_in_place(&mut neg);
+            neg_table.push(C::to_cached(&neg));
+            C::add_cached_in_place(&mut multiple, &double);
+        }
+
+        let mut res = C::identity();
+        let mut doublings = 0;
+        for digit in digits.iter().rev() {
+            doublings += 1;
+            if *digit == 0 {
+                continue;
+            }
+            C::double_n_in_place(&mut res, doublings);
+            doublings = 0;
+
+            let index = (digit.unsigned_abs() / 2) as usize;
+            if *digit > 0 {
+                C::add_cached_in_place(&mut res, &table[index]);
+            } else {
+                C::add_cached_in_place(&mut res, &neg_table[index]);
+            }
+        }
+        C::double_n_in_place(&mut res, doublings);
+        res
+    }
 }
 
 impl VariableBaseMSM {
@@ -53,7 +100,7 @@ impl VariableBaseMSM {
         N: PrimeField,
     {
         let mut res = C::identity();
-        for (base, scalar) in bases.into_iter().zip(scalars.into_iter()) {
+        for (base, scalar) in bases.into_iter().zip(scalars) {
             C::add_in_place(
                 &mut res,
                 &ScalarMul::montgomery_ladder::<C>(base.borrow(), &scalar.borrow().as_int()),
@@ -61,4 +108,63 @@ impl VariableBaseMSM {
         }
         res
     }
+
+    /// Multi-scalar multiplication with public scalars using the bucket method of Pippenger.
+    ///
+    /// The bases are converted to the cached representation once, as each of them is added
+    /// to a bucket in every window. Does not run in constant time.
+    pub fn msm_pippenger<C: CurveOperations, I, J, N>(bases: I, scalars: J) -> C::Point
+    where
+        I: IntoIterator,
+        I::Item: Borrow<C::Point>,
+        J: IntoIterator,
+        J::Item: Borrow<N>,
+        N: PrimeField,
+    {
+        let (bases, scalars): (Vec<_>, Vec<_>) = bases
+            .into_iter()
+            .zip(scalars)
+            .map(|(base, scalar)| {
+                let mut bits = Bits::into_iter_be(&scalar.borrow().as_int()).collect::<Vec<_>>();
+                bits.reverse();
+                (C::to_cached(base.borrow()), bits)
+            })
+            .unzip();
+
+        // window size of about ln(n) + 2 bits
+        let window = match bases.len() {
+            0..=31 => 3,
+            n => (usize::BITS - n.leading_zeros()) as usize * 69 / 100 + 2,
+        };
+        let num_bits = Bits::into_iter_be(&N::MODULUS)
+            .skip_while(|bit| !bit)
+            .count();
+
+        let mut res = C::identity();
+        for start in (0..num_bits).step_by(window).rev() {
+            C::double_n_in_place(&mut res, window);
+
+            // buckets[i] is the sum of the bases whose digit in the window is i + 1
+            let mut buckets = cryp_std::vec![C::identity(); (1 << window) - 1];
+            for (base, bits) in bases.iter().zip(scalars.iter()) {
+                let digit = bits
+                    .iter()
+                    .skip(start)
+                    .take(window)
+                    .rev()
+                    .fold(0, |acc, bit| (acc << 1) | *bit as usize);
+                if digit != 0 {
+                    C::add_cached_in_place(&mut buckets[digit - 1], base);
+                }
+            }
+
+            // sum of (i + 1) * buckets[i]
+            let mut running_sum = C::identity();
+            for bucket in buckets.iter().rev() {
+                C::add_in_place(&mut running_sum, bucket);
+                C::add_in_place(&mut res, &running_sum);
+            }
+        }
+        res
+    }
 }
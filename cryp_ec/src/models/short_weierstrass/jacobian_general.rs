@@ -3,6 +3,11 @@ use super::*;
 /// Formulas from Faster addition and doubling on elliptic curves
 ///
 /// https://hyperelliptic.org/EFD/g1p/auto-shortw-jacobian.html#addition-add-2007-bl
+///
+/// The addition formulas are not unified, so the cases where one of the points is the
+/// identity or the two points are equal are handled separately. These branches make the
+/// operations not run in constant time.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct ShortWeierstrassOperations<P: ShortWeierstrass> {
     _parameters: cryp_std::marker::PhantomData<P>,
 }
@@ -11,6 +16,7 @@ impl<P: ShortWeierstrass> CurveOperations for ShortWeierstrassOperations<P> {
     type Field = P::Field;
     type Point = JacobianPoint<P::Field>;
     type Affine = Affine<P::Field>;
+    type Cached = Self::Point;
 
     const UNIFIED: bool = false;
 
@@ -21,12 +27,24 @@ impl<P: ShortWeierstrass> CurveOperations for ShortWeierstrassOperations<P> {
             Z: Self::Field::zero(),
         }
     }
+
+    /// Checks `y^2 = x^3 + Ax + B`.
+    fn is_on_curve(point: &Self::Affine) -> bool {
+        point.y.square() == (point.x.square() + P::A) * point.x + P::B
+    }
     fn neg_in_place(point: &mut Self::Point) {
         point.Y = -point.Y;
     }
 
     #[allow(non_snake_case)]
     fn add_in_place(lhs: &mut Self::Point, rhs: &Self::Point) {
+        if rhs.Z == P::Field::zero() {
+            return;
+        }
+        if lhs.Z == P::Field::zero() {
+            *lhs = *rhs;
+            return;
+        }
         let (X1, Y1, Z1) = (lhs.X, lhs.Y, lhs.Z);
         let (X2, Y2, Z2) = (rhs.X, rhs.Y, rhs.Z);
 
@@ -37,9 +55,18 @@ impl<P: ShortWeierstrass> CurveOperations for ShortWeierstrassOperations<P> {
         let S1 = Y1 * Z2 * Z2Z2;
         let S2 = Y2 * Z1 * Z1Z1;
         let H = U2 - U1;
+        let r = (S2 - S1).double();
+        if H == P::Field::zero() {
+            // the points have the same x-coordinate, so either P == Q or P == -Q
+            if r == P::Field::zero() {
+                Self::double_in_place(lhs);
+            } else {
+                *lhs = Self::identity();
+            }
+            return;
+        }
         let I = (H + H).square();
         let J = H * I;
-        let r = (S2 - S1).double();
         let V = U1 * I;
         lhs.X = r.square() - J - V.double();
         lhs.Y = r * (V - lhs.X) - S1 * J.double();
@@ -48,6 +75,10 @@ impl<P: ShortWeierstrass> CurveOperations for ShortWeierstrassOperations<P> {
 
     #[allow(non_snake_case)]
     fn add_affine_in_place(lhs: &mut Self::Point, rhs: &Self::Affine) {
+        if lhs.Z == P::Field::zero() {
+            *lhs = (*rhs).into();
+            return;
+        }
         let (X1, Y1, Z1) = (lhs.X, lhs.Y, lhs.Z);
         let (x2, y2) = (rhs.x, rhs.y);
 
@@ -57,10 +88,18 @@ impl<P: ShortWeierstrass> CurveOperations for ShortWeierstrassOperations<P> {
         let U2 = x2 * Z1Z1;
         let S2 = y2 * Z1 * Z1Z1;
         let H = U2 - X1;
+        let r = (S2 - Y1).double();
+        if H == P::Field::zero() {
+            if r == P::Field::zero() {
+                Self::double_in_place(lhs);
+            } else {
+                *lhs = Self::identity();
+            }
+            return;
+        }
         let HH = H.square();
-        let I = HH + HH;
+        let I = HH.double().double();
         let J = H * I;
-        let r = (S2 - Y1).double();
         let V = X1 * I;
         lhs.X = r.square() - J - V.double();
         lhs.Y = r * (V - lhs.X) - Y1 * J.double();
@@ -76,11 +115,19 @@ impl<P: ShortWeierstrass> CurveOperations for ShortWeierstrassOperations<P> {
         let YY = Y.square();
         let YYYY = YY.square();
         let ZZ = Z.square();
-        let S = ((X + YY).square()).double() - XX - YYYY;
+        let S = ((X + YY).square() - XX - YYYY).double();
         let M = XX.double() + XX + P::A * ZZ.square();
         let T = M.square() - S.double();
         point.X = T;
         point.Y = M * (S - T) - YYYY.double().double().double();
         point.Z = (Y + Z).square() - YY - ZZ;
     }
+
+    fn to_cached(point: &Self::Point) -> Self::Cached {
+        *point
+    }
+
+    fn add_cached_in_place(lhs: &mut Self::Point, rhs: &Self::Cached) {
+        Self::add_in_place(lhs, rhs);
+    }
 }
@@ -1,10 +1,26 @@
 use super::coordinates::{Affine, JacobianPoint};
 use super::ff::*;
-use super::CurveOperations;
+use super::{field_from_bytes_be, CurveOperations};
+use cryp_std::vec::Vec;
 
 mod jacobian_general;
+mod projective_complete;
+#[cfg(test)]
+pub(crate) mod test_curve;
 
 pub use jacobian_general::ShortWeierstrassOperations;
+pub use projective_complete::ShortWeierstrassCompleteOperations;
+
+/// Special values of the coefficient `A` for which faster formulas are available.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CoefficientKind {
+    /// `A = 0`, e.g. secp256k1 or BLS12-381.
+    Zero,
+    /// `A = -3`, e.g. the NIST curves.
+    MinusThree,
+    /// Any other value of `A`.
+    General,
+}
 
 /// A trait for the parameters of a short Weierstrass curve.
 ///
@@ -14,4 +30,64 @@ pub trait ShortWeierstrass {
 
     const A: Self::Field;
     const B: Self::Field;
+
+    /// The kind of the coefficient `A`, used to select specialized formulas.
+    ///
+    /// It must be consistent with `A`; the default is always correct.
+    const A_KIND: CoefficientKind = CoefficientKind::General;
+}
+
+/// The compressed SEC1 encoding of a point in affine coordinates.
+///
+/// The encoding is the byte `0x02` or `0x03` according to the parity of `y`, followed by
+/// the big endian encoding of `x`.
+pub fn sec1_compress<F: PrimeField>(point: &Affine<F>) -> Vec<u8> {
+    let y_bytes = Bytes::into_iter_be(&point.y.as_int()).collect::<Vec<_>>();
+    let parity = y_bytes.last().map_or(0, |b| b & 1);
+
+    let mut bytes = Vec::with_capacity(y_bytes.len() + 1);
+    bytes.push(0x02 | parity);
+    bytes.extend(Bytes::into_iter_be(&point.x.as_int()));
+    bytes
+}
+
+/// The uncompressed SEC1 encoding of a point in affine coordinates.
+///
+/// The encoding is the byte `0x04` followed by the big endian encodings of `x` and `y`.
+pub fn sec1_uncompressed<F: PrimeField>(point: &Affine<F>) -> Vec<u8> {
+    let mut bytes = cryp_std::vec![0x04];
+    bytes.extend(Bytes::into_iter_be(&point.x.as_int()));
+    bytes.extend(Bytes::into_iter_be(&point.y.as_int()));
+    bytes
+}
+
+/// Decodes a compressed or uncompressed SEC1 encoding of a point of the curve.
+///
+/// Returns `None` for the encoding `0x00` of the point at infinity, for coordinates that
+/// are not canonical and for points that are not on the curve.
+pub fn sec1_decompress<P: ShortWeierstrass>(bytes: &[u8]) -> Option<Affine<P::Field>>
+where
+    P::Field: PrimeField,
+{
+    let (prefix, coordinates) = bytes.split_first()?;
+    let rhs = |x: &P::Field| x.square() * x + P::A * x + P::B;
+
+    match prefix {
+        0x02 | 0x03 => {
+            let x = field_from_bytes_be::<P::Field>(coordinates)?;
+            let mut y = rhs(&x).sqrt()?;
+            let parity = Bytes::into_iter_be(&y.as_int()).last().map_or(0, |b| b & 1);
+            if parity != prefix & 1 {
+                y = -y;
+            }
+            Some(Affine::new(x, y))
+        }
+        0x04 if coordinates.len() % 2 == 0 => {
+            let (x, y) = coordinates.split_at(coordinates.len() / 2);
+            let x = field_from_bytes_be::<P::Field>(x)?;
+            let y = field_from_bytes_be::<P::Field>(y)?;
+            (y.square() == rhs(&x)).then(|| Affine::new(x, y))
+        }
+        _ => None,
+    }
 }
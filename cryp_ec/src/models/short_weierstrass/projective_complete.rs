@@ -0,0 +1,456 @@
+use super::*;
+use crate::models::coordinates::Projective;
+
+/// Complete formulas from Complete addition formulas for prime order elliptic curves
+/// by Renes, Costello and Batina.
+///
+/// https://eprint.iacr.org/2015/1060
+///
+/// The formulas are correct for all pairs of input points, including the identity and
+/// equal points, when the curve has odd order. The coefficient kind `P::A_KIND` selects
+/// the specialized formulas for `A = 0` (algorithms 7-9) and `A = -3` (algorithms 4-6);
+/// other curves use the general formulas (algorithms 1-3).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ShortWeierstrassCompleteOperations<P: ShortWeierstrass> {
+    _parameters: cryp_std::marker::PhantomData<P>,
+}
+
+impl<P: ShortWeierstrass> CurveOperations for ShortWeierstrassCompleteOperations<P> {
+    type Field = P::Field;
+    type Point = Projective<P::Field>;
+    type Affine = Affine<P::Field>;
+    type Cached = Self::Point;
+
+    const UNIFIED: bool = true;
+
+    fn identity() -> Self::Point {
+        Projective {
+            X: Self::Field::zero(),
+            Y: Self::Field::one(),
+            Z: Self::Field::zero(),
+        }
+    }
+
+    /// Checks `y^2 = x^3 + Ax + B`.
+    fn is_on_curve(point: &Self::Affine) -> bool {
+        point.y.square() == (point.x.square() + P::A) * point.x + P::B
+    }
+
+    fn neg_in_place(point: &mut Self::Point) {
+        point.Y = -point.Y;
+    }
+
+    fn add_in_place(lhs: &mut Self::Point, rhs: &Self::Point) {
+        *lhs = match P::A_KIND {
+            CoefficientKind::Zero => Self::add_a_zero(lhs, rhs),
+            CoefficientKind::MinusThree => Self::add_a_minus_three(lhs, rhs),
+            CoefficientKind::General => Self::add_general(lhs, rhs),
+        };
+    }
+
+    fn add_affine_in_place(lhs: &mut Self::Point, rhs: &Self::Affine) {
+        *lhs = match P::A_KIND {
+            CoefficientKind::Zero => Self::add_affine_a_zero(lhs, rhs),
+            CoefficientKind::MinusThree => Self::add_affine_a_minus_three(lhs, rhs),
+            CoefficientKind::General => Self::add_affine_general(lhs, rhs),
+        };
+    }
+
+    fn double_in_place(point: &mut Self::Point) {
+        *point = match P::A_KIND {
+            CoefficientKind::Zero => Self::double_a_zero(point),
+            CoefficientKind::MinusThree => Self::double_a_minus_three(point),
+            CoefficientKind::General => Self::double_general(point),
+        };
+    }
+
+    fn to_cached(point: &Self::Point) -> Self::Cached {
+        *point
+    }
+
+    fn add_cached_in_place(lhs: &mut Self::Point, rhs: &Self::Cached) {
+        Self::add_in_place(lhs, rhs);
+    }
+}
+
+#[allow(non_snake_case)]
+impl<P: ShortWeierstrass> ShortWeierstrassCompleteOperations<P> {
+    fn b3() -> P::Field {
+        P::B.double() + P::B
+    }
+
+    /// Algorithm 1: complete addition for general `A`.
+    fn add_general(p: &Projective<P::Field>, q: &Projective<P::Field>) -> Projective<P::Field> {
+        let (X1, Y1, Z1) = (p.X, p.Y, p.Z);
+        let (X2, Y2, Z2) = (q.X, q.Y, q.Z);
+        let (a, b3) = (P::A, Self::b3());
+
+        let mut t0 = X1 * X2;
+        let mut t1 = Y1 * Y2;
+        let mut t2 = Z1 * Z2;
+        let mut t3 = (X1 + Y1) * (X2 + Y2);
+        let mut t4 = t0 + t1;
+        t3 -= t4;
+        t4 = (X1 + Z1) * (X2 + Z2);
+        let mut t5 = t0 + t2;
+        t4 -= t5;
+        t5 = (Y1 + Z1) * (Y2 + Z2);
+        let mut X3 = t1 + t2;
+        t5 -= X3;
+        let mut Z3 = a * t4;
+        X3 = b3 * t2;
+        Z3 += X3;
+        X3 = t1 - Z3;
+        Z3 += t1;
+        let mut Y3 = X3 * Z3;
+        t1 = t0.double() + t0;
+        t2 *= a;
+        t4 *= b3;
+        t1 += t2;
+        t2 = a * (t0 - t2);
+        t4 += t2;
+        t0 = t1 * t4;
+        Y3 += t0;
+        t0 = t5 * t4;
+        X3 *= t3;
+        X3 -= t0;
+        t0 = t3 * t1;
+        Z3 *= t5;
+        Z3 += t0;
+
+        Projective {
+            X: X3,
+            Y: Y3,
+            Z: Z3,
+        }
+    }
+
+    /// Algorithm 2: mixed addition for general `A`.
+    fn add_affine_general(p: &Projective<P::Field>, q: &Affine<P::Field>) -> Projective<P::Field> {
+        let (X1, Y1, Z1) = (p.X, p.Y, p.Z);
+        let (X2, Y2) = (q.x, q.y);
+        let (a, b3) = (P::A, Self::b3());
+
+        let mut t0 = X1 * X2;
+        let mut t1 = Y1 * Y2;
+        let mut t3 = (X2 + Y2) * (X1 + Y1);
+        let mut t4 = t0 + t1;
+        t3 -= t4;
+        t4 = X2 * Z1 + X1;
+        let t5 = Y2 * Z1 + Y1;
+        let mut Z3 = a * t4;
+        let mut X3 = b3 * Z1;
+        Z3 += X3;
+        X3 = t1 - Z3;
+        Z3 += t1;
+        let mut Y3 = X3 * Z3;
+        t1 = t0.double() + t0;
+        let mut t2 = a * Z1;
+        t4 *= b3;
+        t1 += t2;
+        t2 = a * (t0 - t2);
+        t4 += t2;
+        t0 = t1 * t4;
+        Y3 += t0;
+        t0 = t5 * t4;
+        X3 *= t3;
+        X3 -= t0;
+        t0 = t3 * t1;
+        Z3 *= t5;
+        Z3 += t0;
+
+        Projective {
+            X: X3,
+            Y: Y3,
+            Z: Z3,
+        }
+    }
+
+    /// Algorithm 3: doubling for general `A`.
+    fn double_general(p: &Projective<P::Field>) -> Projective<P::Field> {
+        let (X, Y, Z) = (p.X, p.Y, p.Z);
+        let (a, b3) = (P::A, Self::b3());
+
+        let mut t0 = X.square();
+        let t1 = Y.square();
+        let mut t2 = Z.square();
+        let mut t3 = (X * Y).double();
+        let mut Z3 = (X * Z).double();
+        let mut X3 = a * Z3;
+        let mut Y3 = b3 * t2;
+        Y3 += X3;
+        X3 = t1 - Y3;
+        Y3 += t1;
+        Y3 *= X3;
+        X3 *= t3;
+        Z3 *= b3;
+        t2 *= a;
+        t3 = a * (t0 - t2);
+        t3 += Z3;
+        Z3 = t0.double();
+        t0 += Z3;
+        t0 += t2;
+        t0 *= t3;
+        Y3 += t0;
+        t2 = (Y * Z).double();
+        t0 = t2 * t3;
+        X3 -= t0;
+        Z3 = (t2 * t1).double().double();
+
+        Projective {
+            X: X3,
+            Y: Y3,
+            Z: Z3,
+        }
+    }
+
+    /// Algorithm 4: complete addition for `A = -3`.
+    fn add_a_minus_three(
+        p: &Projective<P::Field>,
+        q: &Projective<P::Field>,
+    ) -> Projective<P::Field> {
+        let (X1, Y1, Z1) = (p.X, p.Y, p.Z);
+        let (X2, Y2, Z2) = (q.X, q.Y, q.Z);
+        let b = P::B;
+
+        let mut t0 = X1 * X2;
+        let mut t1 = Y1 * Y2;
+        let mut t2 = Z1 * Z2;
+        let mut t3 = (X1 + Y1) * (X2 + Y2);
+        let mut t4 = t0 + t1;
+        t3 -= t4;
+        t4 = (Y1 + Z1) * (Y2 + Z2);
+        let mut X3 = t1 + t2;
+        t4 -= X3;
+        X3 = (X1 + Z1) * (X2 + Z2);
+        let mut Y3 = t0 + t2;
+        Y3 = X3 - Y3;
+        let mut Z3 = b * t2;
+        X3 = Y3 - Z3;
+        Z3 = X3.double();
+        X3 += Z3;
+        Z3 = t1 - X3;
+        X3 += t1;
+        Y3 *= b;
+        t1 = t2.double();
+        t2 += t1;
+        Y3 -= t2;
+        Y3 -= t0;
+        t1 = Y3.double();
+        Y3 += t1;
+        t1 = t0.double();
+        t0 += t1;
+        t0 -= t2;
+        t1 = t4 * Y3;
+        t2 = t0 * Y3;
+        Y3 = X3 * Z3;
+        Y3 += t2;
+        X3 *= t3;
+        X3 -= t1;
+        Z3 *= t4;
+        t1 = t3 * t0;
+        Z3 += t1;
+
+        Projective {
+            X: X3,
+            Y: Y3,
+            Z: Z3,
+        }
+    }
+
+    /// Algorithm 5: mixed addition for `A = -3`.
+    fn add_affine_a_minus_three(
+        p: &Projective<P::Field>,
+        q: &Affine<P::Field>,
+    ) -> Projective<P::Field> {
+        let (X1, Y1, Z1) = (p.X, p.Y, p.Z);
+        let (X2, Y2) = (q.x, q.y);
+        let b = P::B;
+
+        let mut t0 = X1 * X2;
+        let mut t1 = Y1 * Y2;
+        let mut t3 = (X2 + Y2) * (X1 + Y1);
+        let mut t4 = t0 + t1;
+        t3 -= t4;
+        t4 = Y2 * Z1 + Y1;
+        let mut Y3 = X2 * Z1 + X1;
+        let mut Z3 = b * Z1;
+        let mut X3 = Y3 - Z3;
+        Z3 = X3.double();
+        X3 += Z3;
+        Z3 = t1 - X3;
+        X3 += t1;
+        Y3 *= b;
+        t1 = Z1.double();
+        let mut t2 = t1 + Z1;
+        Y3 -= t2;
+        Y3 -= t0;
+        t1 = Y3.double();
+        Y3 += t1;
+        t1 = t0.double();
+        t0 += t1;
+        t0 -= t2;
+        t1 = t4 * Y3;
+        t2 = t0 * Y3;
+        Y3 = X3 * Z3;
+        Y3 += t2;
+        X3 *= t3;
+        X3 -= t1;
+        Z3 *= t4;
+        t1 = t3 * t0;
+        Z3 += t1;
+
+        Projective {
+            X: X3,
+            Y: Y3,
+            Z: Z3,
+        }
+    }
+
+    /// Algorithm 6: doubling for `A = -3`.
+    fn double_a_minus_three(p: &Projective<P::Field>) -> Projective<P::Field> {
+        let (X, Y, Z) = (p.X, p.Y, p.Z);
+        let b = P::B;
+
+        let mut t0 = X.square();
+        let t1 = Y.square();
+        let mut t2 = Z.square();
+        let mut t3 = (X * Y).double();
+        let mut Z3 = (X * Z).double();
+        let mut Y3 = b * t2;
+        Y3 -= Z3;
+        let mut X3 = Y3.double();
+        Y3 += X3;
+        X3 = t1 - Y3;
+        Y3 += t1;
+        Y3 *= X3;
+        X3 *= t3;
+        t3 = t2.double();
+        t2 += t3;
+        Z3 *= b;
+        Z3 -= t2;
+        Z3 -= t0;
+        t3 = Z3.double();
+        Z3 += t3;
+        t3 = t0.double();
+        t0 += t3;
+        t0 -= t2;
+        t0 *= Z3;
+        Y3 += t0;
+        t0 = (Y * Z).double();
+        Z3 = t0 * Z3;
+        X3 -= Z3;
+        Z3 = (t0 * t1).double().double();
+
+        Projective {
+            X: X3,
+            Y: Y3,
+            Z: Z3,
+        }
+    }
+
+    /// Algorithm 7: complete addition for `A = 0`.
+    fn add_a_zero(p: &Projective<P::Field>, q: &Projective<P::Field>) -> Projective<P::Field> {
+        let (X1, Y1, Z1) = (p.X, p.Y, p.Z);
+        let (X2, Y2, Z2) = (q.X, q.Y, q.Z);
+        let b3 = Self::b3();
+
+        let mut t0 = X1 * X2;
+        let mut t1 = Y1 * Y2;
+        let mut t2 = Z1 * Z2;
+        let mut t3 = (X1 + Y1) * (X2 + Y2);
+        let mut t4 = t0 + t1;
+        t3 -= t4;
+        t4 = (Y1 + Z1) * (Y2 + Z2);
+        let mut X3 = t1 + t2;
+        t4 -= X3;
+        X3 = (X1 + Z1) * (X2 + Z2);
+        let mut Y3 = t0 + t2;
+        Y3 = X3 - Y3;
+        X3 = t0.double();
+        t0 += X3;
+        t2 *= b3;
+        let mut Z3 = t1 + t2;
+        t1 -= t2;
+        Y3 *= b3;
+        X3 = t4 * Y3;
+        t2 = t3 * t1;
+        X3 = t2 - X3;
+        Y3 *= t0;
+        t1 *= Z3;
+        Y3 += t1;
+        t0 *= t3;
+        Z3 *= t4;
+        Z3 += t0;
+
+        Projective {
+            X: X3,
+            Y: Y3,
+            Z: Z3,
+        }
+    }
+
+    /// Algorithm 8: mixed addition for `A = 0`.
+    fn add_affine_a_zero(p: &Projective<P::Field>, q: &Affine<P::Field>) -> Projective<P::Field> {
+        let (X1, Y1, Z1) = (p.X, p.Y, p.Z);
+        let (X2, Y2) = (q.x, q.y);
+        let b3 = Self::b3();
+
+        let mut t0 = X1 * X2;
+        let mut t1 = Y1 * Y2;
+        let mut t3 = (X2 + Y2) * (X1 + Y1);
+        let t4 = t0 + t1;
+        t3 -= t4;
+        let t4 = Y2 * Z1 + Y1;
+        let mut Y3 = X2 * Z1 + X1;
+        let mut X3 = t0.double();
+        t0 += X3;
+        let mut t2 = b3 * Z1;
+        let mut Z3 = t1 + t2;
+        t1 -= t2;
+        Y3 *= b3;
+        X3 = t4 * Y3;
+        t2 = t3 * t1;
+        X3 = t2 - X3;
+        Y3 *= t0;
+        t1 *= Z3;
+        Y3 += t1;
+        t0 *= t3;
+        Z3 *= t4;
+        Z3 += t0;
+
+        Projective {
+            X: X3,
+            Y: Y3,
+            Z: Z3,
+        }
+    }
+
+    /// Algorithm 9: doubling for `A = 0`.
+    fn double_a_zero(p: &Projective<P::Field>) -> Projective<P::Field> {
+        let (X, Y, Z) = (p.X, p.Y, p.Z);
+        let b3 = Self::b3();
+
+        let mut t0 = Y.square();
+        let mut Z3 = t0.double().double().double();
+        let mut t1 = Y * Z;
+        let mut t2 = Z.square();
+        t2 *= b3;
+        let mut X3 = t2 * Z3;
+        let mut Y3 = t0 + t2;
+        Z3 *= t1;
+        t1 = t2.double();
+        t2 += t1;
+        t0 -= t2;
+        Y3 *= t0;
+        Y3 += X3;
+        t1 = X * Y;
+        X3 = (t0 * t1).double();
+
+        Projective {
+            X: X3,
+            Y: Y3,
+            Z: Z3,
+        }
+    }
+}
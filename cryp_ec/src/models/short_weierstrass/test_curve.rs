@@ -0,0 +1,487 @@
+//! Small short Weierstrass curves for testing
+//!
+//! All curves are defined over the prime field of size `p = 1000003`.
+//!
+//! - `TestCurveParameters`: the curve `y^2 = x^3 + 3x + 15` has `1001842 = 2 * 500921` points,
+//!   so it has a prime order subgroup of order `q = 500921` with cofactor 2.
+//! - `TestCurveA0Parameters`: the curve `y^2 = x^3 + 5` has prime order `999007`.
+//! - `TestCurveAM3Parameters`: the curve `y^2 = x^3 - 3x + 1` has prime order `999979`.
+
+use super::*;
+use crate::models::{Coordinates, GroupEC, PrimeSubGroupConfig, PublicEC};
+use cryp_alg::{Group, LimbInt, PrimeGroup};
+use cryp_std::rand::Rng;
+
+pub type FpTest = F<MontgomeryOperations<1, FpTestParams>>;
+pub type ScalarTest = F<MontgomeryOperations<1, ScalarTestParams>>;
+pub type TestCurve = ShortWeierstrassOperations<TestCurveParameters>;
+pub type GroupTest = GroupEC<TestCurve>;
+pub type AffineTest = PublicEC<TestCurve>;
+
+pub type ScalarTestA0 = F<MontgomeryOperations<1, ScalarTestA0Params>>;
+pub type ScalarTestAM3 = F<MontgomeryOperations<1, ScalarTestAM3Params>>;
+pub type GroupTestComplete = GroupEC<ShortWeierstrassCompleteOperations<TestCurveParameters>>;
+pub type GroupTestA0 = GroupEC<ShortWeierstrassCompleteOperations<TestCurveA0Parameters>>;
+pub type GroupTestAM3 = GroupEC<ShortWeierstrassCompleteOperations<TestCurveAM3Parameters>>;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FpTestParams;
+
+impl MontParameters<1usize> for FpTestParams {
+    type Limb = u64;
+
+    const MODULUS: [Self::Limb; 1] = [1000003];
+
+    const R: [Self::Limb; 1] = [350687];
+
+    const R2: [Self::Limb; 1] = [3026];
+
+    const MP: Self::Limb = 2336937208910341525;
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ScalarTestParams;
+
+impl MontParameters<1usize> for ScalarTestParams {
+    type Limb = u64;
+
+    const MODULUS: [Self::Limb; 1] = [500921];
+
+    const R: [Self::Limb; 1] = [117162];
+
+    const R2: [Self::Limb; 1] = [196081];
+
+    const MP: Self::Limb = 8360565346228952695;
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ScalarTestA0Params;
+
+impl MontParameters<1usize> for ScalarTestA0Params {
+    type Limb = u64;
+
+    const MODULUS: [Self::Limb; 1] = [999007];
+
+    const R: [Self::Limb; 1] = [313280];
+
+    const R2: [Self::Limb; 1] = [911713];
+
+    const MP: Self::Limb = 985518244318634593;
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ScalarTestAM3Params;
+
+impl MontParameters<1usize> for ScalarTestAM3Params {
+    type Limb = u64;
+
+    const MODULUS: [Self::Limb; 1] = [999979];
+
+    const R: [Self::Limb; 1] = [284486];
+
+    const R2: [Self::Limb; 1] = [983789];
+
+    const MP: Self::Limb = 4607374001447801725;
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TestCurveParameters;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TestCurveA0Parameters;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TestCurveAM3Parameters;
+
+impl TestCurveParameters {
+    // A generator of the subgroup of order q
+    pub const GENERATOR: (u64, u64) = (422413, 115338);
+
+    // The point (578980, 0) of order two, which is not in the subgroup
+    pub const TWO_TORSION_X: u64 = 578980;
+}
+
+impl ShortWeierstrass for TestCurveParameters {
+    type Field = FpTest;
+
+    const A: Self::Field = FpTest::from_RAW_limbs(LimbInt::from_limbs([3 * 350687 % 1000003]));
+    const B: Self::Field = FpTest::from_RAW_limbs(LimbInt::from_limbs([15 * 350687 % 1000003]));
+}
+
+impl ShortWeierstrass for TestCurveA0Parameters {
+    type Field = FpTest;
+
+    const A: Self::Field = FpTest::from_RAW_limbs(LimbInt::from_limbs([0]));
+    const B: Self::Field = FpTest::from_RAW_limbs(LimbInt::from_limbs([5 * 350687 % 1000003]));
+
+    const A_KIND: CoefficientKind = CoefficientKind::Zero;
+}
+
+impl ShortWeierstrass for TestCurveAM3Parameters {
+    type Field = FpTest;
+
+    const A: Self::Field =
+        FpTest::from_RAW_limbs(LimbInt::from_limbs([1000000 * 350687 % 1000003]));
+    const B: Self::Field = FpTest::from_RAW_limbs(LimbInt::from_limbs([350687]));
+
+    const A_KIND: CoefficientKind = CoefficientKind::MinusThree;
+}
+
+/// Implements `PrimeSubGroupConfig` for a test curve with the given generator.
+macro_rules! impl_test_subgroup {
+    ($operations:ty, $params:ty, $scalar:ty, $name:expr, $cofactor:expr, $generator:expr) => {
+        impl PrimeSubGroupConfig for $operations {
+            type ScalarField = $scalar;
+
+            const NAME: &'static str = $name;
+
+            type Cofactor = [u64; 1];
+
+            const COFACTOR: Self::Cofactor = [$cofactor];
+
+            fn generator<R: Rng>(rng: Option<&mut R>) -> Self::Affine {
+                let (x, y) = $generator;
+                let affine_point =
+                    Affine::new(FpTest::from_int(&[x].into()), FpTest::from_int(&[y].into()));
+
+                let mut point = Self::Point::from(affine_point);
+                if let Some(rng) = rng {
+                    let scalar = <$scalar>::rand(rng);
+                    point = <Self as PrimeSubGroupConfig>::scalar_mul(&point, &scalar);
+                }
+                point.into_affine().unwrap()
+            }
+
+            fn compress(input: &Self::Affine) -> Vec<u8> {
+                sec1_compress(input)
+            }
+
+            fn batch_generators_from_label(label: &[u8], n: usize) -> Vec<Self::Affine> {
+                test_generators_from_label::<Self, $params>(label, n)
+            }
+        }
+    };
+}
+
+impl_test_subgroup!(
+    ShortWeierstrassCompleteOperations<TestCurveParameters>,
+    TestCurveParameters,
+    ScalarTest,
+    "TestCurveComplete",
+    2,
+    TestCurveParameters::GENERATOR
+);
+impl_test_subgroup!(
+    ShortWeierstrassCompleteOperations<TestCurveA0Parameters>,
+    TestCurveA0Parameters,
+    ScalarTestA0,
+    "TestCurveA0",
+    1,
+    (1u64, 586770u64)
+);
+impl_test_subgroup!(
+    ShortWeierstrassCompleteOperations<TestCurveAM3Parameters>,
+    TestCurveAM3Parameters,
+    ScalarTestAM3,
+    "TestCurveAM3",
+    1,
+    (3u64, 927878u64)
+);
+
+impl PrimeSubGroupConfig for TestCurve {
+    type ScalarField = ScalarTest;
+
+    const NAME: &'static str = "TestCurve";
+
+    type Cofactor = [u64; 1];
+
+    const COFACTOR: Self::Cofactor = [2];
+
+    fn generator<R: Rng>(rng: Option<&mut R>) -> Self::Affine {
+        let (x, y) = TestCurveParameters::GENERATOR;
+        let affine_point =
+            Affine::new(FpTest::from_int(&[x].into()), FpTest::from_int(&[y].into()));
+
+        let mut point = Self::Point::from(affine_point);
+        if let Some(rng) = rng {
+            let scalar = ScalarTest::rand(rng);
+            point = <Self as PrimeSubGroupConfig>::scalar_mul(&point, &scalar);
+        }
+        point.into_affine().unwrap()
+    }
+
+    fn compress(input: &Self::Affine) -> Vec<u8> {
+        sec1_compress(input)
+    }
+
+    fn batch_generators_from_label(label: &[u8], n: usize) -> Vec<Self::Affine> {
+        test_generators_from_label::<Self, TestCurveParameters>(label, n)
+    }
+}
+
+/// Uses the first 8 bytes of the digest modulo `p` as `x` and multiplies by the cofactor.
+fn test_generators_from_label<C, P>(label: &[u8], n: usize) -> Vec<Affine<FpTest>>
+where
+    C: PrimeSubGroupConfig<Affine = Affine<FpTest>>,
+    P: ShortWeierstrass<Field = FpTest>,
+{
+    crate::models::generators_from_label::<C>(label, n, |digest| {
+        let x = u64::from_be_bytes(digest[..8].try_into().unwrap()) % 1000003;
+        let x = FpTest::from_int(&[x].into());
+        let y = (x.square() * x + P::A * x + P::B).sqrt()?;
+        Some(C::mul_by_cofactor(&Affine::new(x, y).into()))
+    })
+}
+
+pub fn is_on_curve<P: ShortWeierstrass<Field = FpTest>>(point: &Affine<FpTest>) -> bool {
+    let (x, y) = (point.x, point.y);
+    y.square() == x.square() * x + P::A * x + P::B
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::check_generators;
+    use cryp_std::rand::{rngs::ThreadRng, thread_rng, UniformRand};
+
+    fn affine(x: u64, y: u64) -> Affine<FpTest> {
+        Affine::new(FpTest::from_int(&[x].into()), FpTest::from_int(&[y].into()))
+    }
+
+    #[test]
+    fn test_parameters() {
+        assert_eq!(TestCurveParameters::A, FpTest::from_int(&[3].into()));
+        assert_eq!(TestCurveParameters::B, FpTest::from_int(&[15].into()));
+
+        let g = GroupTest::generator::<ThreadRng>(None);
+        assert!(is_on_curve::<TestCurveParameters>(&g.point));
+        assert!(GroupTest::is_valid(&g));
+
+        let t = affine(TestCurveParameters::TWO_TORSION_X, 0);
+        assert!(is_on_curve::<TestCurveParameters>(&t));
+        assert!(!GroupTest::is_valid(&AffineTest::new(t)));
+    }
+
+    #[test]
+    fn test_group_law() {
+        // 2G, 3G and (q - 1)G computed with affine formulas
+        let g = GroupTest::from(GroupTest::generator::<ThreadRng>(None));
+        let g2 = GroupTest::from(AffineTest::new(affine(730471, 481446)));
+        let g3 = GroupTest::from(AffineTest::new(affine(695903, 218384)));
+        let minus_g = GroupTest::from(AffineTest::new(affine(422413, 884665)));
+        let identity = GroupTest::identity();
+
+        assert_eq!(g.double(), g2);
+        assert_eq!(g + g, g2);
+        assert_eq!(g2 + g, g3);
+        assert_eq!(g + g2, g3);
+        assert_eq!(-g, minus_g);
+        assert_eq!(g + minus_g, identity);
+        assert_eq!(g - g, identity);
+
+        // additions involving the identity
+        assert_eq!(identity + g, g);
+        assert_eq!(g + identity, g);
+        assert_eq!(identity + identity, identity);
+        assert_eq!(identity.double(), identity);
+
+        // mixed additions with a public point
+        let g_public = g.as_public().unwrap();
+        assert_eq!(g + g_public, g2);
+        assert_eq!(g2 + g_public, g3);
+        assert_eq!(minus_g + g_public, identity);
+        assert_eq!(identity + g_public, g);
+
+        // the point of order two
+        let t = GroupTest::from(AffineTest::new(affine(
+            TestCurveParameters::TWO_TORSION_X,
+            0,
+        )));
+        assert_eq!(t.double(), identity);
+        assert_eq!(t + t, identity);
+        assert_eq!(-t, t);
+    }
+
+    #[test]
+    fn test_scalar_mul() {
+        let mut rng = thread_rng();
+        let g = GroupTest::from(GroupTest::generator(Some(&mut rng)));
+        let identity = GroupTest::identity();
+
+        assert_eq!(g.mul_int(&ScalarTest::MODULUS), identity);
+        assert_eq!(g.mul_int_non_ct(&ScalarTest::MODULUS), identity);
+        assert_eq!(g * &(-ScalarTest::one()), -g);
+
+        for _ in 0..20 {
+            let a = ScalarTest::rand(&mut rng);
+            let b = ScalarTest::rand(&mut rng);
+
+            assert_eq!(g * &a + g * &b, g * &(a + b));
+            assert_eq!((g * &a) * &b, g * &(a * b));
+            assert_eq!(g.mul_int_non_ct(&a.as_int()), g * &a);
+            assert!(is_on_curve::<TestCurveParameters>(
+                &(g * &a).as_public().unwrap().point
+            ));
+        }
+
+        let generators = GroupTest::batch_generators(5, &mut rng);
+        let scalars = (0..5)
+            .map(|_| ScalarTest::rand(&mut rng))
+            .collect::<Vec<_>>();
+        let expected: GroupTest = generators
+            .iter()
+            .zip(scalars.iter())
+            .map(|(g, s)| *g * s)
+            .sum();
+        assert_eq!(GroupTest::msm(&generators, &scalars), expected);
+    }
+
+    #[test]
+    fn test_compress() {
+        use cryp_std::string::ToString;
+
+        let g = GroupTest::generator::<ThreadRng>(None);
+        // x = 422413 = 0x6720d, y = 115338 is even
+        assert_eq!(g.to_string(), "02000000000006720d");
+        assert_eq!((-GroupTest::from(g)).to_string(), "03000000000006720d");
+    }
+
+    /// Checks the complete formulas against the Jacobian formulas, including the
+    /// exceptional cases of the latter.
+    #[test]
+    fn test_complete_general() {
+        let mut rng = thread_rng();
+        let identity = GroupTestComplete::identity();
+
+        for _ in 0..10 {
+            let g = GroupTest::generator(Some(&mut rng));
+            let a = ScalarTest::rand(&mut rng);
+            let b = ScalarTest::rand(&mut rng);
+
+            let g_j = GroupTest::from(g);
+            let g_c = GroupTestComplete::from(PublicEC::new(g.point));
+            let to_affine = |p: GroupTestComplete| p.as_public().unwrap().point;
+            let from_jacobian = |p: GroupTest| p.as_public().unwrap().point;
+
+            assert_eq!(to_affine(g_c * &a), from_jacobian(g_j * &a));
+            assert_eq!(to_affine(g_c.double()), from_jacobian(g_j.double()));
+            assert_eq!(
+                to_affine(g_c * &a + g_c * &b),
+                from_jacobian(g_j * &(a + b))
+            );
+            assert_eq!(g_c + g_c, g_c.double());
+            assert_eq!(g_c + (-g_c), identity);
+            assert_eq!(g_c + identity, g_c);
+            assert_eq!(identity + g_c, g_c);
+            assert_eq!(identity.double(), identity);
+
+            let public = g_c.as_public().unwrap();
+            assert_eq!(g_c + public, g_c.double());
+            assert_eq!(identity + public, g_c);
+            assert_eq!(-g_c + public, identity);
+        }
+    }
+
+    fn check_complete<P>(rng: &mut ThreadRng)
+    where
+        P: ShortWeierstrass<Field = FpTest>,
+        ShortWeierstrassCompleteOperations<P>: PrimeSubGroupConfig<Affine = Affine<FpTest>>,
+    {
+        type G<P> = GroupEC<ShortWeierstrassCompleteOperations<P>>;
+        type Scalar<P> =
+            <ShortWeierstrassCompleteOperations<P> as PrimeSubGroupConfig>::ScalarField;
+
+        let identity = G::<P>::identity();
+        let g = G::<P>::from(G::<P>::generator(Some(&mut *rng)));
+        let public = g.as_public().unwrap();
+        assert!(is_on_curve::<P>(&public.point));
+        assert!(G::<P>::is_valid(&public));
+
+        let order = Scalar::<P>::MODULUS;
+        assert_eq!(g.mul_int(&order), identity);
+        assert_eq!(g.mul_int_non_ct(&order), identity);
+
+        for _ in 0..10 {
+            let a = Scalar::<P>::rand(rng);
+            let b = Scalar::<P>::rand(rng);
+
+            let (ga, gb) = (g * &a, g * &b);
+            assert!(is_on_curve::<P>(&ga.as_public().unwrap().point));
+            assert_eq!(ga + gb, g * &(a + b));
+            assert_eq!(ga + gb.as_public().unwrap(), g * &(a + b));
+            assert_eq!(ga.double(), ga + ga);
+            assert_eq!(ga + ga.as_public().unwrap(), ga.double());
+            assert_eq!(ga + (-ga), identity);
+            assert_eq!(ga + identity, ga);
+            assert_eq!(identity + ga, ga);
+            assert_eq!(identity + ga.as_public().unwrap(), ga);
+        }
+        assert_eq!(identity.double(), identity);
+        assert_eq!(identity + identity, identity);
+    }
+
+    #[test]
+    fn test_complete_specializations() {
+        let mut rng = thread_rng();
+
+        // compare against the known multiples computed with affine formulas
+        let g = GroupTestA0::generator::<ThreadRng>(None);
+        assert_eq!(
+            (GroupTestA0::from(g) + g).as_public().unwrap().point,
+            affine(875001, 860801)
+        );
+        assert_eq!(
+            GroupTestA0::from(g)
+                .double()
+                .double()
+                .as_public()
+                .unwrap()
+                .point,
+            (GroupTestA0::from(g) * &ScalarTestA0::from_int(&[4].into()))
+                .as_public()
+                .unwrap()
+                .point
+        );
+        let g = GroupTestAM3::generator::<ThreadRng>(None);
+        assert_eq!(
+            (GroupTestAM3::from(g).double() + g)
+                .as_public()
+                .unwrap()
+                .point,
+            affine(220858, 936449)
+        );
+
+        check_complete::<TestCurveParameters>(&mut rng);
+        check_complete::<TestCurveA0Parameters>(&mut rng);
+        check_complete::<TestCurveAM3Parameters>(&mut rng);
+    }
+
+    #[test]
+    fn test_batch_as_public() {
+        let mut rng = thread_rng();
+
+        // Jacobian coordinates
+        let g = GroupTest::from(GroupTest::generator(Some(&mut rng)));
+        let points = [g, g.double(), GroupTest::identity(), g.double() + g, -g];
+        let expected = points.iter().map(GroupTest::as_public).collect::<Vec<_>>();
+        assert_eq!(expected[2], None);
+        assert_eq!(GroupTest::batch_as_public(&points), expected);
+        assert!(GroupTest::batch_as_public(&[]).is_empty());
+
+        // projective coordinates
+        let g = GroupTestComplete::from(GroupTestComplete::generator(Some(&mut rng)));
+        let points = [GroupTestComplete::identity(), g, g.double(), g.double() + g];
+        let expected = points
+            .iter()
+            .map(GroupTestComplete::as_public)
+            .collect::<Vec<_>>();
+        assert_eq!(expected[0], None);
+        assert_eq!(GroupTestComplete::batch_as_public(&points), expected);
+    }
+
+    #[test]
+    fn test_generators() {
+        check_generators::<GroupTest>("020000000000082e1b");
+        check_generators::<GroupTestAM3>("0200000000000ac179");
+    }
+}
@@ -3,6 +3,90 @@ use super::*;
 /// The operation used is the unified formulas from section 3.1. of the paper
 /// "Twisted Edwards Curves Revisited" by Hisil, Wong, Carter, Dawson, and Dahab.
 ///  http://eprint.iacr.org/2008/522
+///
+/// The formulas are complete when `a` is a square and `d` is a non-square in the field.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct EdwardsGeneralUnifiedOperations<P: TwistedEdwardsGeneral> {
     _marker: cryp_std::marker::PhantomData<P>,
 }
+
+impl<P: TwistedEdwardsGeneral> CurveOperations for EdwardsGeneralUnifiedOperations<P> {
+    type Field = P::Field;
+    type Point = ExtendedPoint<P::Field>;
+    type Affine = Affine<P::Field>;
+    type Cached = Self::Point;
+
+    const UNIFIED: bool = true;
+
+    fn identity() -> Self::Point {
+        ExtendedPoint {
+            X: P::Field::zero(),
+            Y: P::Field::one(),
+            T: P::Field::zero(),
+            Z: P::Field::one(),
+        }
+    }
+
+    /// Checks `ax^2 + y^2 = 1 + dx^2y^2`.
+    fn is_on_curve(point: &Self::Affine) -> bool {
+        let (x2, y2) = (point.x.square(), point.y.square());
+        P::A * x2 + y2 == P::Field::one() + P::D * x2 * y2
+    }
+
+    fn neg_in_place(point: &mut Self::Point) {
+        point.X = -point.X;
+        point.T = -point.T;
+    }
+
+    #[allow(non_snake_case)]
+    fn add_in_place(lhs: &mut Self::Point, rhs: &Self::Point) {
+        let (X1, Y1, Z1, T1) = (lhs.X, lhs.Y, lhs.Z, lhs.T);
+        let (X2, Y2, Z2, T2) = (rhs.X, rhs.Y, rhs.Z, rhs.T);
+
+        // Formulas from 2008 Hisil--Wong--Carter--Dawson, http://eprint.iacr.org/2008/522, Section 3.1
+        let A = X1 * X2;
+        let B = Y1 * Y2;
+        let C = T1 * P::D * T2;
+        let D = Z1 * Z2;
+        let E = (X1 + Y1) * (X2 + Y2) - A - B;
+        let F = D - C;
+        let G = D + C;
+        let H = B - P::A * A;
+        lhs.X = E * F;
+        lhs.Y = G * H;
+        lhs.T = E * H;
+        lhs.Z = F * G;
+    }
+
+    #[allow(non_snake_case)]
+    fn add_affine_in_place(lhs: &mut Self::Point, rhs: &Self::Affine) {
+        let (X1, Y1, Z1, T1) = (lhs.X, lhs.Y, lhs.Z, lhs.T);
+        let (X2, Y2, T2) = (rhs.x, rhs.y, rhs.x * rhs.y);
+
+        let A = X1 * X2;
+        let B = Y1 * Y2;
+        let C = T1 * P::D * T2;
+        let D = Z1;
+        let E = (X1 + Y1) * (X2 + Y2) - A - B;
+        let F = D - C;
+        let G = D + C;
+        let H = B - P::A * A;
+        lhs.X = E * F;
+        lhs.Y = G * H;
+        lhs.T = E * H;
+        lhs.Z = F * G;
+    }
+
+    fn double_in_place(point: &mut Self::Point) {
+        let rhs = *point;
+        Self::add_in_place(point, &rhs);
+    }
+
+    fn to_cached(point: &Self::Point) -> Self::Cached {
+        *point
+    }
+
+    fn add_cached_in_place(lhs: &mut Self::Point, rhs: &Self::Cached) {
+        Self::add_in_place(lhs, rhs);
+    }
+}
@@ -1,4 +1,4 @@
-use super::coordinates::{Affine, ExtendedPoint};
+use super::coordinates::{Affine, CompletedPoint, ExtendedPoint, Projective, ProjectiveNielsPoint};
 use super::ff::*;
 use super::CurveOperations;
 
@@ -6,15 +6,20 @@ mod a_minus_one_unified;
 mod general_unified;
 
 pub use a_minus_one_unified::EdwardsAM1UnifiedOperations;
+pub use general_unified::EdwardsGeneralUnifiedOperations;
 
 /// Twisted Edwards Curve parameters
 ///
 ///  ax2 + y2 = 1 + dx2y2
 /// No assumptions on a and d.
 pub trait TwistedEdwardsGeneral {
+    /// The field over which the curve is defined
     type Field: Field;
 
+    /// The parameter a
     const A: Self::Field;
+
+    /// The parameter d
     const D: Self::Field;
 }
 
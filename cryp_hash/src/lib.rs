@@ -0,0 +1,102 @@
+//! Hash functions implemented from scratch, without dependencies.
+//!
+//! - `Sha256` and `Sha512`, the SHA-2 hash functions of FIPS 180-4.
+//! - `Sha3_224`, `Sha3_256`, `Sha3_384` and `Sha3_512`, the SHA-3 hash functions of FIPS 202.
+//! - `Shake128` and `Shake256`, the extendable output functions of FIPS 202.
+//!
+//! The hash functions absorb their input incrementally through the `Digest` and
+//! `ExtendableOutput` traits.
+
+#![cfg_attr(not(test), no_std)]
+
+use core::fmt::Debug;
+
+mod sha2;
+mod sha3;
+
+pub use sha2::{Sha256, Sha512};
+pub use sha3::{Sha3, Sha3_224, Sha3_256, Sha3_384, Sha3_512, Shake, Shake128, Shake256};
+
+/// A hash function with a fixed output size that absorbs its input incrementally.
+///
+/// ```rust
+/// use cryp_hash::{Digest, Sha512};
+///
+/// let mut hasher = Sha512::new();
+/// hasher.update(b"a");
+/// hasher.update(b"bc");
+/// assert_eq!(hasher.finalize(), Sha512::digest(b"abc"));
+/// ```
+pub trait Digest: Clone + Debug + Default {
+    /// The size of the digest in bytes.
+    const OUTPUT_SIZE: usize;
+    /// The size of the blocks processed by the compression function in bytes.
+    const BLOCK_SIZE: usize;
+
+    /// The digest, an array of `OUTPUT_SIZE` bytes.
+    type Output: AsRef<[u8]> + AsMut<[u8]> + Clone + Copy + Debug + PartialEq + Eq;
+
+    fn new() -> Self;
+
+    /// Absorbs more input.
+    fn update(&mut self, data: &[u8]);
+
+    /// Pads the input and returns the digest.
+    fn finalize(self) -> Self::Output;
+
+    /// The digest of the given input.
+    fn digest(data: &[u8]) -> Self::Output {
+        let mut hasher = Self::new();
+        hasher.update(data);
+        hasher.finalize()
+    }
+}
+
+/// A hash function with an output of arbitrary length, an extendable output function.
+///
+/// ```rust
+/// use cryp_hash::{ExtendableOutput, Shake128};
+///
+/// let mut hasher = Shake128::default();
+/// hasher.update(b"abc");
+/// let mut output = [0u8; 100];
+/// hasher.finalize_into(&mut output);
+/// ```
+pub trait ExtendableOutput: Clone + Debug + Default {
+    /// Absorbs more input.
+    fn update(&mut self, data: &[u8]);
+
+    /// Fills `output` with the first `output.len()` bytes of the output.
+    fn finalize_into(self, output: &mut [u8]);
+}
+
+#[cfg(test)]
+mod test_utils {
+    use super::Digest;
+    use std::fmt::Write;
+    use std::string::String;
+    use std::vec::Vec;
+
+    pub fn to_hex(bytes: &[u8]) -> String {
+        let mut hex = String::new();
+        for byte in bytes {
+            write!(hex, "{:02x}", byte).unwrap();
+        }
+        hex
+    }
+
+    /// Absorbing the input in pieces gives the same digest, for all padding lengths
+    pub fn check_update<H: Digest>() {
+        let input = (0..=255u8).cycle().take(600).collect::<Vec<_>>();
+        for len in 0..input.len() {
+            let expected = H::digest(&input[..len]);
+            for split in [0, 1, len / 3, len / 2] {
+                let split = split.min(len);
+                let mut hasher = H::new();
+                hasher.update(&input[..split]);
+                hasher.update(&input[split..len]);
+                assert_eq!(hasher.finalize(), expected);
+            }
+        }
+    }
+}
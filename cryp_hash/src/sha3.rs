@@ -0,0 +1,315 @@
+use super::{Digest, ExtendableOutput};
+
+/// The round constants of Keccak-f[1600], FIPS 202 section 3.2.5.
+const RC: [u64; 24] = [
+    0x0000000000000001,
+    0x0000000000008082,
+    0x800000000000808a,
+    0x8000000080008000,
+    0x000000000000808b,
+    0x0000000080000001,
+    0x8000000080008081,
+    0x8000000000008009,
+    0x000000000000008a,
+    0x0000000000000088,
+    0x0000000080008009,
+    0x000000008000000a,
+    0x000000008000808b,
+    0x800000000000008b,
+    0x8000000000008089,
+    0x8000000000008003,
+    0x8000000000008002,
+    0x8000000000000080,
+    0x000000000000800a,
+    0x800000008000000a,
+    0x8000000080008081,
+    0x8000000000008080,
+    0x0000000080000001,
+    0x8000000080008008,
+];
+
+/// The rotation offsets of the step rho, FIPS 202 section 3.2.2, for the lane `x + 5y`.
+const RHO: [u32; 25] = [
+    0, 1, 62, 28, 27, 36, 44, 6, 55, 20, 3, 10, 43, 25, 39, 41, 45, 15, 21, 8, 18, 2, 61, 56, 14,
+];
+
+/// The permutation Keccak-f[1600] of FIPS 202 section 3.3, on the lanes `x + 5y`.
+fn keccak_f(state: &mut [u64; 25]) {
+    for rc in RC {
+        // theta: add the parities of two neighbouring columns
+        let mut c = [0u64; 5];
+        for x in 0..5 {
+            c[x] = state[x] ^ state[x + 5] ^ state[x + 10] ^ state[x + 15] ^ state[x + 20];
+        }
+        for x in 0..5 {
+            let d = c[(x + 4) % 5] ^ c[(x + 1) % 5].rotate_left(1);
+            for y in 0..5 {
+                state[x + 5 * y] ^= d;
+            }
+        }
+
+        // rho and pi: rotate the lanes and move (x, y) to (y, 2x + 3y)
+        let mut b = [0u64; 25];
+        for x in 0..5 {
+            for y in 0..5 {
+                b[y + 5 * ((2 * x + 3 * y) % 5)] = state[x + 5 * y].rotate_left(RHO[x + 5 * y]);
+            }
+        }
+
+        // chi: the only non linear step, along the rows
+        for y in 0..5 {
+            for x in 0..5 {
+                state[x + 5 * y] =
+                    b[x + 5 * y] ^ (!b[(x + 1) % 5 + 5 * y] & b[(x + 2) % 5 + 5 * y]);
+            }
+        }
+
+        // iota
+        state[0] ^= rc;
+    }
+}
+
+/// The sponge construction of FIPS 202 section 4 on Keccak-f[1600], with a rate of `RATE`
+/// bytes.
+#[derive(Clone, Debug)]
+struct Sponge<const RATE: usize> {
+    state: [u64; 25],
+    position: usize,
+}
+
+impl<const RATE: usize> Sponge<RATE> {
+    fn new() -> Self {
+        Self {
+            state: [0; 25],
+            position: 0,
+        }
+    }
+
+    /// Adds a byte to the state at the current position, in little endian lane order.
+    fn xor_byte(&mut self, byte: u8) {
+        self.state[self.position / 8] ^= (byte as u64) << (8 * (self.position % 8));
+        self.position += 1;
+    }
+
+    fn absorb(&mut self, data: &[u8]) {
+        for byte in data {
+            self.xor_byte(*byte);
+            if self.position == RATE {
+                keccak_f(&mut self.state);
+                self.position = 0;
+            }
+        }
+    }
+
+    /// Appends the domain separation bits, starting at the lowest bit of `suffix` and
+    /// ending with the first one bit of the padding, and the padding `10*1`.
+    fn pad(&mut self, suffix: u8) {
+        self.xor_byte(suffix);
+        self.position = RATE - 1;
+        self.xor_byte(0x80);
+        keccak_f(&mut self.state);
+        self.position = 0;
+    }
+
+    fn squeeze(&mut self, output: &mut [u8]) {
+        for byte in output {
+            if self.position == RATE {
+                keccak_f(&mut self.state);
+                self.position = 0;
+            }
+            *byte = (self.state[self.position / 8] >> (8 * (self.position % 8))) as u8;
+            self.position += 1;
+        }
+    }
+}
+
+/// The SHA-3 hash function with a rate of `RATE` bytes and a digest of `N` bytes,
+/// FIPS 202 section 6.1.
+#[derive(Clone, Debug)]
+pub struct Sha3<const RATE: usize, const N: usize> {
+    sponge: Sponge<RATE>,
+}
+
+/// SHA3-224, with a rate of 1152 bits.
+pub type Sha3_224 = Sha3<144, 28>;
+/// SHA3-256, with a rate of 1088 bits.
+pub type Sha3_256 = Sha3<136, 32>;
+/// SHA3-384, with a rate of 832 bits.
+pub type Sha3_384 = Sha3<104, 48>;
+/// SHA3-512, with a rate of 576 bits.
+pub type Sha3_512 = Sha3<72, 64>;
+
+impl<const RATE: usize, const N: usize> Digest for Sha3<RATE, N> {
+    const OUTPUT_SIZE: usize = N;
+    const BLOCK_SIZE: usize = RATE;
+
+    type Output = [u8; N];
+
+    fn new() -> Self {
+        Self {
+            sponge: Sponge::new(),
+        }
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        self.sponge.absorb(data);
+    }
+
+    /// The domain separation bits of SHA-3 are `01`.
+    fn finalize(mut self) -> [u8; N] {
+        self.sponge.pad(0x06);
+        let mut digest = [0u8; N];
+        self.sponge.squeeze(&mut digest);
+        digest
+    }
+}
+
+impl<const RATE: usize, const N: usize> Default for Sha3<RATE, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The SHAKE extendable output function with a rate of `RATE` bytes, FIPS 202 section 6.2.
+#[derive(Clone, Debug)]
+pub struct Shake<const RATE: usize> {
+    sponge: Sponge<RATE>,
+}
+
+/// SHAKE128, with a rate of 1344 bits.
+pub type Shake128 = Shake<168>;
+/// SHAKE256, with a rate of 1088 bits.
+pub type Shake256 = Shake<136>;
+
+impl<const RATE: usize> ExtendableOutput for Shake<RATE> {
+    fn update(&mut self, data: &[u8]) {
+        self.sponge.absorb(data);
+    }
+
+    /// The domain separation bits of SHAKE are `1111`.
+    fn finalize_into(mut self, output: &mut [u8]) {
+        self.sponge.pad(0x1f);
+        self.sponge.squeeze(output);
+    }
+}
+
+impl<const RATE: usize> Default for Shake<RATE> {
+    fn default() -> Self {
+        Self {
+            sponge: Sponge::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{check_update, to_hex};
+
+    fn shake<H: ExtendableOutput>(input: &[u8], len: usize) -> String {
+        let mut hasher = H::default();
+        hasher.update(input);
+        let mut output = vec![0u8; len];
+        hasher.finalize_into(&mut output);
+        to_hex(&output)
+    }
+
+    /// The examples of FIPS 202 for the empty message, "abc" and 200 bytes 0xa3, and the
+    /// first message of the short message files of the NIST CAVP
+    #[test]
+    fn test_sha3() {
+        let a3 = [0xa3; 200];
+        let vectors: [(&[u8], &str, &str); 3] = [
+            (
+                b"",
+                "6b4e03423667dbb73b6e15454f0eb1abd4597f9a1b078e3f5b5a6bc7",
+                "a7ffc6f8bf1ed76651c14756a061d662f580ff4de43b49fa82d80a4b80f8434a",
+            ),
+            (
+                b"abc",
+                "e642824c3f8cf24ad09234ee7d3c766fc9a3a5168d0c94ad73b46fdf",
+                "3a985da74fe225b2045c172d6bd390bd855f086e3e9d525b46bfe24511431532",
+            ),
+            (
+                &a3,
+                "9376816aba503f72f96ce7eb65ac095deee3be4bf9bbc2a1cb7e11e0",
+                "79f38adec5c20307a98ef76e8324afbfd46cfd81b22e3973c65fa1bd9de31787",
+            ),
+        ];
+        for (input, digest224, digest256) in vectors {
+            assert_eq!(to_hex(&Sha3_224::digest(input)), digest224);
+            assert_eq!(to_hex(&Sha3_256::digest(input)), digest256);
+        }
+
+        let vectors: [(&[u8], &str, &str); 3] = [
+            (
+                b"",
+                "0c63a75b845e4f7d01107d852e4c2485c51a50aaaa94fc61995e71bbee983a2a\
+                 c3713831264adb47fb6bd1e058d5f004",
+                "a69f73cca23a9ac5c8b567dc185a756e97c982164fe25859e0d1dcc1475c80a6\
+                 15b2123af1f5f94c11e3e9402c3ac558f500199d95b6d3e301758586281dcd26",
+            ),
+            (
+                b"abc",
+                "ec01498288516fc926459f58e2c6ad8df9b473cb0fc08c2596da7cf0e49be4b2\
+                 98d88cea927ac7f539f1edf228376d25",
+                "b751850b1a57168a5693cd924b6b096e08f621827444f70d884f5d0240d2712e\
+                 10e116e9192af3c91a7ec57647e3934057340b4cf408d5a56592f8274eec53f0",
+            ),
+            (
+                &a3,
+                "1881de2ca7e41ef95dc4732b8f5f002b189cc1e42b74168ed1732649ce1dbcdd\
+                 76197a31fd55ee989f2d7050dd473e8f",
+                "e76dfad22084a8b1467fcf2ffa58361bec7628edf5f3fdc0e4805dc48caeeca8\
+                 1b7c13c30adf52a3659584739a2df46be589c51ca1a4a8416df6545a1ce8ba00",
+            ),
+        ];
+        for (input, digest384, digest512) in vectors {
+            assert_eq!(to_hex(&Sha3_384::digest(input)), digest384);
+            assert_eq!(to_hex(&Sha3_512::digest(input)), digest512);
+        }
+
+        assert_eq!(
+            to_hex(&Sha3_256::digest(&[0xe9])),
+            "f0d04dd1e6cfc29a4460d521796852f25d9ef8d28b44ee91ff5b759d72c1e6d6"
+        );
+    }
+
+    /// The examples of FIPS 202 for SHAKE, with outputs longer than the rate
+    #[test]
+    fn test_shake() {
+        assert_eq!(
+            shake::<Shake128>(b"", 32),
+            "7f9c2ba4e88f827d616045507605853ed73b8093f6efbc88eb1a6eacfa66ef26"
+        );
+        assert_eq!(
+            shake::<Shake256>(b"", 64),
+            "46b9dd2b0ba88d13233b3feb743eeb243fcd52ea62b81b82b50c27646ed5762f\
+             d75dc4ddd8c0f200cb05019d67b592f6fc821c49479ab48640292eacb3b7c4be"
+        );
+        assert_eq!(
+            shake::<Shake128>(&[0xa3; 200], 64),
+            "131ab8d2b594946b9c81333f9bb6e0ce75c3b93104fa3469d3917457385da037\
+             cf232ef7164a6d1eb448c8908186ad852d3f85a5cf28da1ab6fe343817197846"
+        );
+        assert_eq!(
+            shake::<Shake256>(&[0xa3; 200], 64),
+            "cd8a920ed141aa0407a22d59288652e9d9f1a7ee0c1e7c1ca699424da84a904d\
+             2d700caae7396ece96604440577da4f3aa22aeb8857f961c4cd8e06f0ae6610b"
+        );
+
+        // squeezing several blocks agrees with a shorter output
+        let long = shake::<Shake128>(b"abc", 1000);
+        assert_eq!(long[..64], shake::<Shake128>(b"abc", 32));
+        assert_eq!(
+            long[..64],
+            *"5881092dd818bf5cf8a3ddb793fbcba74097d5c526a6d35f97b83351940f2cc8"
+        );
+    }
+
+    #[test]
+    fn test_update() {
+        check_update::<Sha3_256>();
+        check_update::<Sha3_512>();
+    }
+}
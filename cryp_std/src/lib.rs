@@ -1,18 +1,20 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
+// `core` and `alloc` both export some modules, such as `slice` and `str`
 #[cfg(not(feature = "std"))]
+#[allow(ambiguous_glob_reexports)]
 pub use core::*;
 
 #[cfg(not(feature = "std"))]
 #[doc(hidden)]
-extern crate alloc;
+extern crate alloc as alloc_crate;
 
 #[cfg(not(feature = "std"))]
-pub use alloc::*;
+pub use alloc_crate::*;
 
 #[cfg(not(feature = "std"))]
 pub mod fmt {
-    pub use alloc::fmt::*;
+    pub use alloc_crate::fmt::*;
     pub use core::fmt::*;
 }
 